@@ -0,0 +1,56 @@
+//! Times the digit-wise arithmetic of [`NitwiseArithmetic`] against combining the digits one place at a time through [`NitwiseLogic::zip_nits`],
+//! its scalar reference implementation, for power-of-two and other bases.
+//!
+//! Run with `cargo bench --bench nitwise`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use nit::Nit;
+use nit::arithmetic::NitwiseArithmetic;
+use nit::logic::NitwiseLogic;
+use nit::supported::BaseMaximum;
+
+/// The amount of pairs of words combined by each timing.
+const ITERATIONS: u32 = 1 << 20;
+
+/// Returns the time taken to combine `ITERATIONS` pseudo-random pairs of words.
+fn time(f: impl Fn(u64, u64) -> u64) -> Duration {
+	let mut state = 0x9E37_79B9_7F4A_7C15_u64;
+	let mut accumulator = 0;
+	let start = Instant::now();
+	for _ in 0..ITERATIONS {
+		state = state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+		accumulator ^= f(black_box(state), black_box(state.rotate_left(29)));
+	}
+	let elapsed = start.elapsed();
+	black_box(accumulator);
+	elapsed
+}
+
+/// Prints the time taken per pair of words by the digit-wise addition and minimum, and by their place-by-place equivalents.
+fn compare<const BASE: BaseMaximum>() {
+	let rows: [(&str, Duration, Duration); 2] = [
+		(
+			"add",
+			time(|a, b| a.nitwise_add::<BASE>(b)),
+			time(|a, b| a.zip_nits::<BASE, 1, _>(&b, Nit::wrapping_add)),
+		),
+		(
+			"min",
+			time(|a, b| a.nitwise_min::<BASE>(b)),
+			time(|a, b| a.zip_nits::<BASE, 1, _>(&b, core::cmp::min)),
+		),
+	];
+	for (name, words, places) in rows {
+		let per = |duration: Duration| duration.as_secs_f64() * 1e9 / f64::from(ITERATIONS);
+		println!("base {BASE:>3} {name}: {:>6.2} ns (nitwise), {:>6.2} ns (zip_nits)", per(words), per(places));
+	}
+}
+
+fn main() {
+	compare::<2>();
+	compare::<4>();
+	compare::<3>();
+	compare::<10>();
+	compare::<85>();
+}
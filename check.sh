@@ -3,4 +3,8 @@ echo "=== [NIGHTLY] CLIPPY === " && cargo +nightly clippy \
 && echo "=== [NIGHTLY] TEST (RELEASE) === " && cargo +nightly test --release \
 && echo "=== [STABLE] CLIPPY === " && cargo clippy \
 && echo "=== [STABLE] TEST (DEBUG) === " && cargo test \
-&& echo "=== [STABLE] TEST (RELEASE) === " && cargo test --release
+&& echo "=== [STABLE] TEST (RELEASE) === " && cargo test --release \
+&& echo "=== [STABLE] CLIPPY (STD) === " && cargo clippy --all-targets --features std \
+&& echo "=== [STABLE] TEST (NO FEATURES) === " && cargo test --no-default-features \
+&& echo "=== [STABLE] TEST (ALLOC) === " && cargo test --features alloc \
+&& echo "=== [STABLE] TEST (STD) === " && cargo test --features std
@@ -1,19 +1,61 @@
 use nit::prelude::*;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// The state of a single permission for a role.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Nit)]
 #[repr(u8)]
 enum PermissionGrant {
+	/// Defers to the parent role.
 	Unset = 0,
+	/// Grants the permission, unless an ancestor denies it.
 	Allow = 1,
+	/// Denies the permission, regardless of any ancestor.
 	Deny = 2,
 }
 
+/// Resolves the effective grants of a role, given its own grants and those of its parent.
+/// Each place within the `u16` is a permission, stored as a [`PermissionGrant`].
+fn inherit(parent: u16, child: u16) -> u16 {
+	let mut resolved = child;
+	for i in 0.. {
+		let (Some(inherited), Some(own)) = (parent.get_as::<PermissionGrant>(i), child.get_as::<PermissionGrant>(i)) else { break };
+		let grant = match (inherited, own) {
+			(PermissionGrant::Deny, _) | (_, PermissionGrant::Deny) => PermissionGrant::Deny,
+			(inherited, PermissionGrant::Unset) => inherited,
+			(_, own) => own,
+		};
+		let _ = resolved.set_as(i, grant);
+	}
+	resolved
+}
+
 fn main() {
+	let mut parent = 0_u16;
+	let mut child = 0_u16;
+	let _ = parent.set_as(0, PermissionGrant::Allow);
+	let _ = parent.set_as(1, PermissionGrant::Deny);
+	let _ = child.set_as(1, PermissionGrant::Allow);
+	let _ = child.set_as(2, PermissionGrant::Allow);
 
+	let resolved = inherit(parent, child);
+	for i in 0..3 {
+		println!("{i}: {:?}", resolved.get_as::<PermissionGrant>(i));
+	}
 }
 
-
 #[test]
-fn aa () {
+fn inheritance() {
+	let mut parent = 0_u16;
+	let mut child = 0_u16;
+	assert_eq!(parent.set_as(0, PermissionGrant::Allow), Ok(PermissionGrant::Unset));
+	assert_eq!(parent.set_as(1, PermissionGrant::Deny), Ok(PermissionGrant::Unset));
+	assert_eq!(child.set_as(1, PermissionGrant::Allow), Ok(PermissionGrant::Unset));
+	assert_eq!(child.set_as(2, PermissionGrant::Allow), Ok(PermissionGrant::Unset));
 
+	let resolved = inherit(parent, child);
+	assert_eq!(resolved.get_as(0), Some(PermissionGrant::Allow));
+	assert_eq!(resolved.get_as(1), Some(PermissionGrant::Deny));
+	assert_eq!(resolved.get_as(2), Some(PermissionGrant::Allow));
+	assert_eq!(resolved.get_as(3), Some(PermissionGrant::Unset));
+	assert_eq!(Nit::<3>::from(PermissionGrant::Deny), Nit::new(2).unwrap());
+	assert_eq!(PermissionGrant::try_from(Nit::<3>::new(1).unwrap()).ok(), Some(PermissionGrant::Allow));
 }
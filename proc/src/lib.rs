@@ -1,18 +1,287 @@
+//! Procedural macros for the [`nit`](https://docs.rs/nit) crate.
+//!
+//! These are re-exported by `nit` itself, and should be used through it rather than depending on this crate directly.
+//! Generated code refers to items through the `::nit` path.
+
 extern crate proc_macro;
-use proc_macro::TokenStream;
+use proc_macro::{Delimiter, TokenStream, TokenTree};
+
+/// Utilities for walking raw token trees without pulling in a full parser.
+mod parse;
+
+/// Derives [`NitRepr`], `From<Self> for Nit<BASE, BYTES>` and `TryFrom<Nit<BASE, BYTES>> for Self` on a fieldless enum.
+///
+/// The base is the amount of variants, and each variant is represented by the digit of its declaration index;
+/// explicit discriminants are not taken into consideration.
+/// The nits are the fewest bytes which hold the base, which is the default of one byte for enums of up to 256 variants.
+///
+/// [`NitRepr`]: https://docs.rs/nit/latest/nit/repr/trait.NitRepr.html
+#[proc_macro_derive(Nit)]
+pub fn derive_nit(item: TokenStream) -> TokenStream {
+	match derive_nit_inner(item) {
+		Ok(stream) | Err(stream) => stream,
+	}
+}
+
+/// Implementation of [`derive_nit`], with errors being early-returned as a `compile_error!` invocation.
+fn derive_nit_inner(item: TokenStream) -> Result<TokenStream, TokenStream> {
+	let tokens: Vec<TokenTree> = item.into_iter().collect();
+	let mut i = 0;
+	parse::skip_attributes_and_visibility(&tokens, &mut i);
+	if !parse::is_ident(tokens.get(i), "enum") {
+		return Err(parse::compile_error("`#[derive(Nit)]` can only be used on enums."));
+	}
+	i += 1;
+	let Some(TokenTree::Ident(name)) = tokens.get(i) else {
+		return Err(parse::compile_error("Expected an enum name."));
+	};
+	let name = name.to_string();
+	i += 1;
+	let body = match tokens.get(i) {
+		Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => group.stream(),
+		_ => return Err(parse::compile_error("`#[derive(Nit)]` does not support generic enums.")),
+	};
+
+	let mut variants = Vec::new();
+	for variant in parse::split_on_commas(body, false) {
+		let mut j = 0;
+		parse::skip_attributes_and_visibility(&variant, &mut j);
+		let Some(TokenTree::Ident(ident)) = variant.get(j) else { continue };
+		if let Some(TokenTree::Group(_)) = variant.get(j + 1) {
+			return Err(parse::compile_error("`#[derive(Nit)]` can only be used on enums with fieldless variants."));
+		}
+		variants.push(ident.to_string());
+	}
+	let (2.., Some(last)) = (variants.len(), variants.last()) else {
+		return Err(parse::compile_error("`#[derive(Nit)]` requires an enum with at least two variants."));
+	};
+	let base = variants.len();
+	// The fewest bytes which hold the largest digit.
+	let bytes = (usize::BITS - (base - 1).leading_zeros()).div_ceil(8).max(1);
+
+	let mut into_nit_arms = String::new();
+	let mut into_digit_arms = String::new();
+	let mut from_digit_arms = String::new();
+	let mut try_from_arms = String::new();
+	for (digit, variant) in variants.iter().enumerate() {
+		into_nit_arms += &format!("{name}::{variant} => const {{ match ::nit::Nit::<{base}, {bytes}>::new({digit}) {{ ::core::result::Result::Ok(nit) => nit, ::core::result::Result::Err(_) => ::core::panic!(\"The digit of a variant exceeds the base.\") }} }},");
+		into_digit_arms += &format!("Self::{variant} => {digit},");
+		try_from_arms += &format!("{digit} => ::core::result::Result::Ok(Self::{variant}),");
+		if digit + 1 < base {
+			from_digit_arms += &format!("{digit} => Self::{variant},");
+		}
+	}
+
+	let output = format!(r#"
+		const _: () = if let ::core::result::Result::Err(err) = ::nit::max_nits::compute_max_nits_in_bits::<{base}, {{ ::nit::supported::MAXIMUM_SUPPORTED_BITS }}>() {{
+			::core::panic!("{{}}", err.get_str());
+		}};
+
+		impl ::core::convert::From<{name}> for ::nit::Nit<{base}, {bytes}> {{
+			fn from(value: {name}) -> Self {{
+				match value {{ {into_nit_arms} }}
+			}}
+		}}
+
+		impl ::core::convert::TryFrom<::nit::Nit<{base}, {bytes}>> for {name} {{
+			type Error = ::nit::NitCreationError;
+
+			fn try_from(value: ::nit::Nit<{base}, {bytes}>) -> ::core::result::Result<Self, Self::Error> {{
+				match value.get_value() {{
+					{try_from_arms}
+					_ => ::core::result::Result::Err(::nit::NitCreationError),
+				}}
+			}}
+		}}
+
+		impl ::nit::repr::NitRepr for {name} {{
+			const BASE: ::nit::supported::BaseMaximum = {base};
+
+			fn into_digit(self) -> ::nit::supported::BaseMaximum {{
+				match self {{ {into_digit_arms} }}
+			}}
+
+			unsafe fn from_digit_unchecked(digit: ::nit::supported::BaseMaximum) -> Self {{
+				match digit {{
+					{from_digit_arms}
+					_ => Self::{last},
+				}}
+			}}
+
+			fn get_from<const TYPE_BIT_WIDTH: ::nit::supported::FitsMaximumBits, C: ::nit::data_container::NitDataContainer<TYPE_BIT_WIDTH> + ?::core::marker::Sized>(container: &C, n: ::nit::supported::FitsMaximumBits) -> ::core::option::Option<Self> {{
+				match container.get_nit::<{base}, {bytes}>(n) {{
+					::core::option::Option::Some(nit) => Self::try_from(nit).ok(),
+					::core::option::Option::None => ::core::option::Option::None,
+				}}
+			}}
 
-#[proc_macro_derive(AnswerFn)]
-pub fn derive_answer_fn(_item: TokenStream) -> TokenStream {
-    "fn answer() -> u32 { 42 }".parse().unwrap()
+			fn set_in<const TYPE_BIT_WIDTH: ::nit::supported::FitsMaximumBits, C: ::nit::data_container::NitDataContainer<TYPE_BIT_WIDTH> + ?::core::marker::Sized>(self, container: &mut C, n: ::nit::supported::FitsMaximumBits) -> ::core::result::Result<Self, ::nit::places::PlacesIndexCreationError> {{
+				match container.set_nit::<{base}, {bytes}>(n, self.into()) {{
+					// SAFETY: The previous digit was extracted from the container, so it is within `0..BASE`.
+					::core::result::Result::Ok(previous) => ::core::result::Result::Ok(unsafe {{ Self::from_digit_unchecked(previous.get_value()) }}),
+					::core::result::Result::Err(err) => ::core::result::Result::Err(err),
+				}}
+			}}
+		}}
+	"#);
+	output.parse().map_err(|_| parse::compile_error("Failed to generate the `Nit` implementation."))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Turns a struct with named fields into a `Copy` newtype over an unsigned integer, with each field being stored as a place of a mixed-radix number.
+///
+/// Each field's type must implement [`NitRepr`]; the first field is the least significant place.
+/// Getters (`field`), setters (`set_field`, returning the previous value) and a constructor (`new`) are generated,
+/// and compilation fails if the integer can't represent every combination of the fields.
+///
+/// Attributes on the struct are kept on the generated newtype, so further traits can be derived on it as usual;
+/// `Clone` and `Copy` are derived if they aren't already, as the accessors take `self` by value.
+/// Fields may only have documentation attributes, which are given to their accessors.
+///
+/// [`NitRepr`]: https://docs.rs/nit/latest/nit/repr/trait.NitRepr.html
+#[proc_macro_attribute]
+pub fn packed(attribute: TokenStream, item: TokenStream) -> TokenStream {
+	match packed_inner(attribute, item) {
+		Ok(stream) | Err(stream) => stream,
+	}
+}
+
+/// A field of a struct annotated with [`packed`].
+struct PackedField {
+	/// The documentation attributes of the field.
+	docs: Vec<String>,
+	/// The visibility of the field, which is used for its accessors.
+	visibility: String,
+	/// The name of the field.
+	name: String,
+	/// The type of the field.
+	ty: String,
+}
+
+/// Implementation of [`packed`], with errors being early-returned as a `compile_error!` invocation.
+fn packed_inner(attribute: TokenStream, item: TokenStream) -> Result<TokenStream, TokenStream> {
+	let integer = attribute.to_string();
+	if integer.is_empty() {
+		return Err(parse::compile_error("`#[packed(...)]` requires the integer type to pack into, e.g. `#[packed(u16)]`."));
+	}
+
+	let tokens: Vec<TokenTree> = item.into_iter().collect();
+	let mut i = 0;
+	let attributes = parse::take_attributes(&tokens, &mut i);
+	let derives = ["Clone", "Copy"]
+		.into_iter()
+		.filter(|name| !attributes.iter().any(|attribute| parse::is_derive_of(attribute, name)))
+		.collect::<Vec<_>>()
+		.join(", ");
+	let attributes = attributes.concat();
+	let visibility = parse::take_visibility(&tokens, &mut i);
+	if !parse::is_ident(tokens.get(i), "struct") {
+		return Err(parse::compile_error("`#[packed(...)]` can only be used on structs."));
+	}
+	i += 1;
+	let Some(TokenTree::Ident(name)) = tokens.get(i) else {
+		return Err(parse::compile_error("Expected a struct name."));
+	};
+	let name = name.to_string();
+	i += 1;
+	let body = match tokens.get(i) {
+		Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => group.stream(),
+		_ => return Err(parse::compile_error("`#[packed(...)]` can only be used on non-generic structs with named fields.")),
+	};
+
+	let mut fields = Vec::new();
+	for field in parse::split_on_commas(body, true) {
+		let mut j = 0;
+		let docs = parse::take_attributes(&field, &mut j);
+		if !docs.iter().all(|attribute| parse::is_doc_attribute(attribute)) {
+			return Err(parse::compile_error("The fields of a `#[packed(...)]` struct can only have documentation attributes."));
+		}
+		let visibility = parse::take_visibility(&field, &mut j);
+		let (Some(TokenTree::Ident(ident)), true) = (field.get(j), parse::is_punct(field.get(j + 1), ':')) else {
+			return Err(parse::compile_error("Expected a named field."));
+		};
+		let ty = field[j + 2..].iter().cloned().collect::<TokenStream>().to_string();
+		fields.push(PackedField { docs, visibility, name: ident.to_string(), ty });
+	}
+	let places = fields.len();
+
+	let bases = fields.iter().map(|field| format!("<{} as ::nit::repr::NitRepr>::BASE,", field.ty)).collect::<String>();
+	let parameters = fields.iter().map(|field| format!("{}: {},", field.name, field.ty)).collect::<String>();
+	let mut constructor = String::new();
+	let mut accessors = String::new();
+	for (place, PackedField { docs, visibility, name: field, ty }) in fields.iter().enumerate() {
+		let docs = docs.concat();
+		constructor += &format!("+ (::nit::repr::NitRepr::into_digit({field}) as {integer}) * Self::__NIT_SHIFTERS[{place}]");
+		accessors += &format!(r#"
+			{docs}
+			#[must_use]
+			{visibility} fn {field}(self) -> {ty} {{
+				let digit = (self.0 / Self::__NIT_SHIFTERS[{place}]) % (Self::__NIT_BASES[{place}] as {integer});
+				// SAFETY: The digit is within the range of `0..BASE` because of the modulo operation.
+				unsafe {{ <{ty} as ::nit::repr::NitRepr>::from_digit_unchecked(digit as ::nit::supported::BaseMaximum) }}
+			}}
+
+			/// Sets the place of the field to the given value, returning the previous value.
+			{visibility} fn set_{field}(&mut self, value: {ty}) -> {ty} {{
+				let previous = self.{field}();
+				let shifter = Self::__NIT_SHIFTERS[{place}];
+				self.0 = self.0 - (::nit::repr::NitRepr::into_digit(previous) as {integer}) * shifter + (::nit::repr::NitRepr::into_digit(value) as {integer}) * shifter;
+				previous
+			}}
+		"#);
+	}
+
+	let output = format!(r#"
+		{attributes}
+		#[derive({derives})]
+		#[repr(transparent)]
+		{visibility} struct {name}({integer});
+
+		const _: () = {{ let _ = {name}::MAX_VALUE; }};
+
+		#[allow(clippy::cast_lossless, clippy::cast_possible_truncation)]
+		impl {name} {{
+			/// The base of each field, starting from the least significant place.
+			const __NIT_BASES: [::nit::supported::BaseMaximum; {places}] = [{bases}];
+			/// The shifter of each field; the product of the bases of all less significant places.
+			const __NIT_SHIFTERS: [{integer}; {places}] = {{
+				let mut shifters = [1; {places}];
+				let mut i = 1;
+				while i < {places} {{
+					shifters[i] = shifters[i - 1] * (Self::__NIT_BASES[i - 1] as {integer});
+					i += 1;
+				}}
+				shifters
+			}};
+			/// The largest underlying value, where every field is at its largest digit.
+			{visibility} const MAX_VALUE: {integer} = match ::nit::max_nits::compute_mixed_radix_max_value::<{{ <{integer}>::BITS as ::nit::supported::FitsMaximumBits }}>(&Self::__NIT_BASES) {{
+				::core::result::Result::Ok(max) => max as {integer},
+				::core::result::Result::Err(err) => ::core::panic!("{{}}", err.get_str()),
+			}};
+
+			/// Creates a new value from each of its fields.
+			#[must_use]
+			{visibility} fn new({parameters}) -> Self {{
+				Self(0 {constructor})
+			}}
+
+			/// Creates a value from its underlying integer, if it is no greater than [`Self::MAX_VALUE`].
+			#[must_use]
+			{visibility} const fn from_bits(bits: {integer}) -> ::core::option::Option<Self> {{
+				if bits <= Self::MAX_VALUE {{
+					::core::option::Option::Some(Self(bits))
+				}} else {{
+					::core::option::Option::None
+				}}
+			}}
+
+			/// Returns the underlying integer.
+			#[must_use]
+			{visibility} const fn bits(self) -> {integer} {{
+				self.0
+			}}
 
-    #[test]
-    fn it_works() {
-        let result = add(2, 2);
-        assert_eq!(result, 4);
-    }
+			{accessors}
+		}}
+	"#);
+	output.parse().map_err(|_| parse::compile_error("Failed to generate the packed struct."))
 }
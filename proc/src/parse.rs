@@ -0,0 +1,94 @@
+use proc_macro::{Delimiter, TokenStream, TokenTree};
+
+/// Returns whether the token is an identifier equal to `expected`.
+pub fn is_ident(token: Option<&TokenTree>, expected: &str) -> bool {
+	matches!(token, Some(TokenTree::Ident(ident)) if ident.to_string() == expected)
+}
+
+/// Returns whether the token is the punctuation character `expected`.
+pub fn is_punct(token: Option<&TokenTree>, expected: char) -> bool {
+	matches!(token, Some(TokenTree::Punct(punct)) if punct.as_char() == expected)
+}
+
+/// Advances `i` past any outer attributes (`#[...]`), returning their source.
+pub fn take_attributes(tokens: &[TokenTree], i: &mut usize) -> Vec<String> {
+	let mut attributes = Vec::new();
+	while is_punct(tokens.get(*i), '#') {
+		match tokens.get(*i + 1) {
+			Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Bracket => {
+				attributes.push(format!("#{group}"));
+				*i += 2;
+			}
+			_ => break,
+		}
+	}
+	attributes
+}
+
+/// Advances `i` past a visibility modifier (`pub`, `pub(...)`), returning its source; empty if there is none.
+pub fn take_visibility(tokens: &[TokenTree], i: &mut usize) -> String {
+	let mut visibility = String::new();
+	if is_ident(tokens.get(*i), "pub") {
+		visibility.push_str("pub");
+		*i += 1;
+		if let Some(TokenTree::Group(group)) = tokens.get(*i) {
+			if group.delimiter() == Delimiter::Parenthesis {
+				visibility += &group.to_string();
+				*i += 1;
+			}
+		}
+	}
+	visibility
+}
+
+/// Advances `i` past any outer attributes (`#[...]`) and a visibility modifier (`pub`, `pub(...)`).
+pub fn skip_attributes_and_visibility(tokens: &[TokenTree], i: &mut usize) {
+	take_attributes(tokens, i);
+	take_visibility(tokens, i);
+}
+
+/// Returns whether the attribute source is that of a documentation comment.
+pub fn is_doc_attribute(attribute: &str) -> bool {
+	attribute.trim_start_matches(['#', '[', ' ']).starts_with("doc")
+}
+
+/// Returns whether the attribute source is a `derive` which includes the trait named `name`, by any path.
+pub fn is_derive_of(attribute: &str, name: &str) -> bool {
+	let Some(traits) = attribute.trim_start_matches(['#', '[', ' ']).strip_prefix("derive") else { return false };
+	traits
+		.trim_start()
+		.trim_start_matches('(')
+		.trim_end_matches([']', ')', ' '])
+		.split(',')
+		.any(|path| path.rsplit("::").next().is_some_and(|last| last.trim() == name))
+}
+
+/// Splits a stream on its top-level commas.
+/// If `track_angles` is set, commas within angle brackets (e.g. in generic arguments) are kept intact;
+/// this should not be used when the stream may contain expressions, as `<` may then be an operator.
+/// Empty segments, such as one following a trailing comma, are omitted.
+pub fn split_on_commas(stream: TokenStream, track_angles: bool) -> Vec<Vec<TokenTree>> {
+	let mut segments = Vec::new();
+	let mut current = Vec::new();
+	let mut depth = 0_usize;
+	for token in stream {
+		match &token {
+			TokenTree::Punct(punct) if track_angles && punct.as_char() == '<' => depth += 1,
+			TokenTree::Punct(punct) if track_angles && punct.as_char() == '>' => depth = depth.saturating_sub(1),
+			TokenTree::Punct(punct) if punct.as_char() == ',' && depth == 0 => {
+				segments.push(core::mem::take(&mut current));
+				continue;
+			}
+			_ => {}
+		}
+		current.push(token);
+	}
+	segments.push(current);
+	segments.retain(|segment| !segment.is_empty());
+	segments
+}
+
+/// Returns a `compile_error!` invocation with the given message.
+pub fn compile_error(message: &str) -> TokenStream {
+	format!("::core::compile_error!({message:?});").parse().unwrap_or_default()
+}
@@ -0,0 +1,11 @@
+//! Checks that invalid usages of the macros are rejected with a readable error, and that valid ones compile.
+
+#[test]
+fn compile_fail() {
+	trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
+
+#[test]
+fn pass() {
+	trybuild::TestCases::new().pass("tests/pass/*.rs");
+}
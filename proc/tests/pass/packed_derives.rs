@@ -0,0 +1,13 @@
+#[nit::packed(u8)]
+#[derive(Clone, core::marker::Copy, PartialEq, Debug)]
+struct Cell {
+	/// The state of the cell.
+	state: nit::Trit,
+	flag: nit::Bit,
+}
+
+fn main() {
+	let cell = Cell::new(nit::Trit::TWO, nit::Bit::ONE);
+	assert_eq!(cell, cell);
+	assert_eq!(Cell::MAX_VALUE, 5);
+}
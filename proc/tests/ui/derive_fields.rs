@@ -0,0 +1,7 @@
+#[derive(nit::Nit)]
+enum Shape {
+	Point,
+	Circle(u8),
+}
+
+fn main() {}
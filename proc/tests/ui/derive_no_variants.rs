@@ -0,0 +1,4 @@
+#[derive(nit::Nit)]
+enum Never {}
+
+fn main() {}
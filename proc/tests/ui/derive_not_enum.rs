@@ -0,0 +1,6 @@
+#[derive(nit::Nit)]
+struct Flag {
+	set: bool,
+}
+
+fn main() {}
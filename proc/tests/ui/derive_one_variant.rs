@@ -0,0 +1,6 @@
+#[derive(nit::Nit)]
+enum Unit {
+	Only,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[nit::packed(u8)]
+struct Cell {
+	#[allow(dead_code)]
+	state: nit::Trit,
+}
+
+fn main() {}
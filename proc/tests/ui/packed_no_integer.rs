@@ -0,0 +1,6 @@
+#[nit::packed]
+struct Cell {
+	state: nit::Trit,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#[nit::packed(u8)]
+struct Cell {
+	a: nit::Trit,
+	b: nit::Trit,
+	c: nit::Trit,
+	d: nit::Trit,
+	e: nit::Trit,
+	f: nit::Trit,
+}
+
+fn main() {}
@@ -0,0 +1,4 @@
+#[nit::packed(u8)]
+struct Cell(nit::Trit);
+
+fn main() {}
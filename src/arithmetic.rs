@@ -0,0 +1,835 @@
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use crate::Nit;
+use crate::base::Powers;
+use crate::max_nits::compute_max_nits_in_bits;
+use crate::supported::{BaseMaximum, FitsMaximumBits};
+
+/// A type wide enough to hold the product of any two digits, or the sum of any three.
+type Wide = u64;
+
+impl<const BASE: BaseMaximum, const BYTES: usize> Nit<BASE, BYTES> {
+	/// Whether `BASE` is prime, in which case the digits form the finite field $\mathrm{GF}(b)$, and every non-zero digit has an [inverse](Self::inverse).
+	///
+	/// # Example
+	/// ```
+	/// use nit::Nit;
+	/// assert!(Nit::<7>::IS_PRIME);
+	/// assert!(!Nit::<9>::IS_PRIME);
+	/// ```
+	pub const IS_PRIME: bool = {
+		let mut prime = BASE >= 2;
+		let mut divisor = 2;
+		while prime && divisor * divisor <= BASE as Wide {
+			prime = !(BASE as Wide).is_multiple_of(divisor);
+			divisor += 1;
+		}
+		prime
+	};
+
+	/// Evaluated at compile-time, failing if `BASE` isn't prime.
+	const ASSERT_PRIME: () = assert!(Self::IS_PRIME, "Division requires a prime base.");
+
+	/// Creates a digit from a wide value, reducing it modulo `BASE`.
+	#[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+	const fn reduce(value: Wide) -> Self {
+		// The remainder is less than `BASE`, so it fits and is within the range of `0..BASE`.
+		Self::from_value((value % BASE as Wide) as BaseMaximum)
+	}
+
+	/// Creates a digit from a wide value that is known to be less than `BASE`.
+	#[allow(clippy::cast_possible_truncation)]
+	const fn narrow(value: Wide) -> Self {
+		Self::from_value(value as BaseMaximum)
+	}
+
+	/// Returns `(self + rhs) % BASE`.
+	///
+	/// # Example
+	/// ```
+	/// use nit::Trit;
+	/// assert_eq!(Trit::TWO.wrapping_add(Trit::TWO), Trit::ONE);
+	/// assert_eq!(Trit::TWO + Trit::ONE, Trit::ZERO);
+	/// ```
+	#[must_use]
+	pub const fn wrapping_add(self, rhs: Self) -> Self {
+		self.overflowing_add(rhs).0
+	}
+
+	/// Returns `(self - rhs) % BASE`, wrapping around below zero.
+	///
+	/// # Example
+	/// ```
+	/// use nit::Trit;
+	/// assert_eq!(Trit::ZERO.wrapping_sub(Trit::ONE), Trit::TWO);
+	/// assert_eq!(Trit::ONE - Trit::TWO, Trit::TWO);
+	/// ```
+	#[must_use]
+	pub const fn wrapping_sub(self, rhs: Self) -> Self {
+		self.overflowing_sub(rhs).0
+	}
+
+	/// Returns `(self * rhs) % BASE`.
+	///
+	/// # Example
+	/// ```
+	/// use nit::Nit;
+	/// let three = Nit::<5>::new(3).unwrap();
+	/// assert_eq!(three.wrapping_mul(three), Nit::new(4).unwrap());
+	/// assert_eq!(three * three, Nit::new(4).unwrap());
+	/// ```
+	#[must_use]
+	pub const fn wrapping_mul(self, rhs: Self) -> Self {
+		self.mul_with_carry(rhs, Self::from_value(0)).0
+	}
+
+	/// Returns the additive inverse modulo `BASE`; the digit which sums with this one to zero.
+	///
+	/// # Example
+	/// ```
+	/// use nit::Trit;
+	/// assert_eq!(Trit::ONE.wrapping_neg(), Trit::TWO);
+	/// assert_eq!(-Trit::ZERO, Trit::ZERO);
+	/// ```
+	#[must_use]
+	pub const fn wrapping_neg(self) -> Self {
+		Self::from_value(0).wrapping_sub(self)
+	}
+
+	/// Returns `self + rhs`, or [`None`] if the sum isn't within the range of `0..BASE`.
+	#[must_use]
+	pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+		match self.overflowing_add(rhs) {
+			(sum, false) => Some(sum),
+			(_, true) => None,
+		}
+	}
+
+	/// Returns `self - rhs`, or [`None`] if the difference is negative.
+	#[must_use]
+	pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+		match self.overflowing_sub(rhs) {
+			(difference, false) => Some(difference),
+			(_, true) => None,
+		}
+	}
+
+	/// Returns `self * rhs`, or [`None`] if the product isn't within the range of `0..BASE`.
+	#[must_use]
+	pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+		match self.overflowing_mul(rhs) {
+			(product, false) => Some(product),
+			(_, true) => None,
+		}
+	}
+
+	/// Returns `self + rhs` wrapped modulo `BASE`, along with whether it wrapped; a carry into the next place.
+	///
+	/// # Example
+	/// ```
+	/// use nit::Trit;
+	/// assert_eq!(Trit::ONE.overflowing_add(Trit::ONE), (Trit::TWO, false));
+	/// assert_eq!(Trit::TWO.overflowing_add(Trit::TWO), (Trit::ONE, true));
+	/// ```
+	#[must_use]
+	pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+		let (sum, carry) = self.add_with_carry(rhs, Self::from_value(0));
+		(sum, carry.get_value() != 0)
+	}
+
+	/// Returns `self - rhs` wrapped modulo `BASE`, along with whether it wrapped; a borrow from the next place.
+	///
+	/// # Example
+	/// ```
+	/// use nit::Trit;
+	/// assert_eq!(Trit::TWO.overflowing_sub(Trit::ONE), (Trit::ONE, false));
+	/// assert_eq!(Trit::ZERO.overflowing_sub(Trit::ONE), (Trit::TWO, true));
+	/// ```
+	#[must_use]
+	pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+		if self.get_value() >= rhs.get_value() {
+			(Self::from_value(self.get_value() - rhs.get_value()), false)
+		} else {
+			// The sum is less than `BASE`, as `rhs` is greater than `self`.
+			(Self::from_value(BASE - rhs.get_value() + self.get_value()), true)
+		}
+	}
+
+	/// Returns `self * rhs` wrapped modulo `BASE`, along with whether it wrapped.
+	///
+	/// The digit that was wrapped is given by [`Self::mul_with_carry`].
+	#[must_use]
+	pub const fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+		let (product, carry) = self.mul_with_carry(rhs, Self::from_value(0));
+		(product, carry.get_value() != 0)
+	}
+
+	/// Returns `self + rhs + carry` as a full adder would; the resulting digit and the carry into the next place.
+	///
+	/// The carry in can be any digit, so the carry out is at most two, or one for a base of two.
+	///
+	/// # Example
+	/// ```
+	/// use nit::Trit;
+	/// // Adding 13 (111) and 14 (112) in ternary, starting from the least significant digit.
+	/// let (a, b) = ([Trit::ONE, Trit::ONE, Trit::ONE], [Trit::TWO, Trit::ONE, Trit::ONE]);
+	/// let mut carry = Trit::ZERO;
+	/// let mut sum = [Trit::ZERO; 3];
+	/// for i in 0..3 {
+	///     (sum[i], carry) = a[i].add_with_carry(b[i], carry);
+	/// }
+	/// assert_eq!((sum, carry), ([Trit::ZERO, Trit::ZERO, Trit::ZERO], Trit::ONE)); // 27 (1000)
+	/// ```
+	#[must_use]
+	pub const fn add_with_carry(self, rhs: Self, carry: Self) -> (Self, Self) {
+		let total = self.get_value() as Wide + rhs.get_value() as Wide + carry.get_value() as Wide;
+		// The total is at most `3 * (BASE - 1)`, so the quotient is less than `BASE` for any base greater than two, and at most one for a base of two.
+		(Self::reduce(total), Self::narrow(total / BASE as Wide))
+	}
+
+	/// Returns `self * rhs + carry` as a long multiplication would; the resulting digit and the carry into the next place.
+	///
+	/// # Example
+	/// ```
+	/// use nit::Nit;
+	/// let digit = |value| Nit::<10>::new(value).unwrap();
+	/// assert_eq!(digit(7).mul_with_carry(digit(8), digit(3)), (digit(9), digit(5))); // 59
+	/// ```
+	#[must_use]
+	pub const fn mul_with_carry(self, rhs: Self, carry: Self) -> (Self, Self) {
+		let total = self.get_value() as Wide * rhs.get_value() as Wide + carry.get_value() as Wide;
+		// The total is at most `(BASE - 1)^2 + (BASE - 1) = BASE * (BASE - 1)`, so the quotient is less than `BASE`.
+		(Self::reduce(total), Self::narrow(total / BASE as Wide))
+	}
+
+	/// Returns the multiplicative inverse modulo `BASE`; the digit which multiplies with this one to one.
+	/// Returns [`None`] for zero, which has no inverse.
+	///
+	/// This fails to compile if `BASE` isn't prime (see [`Self::IS_PRIME`]), as some non-zero digits would have no inverse.
+	///
+	/// # Example
+	/// ```
+	/// use nit::Nit;
+	/// let quint = |value| Nit::<5>::new(value).unwrap();
+	/// assert_eq!(quint(2).inverse(), Some(quint(3)));
+	/// assert_eq!(quint(0).inverse(), None);
+	/// assert_eq!(quint(4) / quint(2), quint(2));
+	/// assert_eq!(quint(4).checked_div(quint(0)), None);
+	/// ```
+	///
+	/// ```compile_fail
+	/// use nit::Nit;
+	/// let _ = Nit::<4>::new(3).unwrap().inverse();
+	/// ```
+	#[must_use]
+	pub const fn inverse(self) -> Option<Self> {
+		let () = Self::ASSERT_PRIME;
+		if self.get_value() == 0 {
+			return None;
+		}
+		// The extended Euclidean algorithm, only tracking the coefficient of `self`.
+		let (mut remainder, mut next_remainder) = (BASE as i64, self.get_value() as i64);
+		let (mut coefficient, mut next_coefficient) = (0_i64, 1_i64);
+		while next_remainder != 0 {
+			let quotient = remainder / next_remainder;
+			(remainder, next_remainder) = (next_remainder, remainder - quotient * next_remainder);
+			(coefficient, next_coefficient) = (next_coefficient, coefficient - quotient * next_coefficient);
+		}
+		// The coefficient is within `-BASE..BASE`, so bringing it into the range of `0..BASE` only needs one addition.
+		#[allow(clippy::cast_sign_loss)]
+		let inverse = coefficient.rem_euclid(BASE as i64) as Wide;
+		Some(Self::narrow(inverse))
+	}
+
+	/// Returns `self / rhs` in the finite field; `self` multiplied by the [inverse](Self::inverse) of `rhs`.
+	/// Returns [`None`] if `rhs` is zero, where the `/` operator panics instead.
+	///
+	/// This fails to compile if `BASE` isn't prime (see [`Self::IS_PRIME`]).
+	#[must_use]
+	pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+		match rhs.inverse() {
+			Some(inverse) => Some(self.wrapping_mul(inverse)),
+			None => None,
+		}
+	}
+}
+
+/// Implements a binary operator and its assigning counterpart for [`Nit`] through the given method.
+macro_rules! impl_nit_operator {
+	($($trait: ident, $function: ident, $assign_trait: ident, $assign_function: ident => $method: ident),*) => {
+		$(
+			impl<const BASE: BaseMaximum, const BYTES: usize> $trait for Nit<BASE, BYTES> {
+				type Output = Self;
+
+				#[doc = concat!("See [`Nit::", stringify!($method), "`].")]
+				fn $function(self, rhs: Self) -> Self {
+					self.$method(rhs)
+				}
+			}
+
+			impl<const BASE: BaseMaximum, const BYTES: usize> $assign_trait for Nit<BASE, BYTES> {
+				#[doc = concat!("See [`Nit::", stringify!($method), "`].")]
+				fn $assign_function(&mut self, rhs: Self) {
+					*self = self.$method(rhs);
+				}
+			}
+		)*
+	};
+}
+impl_nit_operator!(
+	Add, add, AddAssign, add_assign => wrapping_add,
+	Sub, sub, SubAssign, sub_assign => wrapping_sub,
+	Mul, mul, MulAssign, mul_assign => wrapping_mul
+);
+
+impl<const BASE: BaseMaximum, const BYTES: usize> Neg for Nit<BASE, BYTES> {
+	type Output = Self;
+
+	/// See [`Nit::wrapping_neg`].
+	fn neg(self) -> Self {
+		self.wrapping_neg()
+	}
+}
+
+impl<const BASE: BaseMaximum, const BYTES: usize> Div for Nit<BASE, BYTES> {
+	type Output = Self;
+
+	/// See [`Nit::checked_div`], which doesn't panic.
+	///
+	/// # Panics
+	/// - If `rhs` is zero, as with dividing an integer by zero.
+	fn div(self, rhs: Self) -> Self {
+		let Some(quotient) = self.checked_div(rhs) else { panic!("attempt to divide by zero") };
+		quotient
+	}
+}
+
+impl<const BASE: BaseMaximum, const BYTES: usize> DivAssign for Nit<BASE, BYTES> {
+	/// See [`Nit::checked_div`], which doesn't panic.
+	///
+	/// # Panics
+	/// - If `rhs` is zero, as with dividing an integer by zero.
+	fn div_assign(&mut self, rhs: Self) {
+		*self = *self / rhs;
+	}
+}
+
+/// Digit-wise arithmetic across every base-`BASE` digit of a packed integer.
+///
+/// Each operator is equivalent to combining the digits at each place within the nit capacity with the operator of the same name on [`Nit`],
+/// as done by [`NitwiseLogic::zip_nits`](crate::logic::NitwiseLogic::zip_nits), which serves as the scalar reference implementation.
+/// Anything beyond the nit capacity is discarded from the result, as is everything if the base is erroneous for the type.
+///
+/// Every place is computed at once on whole words (SWAR), whatever the base.
+/// - If `BASE` is a power of two, each digit occupies its own lane of bits, and the lanes are operated on all at once without any carry crossing between them.
+/// - Otherwise, the digit-wise result differs from the plain result only by the carries (or borrows) out of each place,
+///   as $\sum\_i \left((a\_i + b\_i) \bmod b\right) b^i = x + y - b \sum\_i c\_i b^i$ where $c\_i = \left[a\_i + b\_i \ge b\right]$,
+///   so the sum is computed on the whole words, and so are the carries: the digits are spread out into lanes of bits with room for twice the base,
+///   through a table of several places at a time, where the carries of every place are found at once, before being packed back together by combining neighbouring lanes in pairs,
+///   in as many steps as the logarithm of the amount of places.
+///
+/// # Example
+/// ```
+/// use nit::arithmetic::NitwiseArithmetic;
+/// use nit::prelude::*;
+/// use nit::Trit;
+///
+/// let a = u16::from_nits(&[Trit::TWO, Trit::ONE, Trit::ZERO]).unwrap();
+/// let b = u16::from_nits(&[Trit::TWO, Trit::TWO, Trit::ONE]).unwrap();
+/// assert_eq!(a.nitwise_add::<3>(b), u16::from_nits(&[Trit::ONE, Trit::ZERO, Trit::ONE]).unwrap());
+/// assert_eq!(a.nitwise_sub::<3>(b), u16::from_nits(&[Trit::ZERO, Trit::TWO, Trit::TWO]).unwrap());
+/// assert_eq!(a.nitwise_min::<3>(b), a);
+/// assert_eq!(a.nitwise_max::<3>(b), b);
+/// // Binary digits are bits, so this is an exclusive or.
+/// assert_eq!(0b1100_u8.nitwise_add::<2>(0b1010), 0b0110);
+/// ```
+pub trait NitwiseArithmetic: Sized {
+	/// Returns the [sum](Nit::wrapping_add) modulo `BASE` at each place.
+	#[must_use]
+	fn nitwise_add<const BASE: BaseMaximum>(self, rhs: Self) -> Self;
+
+	/// Returns the [difference](Nit::wrapping_sub) modulo `BASE` at each place.
+	#[must_use]
+	fn nitwise_sub<const BASE: BaseMaximum>(self, rhs: Self) -> Self;
+
+	/// Returns the lesser digit at each place.
+	#[must_use]
+	fn nitwise_min<const BASE: BaseMaximum>(self, rhs: Self) -> Self;
+
+	/// Returns the greater digit at each place.
+	#[must_use]
+	fn nitwise_max<const BASE: BaseMaximum>(self, rhs: Self) -> Self;
+}
+
+/// The building blocks of [`NitwiseArithmetic`] for each primitive integer type.
+trait PackedPlaces: Sized {
+	/// Returns the value with every place beyond the nit capacity discarded.
+	fn reduce<const BASE: BaseMaximum>(self) -> Self;
+
+	/// Returns `(low, high)`, where `high` has the highest bit of each lane of a power-of-two `BASE` set, and `low` has the rest of the lanes' bits set.
+	fn lanes<const BASE: BaseMaximum>() -> (Self, Self);
+
+	/// Returns the lanes computed by `f` from the [`Lanes`] of both values, packed back together, for the digits within the nit capacity of both values, which must have no others.
+	fn lanewise<const BASE: BaseMaximum, F: Fn(u128, u128) -> u128>(self, rhs: Self, f: F) -> Self;
+
+	/// Returns a mask of the lanes of a power-of-two `BASE` whose digit is less than the digit at the same place of `rhs`.
+	fn lanes_less_than<const BASE: BaseMaximum>(self, rhs: Self) -> Self;
+}
+
+/// The digits of a segment of a packed integer, spread out into lanes of bits within a `u128`, one place per lane.
+///
+/// This is how [`NitwiseArithmetic`] operates on every place of a base other than a power of two at once:
+/// each lane has room for twice the base, so adding two digits (or subtracting one from the other, with the highest bit set) can't carry into the next lane.
+struct Lanes<const BASE: BaseMaximum>;
+
+impl<const BASE: BaseMaximum> Lanes<BASE> {
+	/// The width of each lane, whose highest bit is clear for any digit, and which holds `2 * BASE` without overflowing.
+	const WIDTH: u32 = BaseMaximum::BITS - BASE.saturating_sub(1).leading_zeros() + 1;
+
+	/// The amount of lanes in a segment: as many as fit within a `u128`, but no more places than fit within a `u64`.
+	#[allow(clippy::cast_possible_truncation)]
+	const PER_SEGMENT: FitsMaximumBits = {
+		let lanes = (u128::BITS / Self::WIDTH) as FitsMaximumBits;
+		let places = Powers::<u64, BASE>::CAPACITY;
+		if lanes < places { lanes } else { places }
+	};
+
+	/// The amount of places spread out at once through [`SPREAD`](Self::SPREAD): as many as have no more than 256 combinations of digits.
+	const CHUNK: FitsMaximumBits = {
+		let mut chunk = 1;
+		let mut combinations = BASE as u64;
+		while BASE >= 2 && combinations * (BASE as u64) <= 256 {
+			chunk += 1;
+			combinations *= BASE as u64;
+		}
+		chunk
+	};
+
+	/// Each value of [`CHUNK`](Self::CHUNK) places, with its digits spread out into their lanes.
+	const SPREAD: [u64; 256] = {
+		let mut spread = [0; 256];
+		let mut value = 0;
+		while BASE >= 2 && value < spread.len() {
+			let (mut rest, mut lane) = (value as u64, 0);
+			while rest > 0 {
+				spread[value] |= (rest % BASE as u64) << (lane * Self::WIDTH);
+				rest /= BASE as u64;
+				lane += 1;
+			}
+			value += 1;
+		}
+		spread
+	};
+
+	/// The lowest bit of every lane.
+	const ONES: u128 = {
+		let mut ones = 0;
+		let mut lane = 0;
+		while lane < Self::PER_SEGMENT as u32 {
+			ones |= 1 << (lane * Self::WIDTH);
+			lane += 1;
+		}
+		ones
+	};
+
+	/// The highest bit of every lane.
+	const HIGHS: u128 = Self::ONES << (Self::WIDTH - 1);
+
+	/// Added to every lane so that its highest bit is set exactly where its digit is at least `BASE`.
+	const EXCESS: u128 = Self::ONES * ((1 << (Self::WIDTH - 1)) - BASE as u128);
+
+	/// The masks used by each step of [`pack`](Self::pack), selecting the lower group of lanes of each pair of neighbouring groups.
+	const PAIRS: [u128; 7] = {
+		let mut pairs = [u128::MAX; 7];
+		let mut step = 0;
+		while step < pairs.len() {
+			let width = Self::WIDTH << step;
+			if width < u128::BITS {
+				let mut mask = 0;
+				let mut offset = 0;
+				while offset < u128::BITS {
+					mask |= ((1 << width) - 1) << offset;
+					offset += 2 * width;
+				}
+				pairs[step] = mask;
+			}
+			step += 1;
+		}
+		pairs
+	};
+
+	/// Spreads out the digits of a value of `places` places into their lanes, a [chunk](Self::CHUNK) of places at a time.
+	fn unpack(value: u64, places: FitsMaximumBits) -> u128 {
+		if places <= Self::CHUNK {
+			#[allow(clippy::cast_possible_truncation)]
+			return u128::from(if Self::CHUNK == 1 { value } else { Self::SPREAD[value as usize] });
+		}
+		// There is more than one chunk, so the chunk is within the nit capacity of a `u64`.
+		let reciprocal = Powers::<u64, BASE>::RECIPROCALS[usize::from(Self::CHUNK)];
+		let (mut lanes, mut rest, mut place) = (0, value, 0);
+		while place < places {
+			// The last chunk holds every remaining place, so it needn't be split off.
+			let (high, chunk) = if place + Self::CHUNK >= places {
+				(0, rest)
+			} else {
+				let high = reciprocal.divide(rest);
+				(high, rest - high * reciprocal.divisor())
+			};
+			#[allow(clippy::cast_possible_truncation)]
+			let spread = if Self::CHUNK == 1 { chunk } else { Self::SPREAD[chunk as usize] };
+			lanes |= u128::from(spread) << (u32::from(place) * Self::WIDTH);
+			(rest, place) = (high, place + Self::CHUNK);
+		}
+		lanes
+	}
+
+	/// Packs the lanes of `places` places back together, by combining each pair of neighbouring groups of lanes into one at each step.
+	fn pack(lanes: u128, places: FitsMaximumBits) -> u64 {
+		let (mut lanes, mut group, mut step) = (lanes, 1, 0);
+		while group < places {
+			// A pair of groups of `group` places is less than `BASE.pow(2 * group)`, which fits within their lanes.
+			let mask = Self::PAIRS[step];
+			lanes = (lanes & mask) + ((lanes >> (Self::WIDTH << step)) & mask) * u128::from(Powers::<u64, BASE>::TABLE[usize::from(group)]);
+			(group, step) = (group * 2, step + 1);
+		}
+		#[allow(clippy::cast_possible_truncation)]
+		let packed = lanes as u64;
+		packed
+	}
+
+	/// Returns one in each lane where the sum of the digits is at least `BASE`.
+	const fn carries(lhs: u128, rhs: u128) -> u128 {
+		((lhs + rhs + Self::EXCESS) >> (Self::WIDTH - 1)) & Self::ONES
+	}
+
+	/// Returns one in each lane where the digit of `lhs` is less than that of `rhs`.
+	const fn borrows(lhs: u128, rhs: u128) -> u128 {
+		// Each lane holds `2.pow(WIDTH - 1) + a - b`, which is positive, and has its highest bit clear only if `a < b`.
+		(!((lhs | Self::HIGHS) - rhs) >> (Self::WIDTH - 1)) & Self::ONES
+	}
+
+	/// Returns the amount the digit of `lhs` exceeds that of `rhs` by in each lane, or zero where it doesn't.
+	const fn excess(lhs: u128, rhs: u128) -> u128 {
+		let difference = (lhs | Self::HIGHS) - rhs;
+		// Spreading the comparison of each lane across it can't carry into the next lane.
+		let at_least = ((difference >> (Self::WIDTH - 1)) & Self::ONES) * ((1 << Self::WIDTH) - 1);
+		difference & at_least & !Self::HIGHS
+	}
+}
+
+/// Generates implementations of [`NitwiseArithmetic`] and [`PackedPlaces`] for each primitive integer type provided.
+macro_rules! impl_nitwise_arithmetic {
+	($($type: ty),*) => {
+		$(
+			// The base and digits fit within the type wherever the capacity is checked to be non-zero, before any of them are cast.
+			#[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+			impl PackedPlaces for $type {
+				fn reduce<const BASE: BaseMaximum>(self) -> Self {
+					#[allow(clippy::cast_possible_truncation)]
+					let Ok(capacity) = compute_max_nits_in_bits::<BASE, { <$type>::BITS as FitsMaximumBits }>() else { return 0 };
+					if BASE.is_power_of_two() {
+						let (low, high) = Self::lanes::<BASE>();
+						return self & (low | high);
+					}
+					// `BASE.pow(capacity)` fits within the type for any base other than two, which was handled as a power of two.
+					let top = usize::from(capacity) - 1;
+					let quotient = Powers::<$type, BASE>::BASE_RECIPROCAL.divide(Powers::<$type, BASE>::RECIPROCALS[top].divide(self));
+					self - quotient * (Powers::<$type, BASE>::TABLE[top] * (BASE as $type))
+				}
+
+				fn lanes<const BASE: BaseMaximum>() -> (Self, Self) {
+					#[allow(clippy::cast_possible_truncation)]
+					let Ok(capacity) = compute_max_nits_in_bits::<BASE, { <$type>::BITS as FitsMaximumBits }>() else { return (0, 0) };
+					let width = BASE.trailing_zeros();
+					let mut high: $type = 0;
+					for place in 0..u32::from(capacity) {
+						high |= 1 << (place * width + width - 1);
+					}
+					let all = <$type>::MAX >> (<$type>::BITS - u32::from(capacity) * width);
+					(all & !high, high)
+				}
+
+				fn lanewise<const BASE: BaseMaximum, F: Fn(u128, u128) -> u128>(self, rhs: Self, f: F) -> Self {
+					let capacity = Powers::<$type, BASE>::CAPACITY;
+					let (mut lhs, mut rhs, mut packed, mut place) = (self as u128, rhs as u128, 0, 0);
+					while place < capacity {
+						let places = Lanes::<BASE>::PER_SEGMENT.min(capacity - place);
+						// The last segment holds every remaining place, so it needn't be split off, which spares a `u128` division for every other type.
+						let ((lhs_rest, lhs_segment), (rhs_rest, rhs_segment)) = if place + places == capacity {
+							((0, lhs), (0, rhs))
+						} else {
+							(Powers::<u128, BASE>::split_at_place(lhs, places), Powers::<u128, BASE>::split_at_place(rhs, places))
+						};
+						// Each segment has no more places than fit within a `u64`, and each place is a digit again once packed.
+						let lanes = f(Lanes::<BASE>::unpack(lhs_segment as u64, places), Lanes::<BASE>::unpack(rhs_segment as u64, places));
+						packed = (Lanes::<BASE>::pack(lanes, places) as $type).wrapping_mul(Powers::<$type, BASE>::power(place)).wrapping_add(packed);
+						(lhs, rhs, place) = (lhs_rest, rhs_rest, place + places);
+					}
+					packed
+				}
+
+				fn lanes_less_than<const BASE: BaseMaximum>(self, rhs: Self) -> Self {
+					let (low, high) = Self::lanes::<BASE>();
+					let difference = ((self | high) - (rhs & low)) ^ ((self ^ !rhs) & high);
+					// The borrow out of the highest bit of each lane, as a full subtractor would produce it.
+					let borrows = ((!self & rhs) | (!(self ^ rhs) & difference)) & high;
+					// Spreading each borrow across its lane can't carry into the next lane.
+					(borrows >> (BASE.trailing_zeros() - 1)).wrapping_mul((BASE - 1) as $type)
+				}
+			}
+
+			#[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+			impl NitwiseArithmetic for $type {
+				fn nitwise_add<const BASE: BaseMaximum>(self, rhs: Self) -> Self {
+					if BASE.is_power_of_two() {
+						let (low, high) = Self::lanes::<BASE>();
+						// The lower bits of each lane can't carry out of it, and the highest bit is added without a carry.
+						return ((self & low) + (rhs & low)) ^ ((self ^ rhs) & high);
+					}
+					let (lhs, rhs) = (self.reduce::<BASE>(), rhs.reduce::<BASE>());
+					let carries = lhs.lanewise::<BASE, _>(rhs, Lanes::<BASE>::carries);
+					lhs.wrapping_add(rhs).wrapping_sub(carries.wrapping_mul(BASE as $type))
+				}
+
+				fn nitwise_sub<const BASE: BaseMaximum>(self, rhs: Self) -> Self {
+					if BASE.is_power_of_two() {
+						let (low, high) = Self::lanes::<BASE>();
+						// Setting the highest bit of each lane ensures that nothing is borrowed from the next lane.
+						return (((self | high) - (rhs & low)) ^ ((self ^ !rhs) & high)) & (low | high);
+					}
+					let (lhs, rhs) = (self.reduce::<BASE>(), rhs.reduce::<BASE>());
+					let borrows = lhs.lanewise::<BASE, _>(rhs, Lanes::<BASE>::borrows);
+					lhs.wrapping_sub(rhs).wrapping_add(borrows.wrapping_mul(BASE as $type))
+				}
+
+				fn nitwise_min<const BASE: BaseMaximum>(self, rhs: Self) -> Self {
+					let (lhs, rhs) = (self.reduce::<BASE>(), rhs.reduce::<BASE>());
+					if BASE.is_power_of_two() {
+						let less = lhs.lanes_less_than::<BASE>(rhs);
+						return (lhs & less) | (rhs & !less);
+					}
+					// The minimum is the left-hand side, less the amount it exceeds the right-hand side by at each place.
+					lhs - lhs.lanewise::<BASE, _>(rhs, Lanes::<BASE>::excess)
+				}
+
+				fn nitwise_max<const BASE: BaseMaximum>(self, rhs: Self) -> Self {
+					let (lhs, rhs) = (self.reduce::<BASE>(), rhs.reduce::<BASE>());
+					if BASE.is_power_of_two() {
+						let less = lhs.lanes_less_than::<BASE>(rhs);
+						return (rhs & less) | (lhs & !less);
+					}
+					// The maximum is the right-hand side, plus the amount the left-hand side exceeds it by at each place.
+					rhs + lhs.lanewise::<BASE, _>(rhs, Lanes::<BASE>::excess)
+				}
+			}
+		)*
+	};
+}
+impl_nitwise_arithmetic!(u8, u16, u32, u64, u128);
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::missing_docs_in_private_items)]
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+
+	fn digits<const BASE: BaseMaximum>() -> impl Iterator<Item = Nit<BASE>> + Clone {
+		(0..BASE).map(|value| Nit::new(value).unwrap())
+	}
+
+	fn check_ring<const BASE: BaseMaximum>() {
+		let base = BASE;
+		for a in digits::<BASE>() {
+			let x = a.get_value();
+			assert_eq!((-a).get_value(), (base - x) % base);
+			for b in digits::<BASE>() {
+				let y = b.get_value();
+				assert_eq!((a + b).get_value(), (x + y) % base);
+				assert_eq!((a - b).get_value(), (x + base - y) % base);
+				assert_eq!((a * b).get_value(), (x * y) % base);
+				assert_eq!(a.checked_add(b).is_some(), x + y < base);
+				assert_eq!(a.checked_sub(b).is_some(), x >= y);
+				assert_eq!(a.checked_mul(b).is_some(), x * y < base);
+				for c in digits::<BASE>() {
+					let z = c.get_value();
+					let (sum, carry) = a.add_with_carry(b, c);
+					assert_eq!(sum.get_value() + carry.get_value() * base, x + y + z);
+					let (product, carry) = a.mul_with_carry(b, c);
+					assert_eq!(product.get_value() + carry.get_value() * base, x * y + z);
+				}
+			}
+		}
+	}
+
+	fn check_field<const BASE: BaseMaximum>() {
+		let one = Nit::<BASE>::new(1).unwrap();
+		assert_eq!(one.checked_div(Nit::new(0).unwrap()), None);
+		for a in digits::<BASE>().skip(1) {
+			assert_eq!(a * a.inverse().unwrap(), one);
+			for b in digits::<BASE>() {
+				assert_eq!(b.checked_div(a), Some(b / a));
+				assert_eq!(b / a * a, b);
+				let mut quotient = b;
+				quotient /= a;
+				assert_eq!(quotient, b / a);
+			}
+		}
+	}
+
+	#[test]
+	fn ring() {
+		check_ring::<2>();
+		check_ring::<3>();
+		check_ring::<6>();
+		check_ring::<10>();
+		check_ring::<128>();
+	}
+
+	#[test]
+	fn field() {
+		check_field::<2>();
+		check_field::<3>();
+		check_field::<5>();
+		check_field::<7>();
+		check_field::<127>();
+	}
+
+	#[test]
+	#[should_panic(expected = "attempt to divide by zero")]
+	fn dividing_by_zero() {
+		let _ = Nit::<5>::new(1).unwrap() / Nit::new(0).unwrap();
+	}
+
+	macro_rules! make_nitwise_reference_tests {
+		($(($name: ident, $type: ty)),*) => {
+			$(
+				#[test]
+				#[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+				fn $name() {
+					use crate::logic::NitwiseLogic;
+					fn check<const BASE: BaseMaximum, const BYTES: usize>(x: $type, y: $type) {
+						assert_eq!(x.nitwise_add::<BASE>(y), x.zip_nits::<BASE, BYTES, _>(&y, Nit::wrapping_add), "{x} + {y} in base {BASE}");
+						assert_eq!(x.nitwise_sub::<BASE>(y), x.zip_nits::<BASE, BYTES, _>(&y, Nit::wrapping_sub), "{x} - {y} in base {BASE}");
+						assert_eq!(x.nitwise_min::<BASE>(y), x.zip_nits::<BASE, BYTES, _>(&y, Nit::and), "min({x}, {y}) in base {BASE}");
+						assert_eq!(x.nitwise_max::<BASE>(y), x.zip_nits::<BASE, BYTES, _>(&y, Nit::or), "max({x}, {y}) in base {BASE}");
+					}
+					let mut samples = vec![0, 1, 2, <$type>::MAX, <$type>::MAX - 1, <$type>::MAX / 3];
+					// A cheap generator with good enough coverage of every bit position.
+					let mut state: $type = 0x9E37_79B9_u32 as $type;
+					for _ in 0..40 {
+						state = state.wrapping_mul(0x5851_F42D_u32 as $type).wrapping_add(0x1405_7B7E_u32 as $type) ^ (state >> 3);
+						samples.push(state);
+					}
+					for &x in &samples {
+						for &y in &samples {
+							check::<2, 1>(x, y);
+							check::<3, 1>(x, y);
+							check::<4, 1>(x, y);
+							check::<5, 1>(x, y);
+							check::<8, 1>(x, y);
+							check::<10, 1>(x, y);
+							check::<16, 1>(x, y);
+							check::<64, 1>(x, y);
+							check::<100, 1>(x, y);
+							check::<127, 1>(x, y);
+							check::<128, 1>(x, y);
+							check::<1000, 2>(x, y);
+							check::<60000, 2>(x, y);
+						}
+					}
+				}
+			)*
+		};
+	}
+
+	make_nitwise_reference_tests!(
+		(nitwise_reference_u16, u16),
+		(nitwise_reference_u32, u32),
+		(nitwise_reference_u64, u64),
+		(nitwise_reference_u128, u128)
+	);
+
+	#[test]
+	fn nitwise_exhaustive_u8() {
+		use crate::logic::NitwiseLogic;
+		fn check<const BASE: BaseMaximum, const BYTES: usize>() {
+			for x in 0..=u8::MAX {
+				for y in 0..=u8::MAX {
+					assert_eq!(x.nitwise_add::<BASE>(y), x.zip_nits::<BASE, BYTES, _>(&y, Nit::wrapping_add));
+					assert_eq!(x.nitwise_sub::<BASE>(y), x.zip_nits::<BASE, BYTES, _>(&y, Nit::wrapping_sub));
+					assert_eq!(x.nitwise_min::<BASE>(y), x.zip_nits::<BASE, BYTES, _>(&y, Nit::and));
+					assert_eq!(x.nitwise_max::<BASE>(y), x.zip_nits::<BASE, BYTES, _>(&y, Nit::or));
+				}
+			}
+		}
+		check::<2, 1>();
+		check::<3, 1>();
+		check::<4, 1>();
+		check::<6, 1>();
+		check::<8, 1>();
+		check::<15, 1>();
+		check::<16, 1>();
+		check::<128, 1>();
+	}
+
+	#[test]
+	#[allow(clippy::cast_possible_truncation)]
+	fn nitwise_wide_bases() {
+		// Digits of these bases don't fit within a `Nit` of one byte, so each place is compared against `u128` arithmetic instead.
+		fn reference<const BASE: BaseMaximum>(x: u128, y: u128, capacity: u32, f: impl Fn(u128, u128) -> u128) -> u128 {
+			let base = u128::from(BASE);
+			(0..capacity).rev().fold(0, |packed, place| {
+				packed * base + f(x / base.pow(place) % base, y / base.pow(place) % base)
+			})
+		}
+		fn check<const BASE: BaseMaximum>(x: u128, y: u128) {
+			let base = u128::from(BASE);
+			let add = |a, b| (a + b) % base;
+			let sub = |a, b| (a + base - b) % base;
+			let capacity = compute_max_nits_in_bits::<BASE, 64>().unwrap().into();
+			let (small_x, small_y) = (x as u64, y as u64);
+			assert_eq!(u128::from(small_x.nitwise_add::<BASE>(small_y)), reference::<BASE>(small_x.into(), small_y.into(), capacity, add));
+			assert_eq!(u128::from(small_x.nitwise_sub::<BASE>(small_y)), reference::<BASE>(small_x.into(), small_y.into(), capacity, sub));
+			assert_eq!(u128::from(small_x.nitwise_min::<BASE>(small_y)), reference::<BASE>(small_x.into(), small_y.into(), capacity, u128::min));
+			assert_eq!(u128::from(small_x.nitwise_max::<BASE>(small_y)), reference::<BASE>(small_x.into(), small_y.into(), capacity, u128::max));
+			let capacity = compute_max_nits_in_bits::<BASE, 128>().unwrap().into();
+			assert_eq!(x.nitwise_add::<BASE>(y), reference::<BASE>(x, y, capacity, add));
+			assert_eq!(x.nitwise_sub::<BASE>(y), reference::<BASE>(x, y, capacity, sub));
+			assert_eq!(x.nitwise_min::<BASE>(y), reference::<BASE>(x, y, capacity, u128::min));
+			assert_eq!(x.nitwise_max::<BASE>(y), reference::<BASE>(x, y, capacity, u128::max));
+		}
+		let mut samples = vec![0, 1, u128::MAX, u128::MAX / 3, u128::from(u64::MAX)];
+		let mut state = 0x9E37_79B9_u128;
+		for _ in 0..40 {
+			state = state.wrapping_mul(0x5851_F42D_4C95_7F2D).wrapping_add(0x1405_7B7E_F767_814F) ^ (state >> 7);
+			samples.push(state);
+		}
+		for &x in &samples {
+			for &y in &samples {
+				check::<1000>(x, y);
+				check::<60_000>(x, y);
+				check::<2_147_483_649>(x, y);
+				check::<3_000_000_001>(x, y);
+				check::<4_294_967_295>(x, y);
+			}
+		}
+	}
+
+	#[test]
+	fn nitwise_beyond_a_signed_digit() {
+		// The digits of both places overflow a `u32` when added, and the lower digit of the left-hand side is less than that of the right-hand side.
+		const BASE: BaseMaximum = 3_000_000_001;
+		let pack = |low: u64, high: u64| low + high * u64::from(BASE);
+		assert_eq!(pack(3_000_000_000, 3_000_000_000).nitwise_add::<BASE>(pack(3, 7)), pack(2, 6));
+		assert_eq!(1_u64.nitwise_sub::<BASE>(2_999_999_999), 3);
+		assert_eq!(pack(5, 2_999_999_999).nitwise_sub::<BASE>(pack(7, 1)), pack(2_999_999_999, 2_999_999_998));
+		assert_eq!(pack(5, 3_000_000_000).nitwise_min::<BASE>(pack(7, 1)), pack(5, 1));
+		assert_eq!(pack(5, 3_000_000_000).nitwise_max::<BASE>(pack(7, 1)), pack(7, 3_000_000_000));
+	}
+
+	#[test]
+	fn primality() {
+		let primes = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31];
+		macro_rules! check { ($($base: literal),*) => { $(assert_eq!(Nit::<$base>::IS_PRIME, primes.contains(&$base));)* }; }
+		check!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31);
+	}
+}
@@ -0,0 +1,228 @@
+use crate::Nit;
+use crate::data_container::NitDataContainer;
+use crate::iter::NitIter;
+use crate::max_nits::compute_max_nits_in_bits;
+use crate::places::{PlacesIndex, PlacesIndexCreationError};
+use crate::supported::{BaseMaximum, FitsMaximumBits};
+
+/// The type of each word within a [`NitArray`].
+pub type NitArrayWord = u64;
+/// The bit width of [`NitArrayWord`].
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) const WORD_BITS: FitsMaximumBits = NitArrayWord::BITS as FitsMaximumBits;
+
+/// Computes the amount of words needed by a [`NitArray`] to store `len` base-`BASE` digits.
+///
+/// This is intended to be used for the `WORDS` parameter of [`NitArray`].
+/// Returns zero if the base can't be stored within a word at all.
+///
+/// # Example
+/// ```
+/// use nit::array::words_required;
+/// // 40 trits fit within a `u64`.
+/// assert_eq!(words_required::<3>(40), 1);
+/// assert_eq!(words_required::<3>(41), 2);
+/// assert_eq!(words_required::<3>(1000), 25);
+/// ```
+#[must_use]
+pub const fn words_required<const BASE: BaseMaximum>(len: usize) -> usize {
+	match compute_max_nits_in_bits::<BASE, WORD_BITS>() {
+		Ok(per_word) => len.div_ceil(per_word as usize),
+		Err(_) => 0,
+	}
+}
+
+/// A fixed-size array of `N` base-`BASE` digits, packed into `WORDS` [`u64`](NitArrayWord)s.
+///
+/// Each word holds as many digits as [`compute_max_nits_in_bits::<BASE, 64>`](compute_max_nits_in_bits) allows,
+/// with the `i`th digit being stored in the `i / per_word`th word at the `i % per_word`th place.
+/// No allocation is performed; the words are stored inline.
+/// The digits are read and written as [`Nit<BASE, BYTES>`](Nit)s, so bases above 256 need a wider `BYTES`.
+///
+/// `WORDS` must be equal to [`words_required::<BASE>(N)`](words_required), which is checked at compile-time.
+///
+/// # Example
+/// ```
+/// use nit::array::{NitArray, words_required};
+/// use nit::Trit;
+///
+/// let mut trits = NitArray::<3, 1000, { words_required::<3>(1000) }>::new();
+/// assert_eq!(trits.len(), 1000);
+/// assert_eq!(trits.set(999, Trit::TWO), Ok(Trit::ZERO));
+/// assert_eq!(trits.get(999), Some(Trit::TWO));
+/// assert_eq!(trits.get(1000), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NitArray<const BASE: BaseMaximum, const N: usize, const WORDS: usize, const BYTES: usize = 1>([NitArrayWord; WORDS]);
+
+impl<const BASE: BaseMaximum, const N: usize, const WORDS: usize, const BYTES: usize> NitArray<BASE, N, WORDS, BYTES> {
+	/// The amount of digits stored within each word.
+	///
+	/// This is evaluated at compile-time, failing if the base can't be stored within a word, or if `WORDS` isn't the amount of words required.
+	pub const NITS_PER_WORD: usize = match compute_max_nits_in_bits::<BASE, WORD_BITS>() {
+		Ok(per_word) => {
+			assert!(WORDS == words_required::<BASE>(N), "`WORDS` must be equal to `words_required::<BASE>(N)`.");
+			per_word as usize
+		},
+		Err(err) => panic!("{}", err.get_str()),
+	};
+
+	/// Creates a new array with every digit set to zero.
+	#[must_use]
+	pub const fn new() -> Self {
+		let _ = Self::NITS_PER_WORD;
+		Self([0; WORDS])
+	}
+
+	/// Creates an array from its underlying words, if every word only contains digits within the array's length.
+	///
+	/// # Example
+	/// ```
+	/// use nit::array::NitArray;
+	/// assert!(NitArray::<3, 41, 2>::from_words([3_u64.pow(40) - 1, 2]).is_some());
+	/// assert!(NitArray::<3, 41, 2>::from_words([3_u64.pow(40), 2]).is_none()); // A 41st trit in the first word.
+	/// assert!(NitArray::<3, 41, 2>::from_words([3_u64.pow(40) - 1, 3]).is_none()); // A 42nd trit in the last word.
+	/// ```
+	#[must_use]
+	pub const fn from_words(words: [NitArrayWord; WORDS]) -> Option<Self> {
+		let per_word = Self::NITS_PER_WORD;
+		let mut i = 0;
+		while i < WORDS {
+			// The amount of digits stored within this word; only the last word can be partially filled.
+			let digits = if i + 1 == WORDS { N - i * per_word } else { per_word };
+			#[allow(clippy::cast_possible_truncation)]
+			let limit = (BASE as NitArrayWord).checked_pow(digits as u32);
+			if let Some(limit) = limit {
+				if words[i] >= limit {
+					return None;
+				}
+			}
+			i += 1;
+		}
+		Some(Self(words))
+	}
+
+	/// Returns the underlying words.
+	#[must_use]
+	pub const fn as_words(&self) -> &[NitArrayWord; WORDS] {
+		&self.0
+	}
+
+	/// Returns the amount of digits in the array.
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		N
+	}
+
+	/// Returns whether the array holds no digits.
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		N == 0
+	}
+
+	/// Splits the index of a digit into the index of its word and a [`PlacesIndex`] within that word.
+	///
+	/// # Errors
+	/// - [`PlacesIndexCreationError::OutOfBounds`]: If the index goes beyond the length of the array.
+	const fn locate(i: usize) -> Result<(usize, PlacesIndex<WORD_BITS, BASE>), PlacesIndexCreationError> {
+		if i >= N {
+			return Err(PlacesIndexCreationError::OutOfBounds);
+		}
+		let per_word = Self::NITS_PER_WORD;
+		#[allow(clippy::cast_possible_truncation)]
+		let place = (i % per_word) as FitsMaximumBits;
+		// SAFETY: The place is less than the amount of digits per word, which was computed for this base and bit width.
+		Ok((i / per_word, unsafe { PlacesIndex::new_unchecked(place) }))
+	}
+
+	/// Returns the digit at the `i`th index.
+	/// Returns [`None`] if the index goes beyond the length of the array.
+	#[must_use]
+	pub fn get(&self, i: usize) -> Option<Nit<BASE, BYTES>> {
+		match Self::locate(i) {
+			Ok((word, place)) => Some(self.0[word].get_nit_indexed(place)),
+			Err(_) => None,
+		}
+	}
+
+	/// Sets the digit at the `i`th index, returning the previous digit at that index.
+	///
+	/// # Errors
+	/// - [`PlacesIndexCreationError::OutOfBounds`]: If the index goes beyond the length of the array.
+	pub fn set(&mut self, i: usize, value: Nit<BASE, BYTES>) -> Result<Nit<BASE, BYTES>, PlacesIndexCreationError> {
+		match Self::locate(i) {
+			Ok((word, place)) => Ok(self.0[word].set_nit_indexed(place, value)),
+			Err(err) => Err(err),
+		}
+	}
+
+	/// Returns an iterator over the digits, in order.
+	///
+	/// # Example
+	/// ```
+	/// use nit::array::{NitArray, words_required};
+	/// use nit::Trit;
+	///
+	/// let mut trits = NitArray::<3, 50, { words_required::<3>(50) }>::new();
+	/// trits.set(45, Trit::TWO).unwrap();
+	/// assert_eq!(trits.iter().count(), 50);
+	/// assert_eq!(trits.iter().position(|trit| trit == Trit::TWO), Some(45));
+	/// ```
+	pub fn iter(&self) -> impl Iterator<Item = Nit<BASE, BYTES>> + '_ {
+		// Only the last word can be partially filled, and its places past the length are zero.
+		self.0.iter().flat_map(|&word| NitIter::<NitArrayWord, WORD_BITS, BASE, BYTES>::new(word)).take(N)
+	}
+}
+
+impl<const BASE: BaseMaximum, const N: usize, const WORDS: usize, const BYTES: usize> Default for NitArray<BASE, N, WORDS, BYTES> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::missing_docs_in_private_items)]
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+
+	fn check_against_vec<const BASE: BaseMaximum, const N: usize, const WORDS: usize, const BYTES: usize>() {
+		let nit = |digit: u32| Nit::<BASE, BYTES>::new(digit % BASE).unwrap();
+		let mut array = NitArray::<BASE, N, WORDS, BYTES>::new();
+		let mut expected = vec![nit(0); N];
+		assert!(array.iter().eq(expected.iter().copied()));
+		for (i, previous) in expected.iter_mut().enumerate() {
+			#[allow(clippy::cast_possible_truncation)]
+			let digit = nit(i as u32 * 7 + 3);
+			assert_eq!(array.set(i, digit), Ok(*previous));
+			*previous = digit;
+		}
+		// Overwriting every other digit, including the last one in a partially filled word.
+		for i in (0..N).rev().step_by(2) {
+			let digit = nit(BASE - 1);
+			assert_eq!(array.set(i, digit), Ok(expected[i]));
+			expected[i] = digit;
+		}
+		for (i, &digit) in expected.iter().enumerate() {
+			assert_eq!(array.get(i), Some(digit));
+		}
+		assert!(array.iter().eq(expected.iter().copied()));
+		assert_eq!(array.get(N), None);
+		assert_eq!(array.set(N, nit(0)), Err(PlacesIndexCreationError::OutOfBounds));
+		assert_eq!(NitArray::<BASE, N, WORDS, BYTES>::from_words(*array.as_words()), Some(array));
+	}
+
+	#[test]
+	fn matches_vec() {
+		check_against_vec::<2, 64, { words_required::<2>(64) }, 1>();
+		check_against_vec::<2, 65, { words_required::<2>(65) }, 1>();
+		check_against_vec::<3, 1, { words_required::<3>(1) }, 1>();
+		check_against_vec::<3, 41, { words_required::<3>(41) }, 1>();
+		check_against_vec::<3, 1000, { words_required::<3>(1000) }, 1>();
+		check_against_vec::<10, 57, { words_required::<10>(57) }, 1>();
+		check_against_vec::<256, 9, { words_required::<256>(9) }, 1>();
+		check_against_vec::<1000, 13, { words_required::<1000>(13) }, 2>();
+		check_against_vec::<60000, 5, { words_required::<60000>(5) }, 2>();
+		check_against_vec::<3, 0, { words_required::<3>(0) }, 1>();
+	}
+}
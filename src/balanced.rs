@@ -0,0 +1,474 @@
+use crate::Nit;
+use crate::internal_macros::{define_empty_error, deriving_const, impl_error};
+use crate::supported::{BaseMaximum, FitsMaximumBits, BaseMaximumSigned};
+
+define_empty_error!(BalancedNitCreationError, "The value is not within the range of -(BASE / 2)..=(BASE / 2).", #[doc = "An error indicating that the value is not within the range of -(BASE / 2)..=(BASE / 2)."]);
+deriving_const!((PartialEq) for {
+	/// A balanced base-`BASE` digit of an integer, which falls in the range of `-(BASE / 2)..=(BASE / 2)`.
+	///
+	/// Only odd bases have a balanced representation; using an even base fails to compile.
+	/// Every integer, including negative ones, has exactly one representation in balanced digits, without needing a sign:
+	///
+	/// $$n = \sum\_{i} d\_i b^i, \quad -\left\lfloor\frac{b}{2}\right\rfloor \le d\_i \le \left\lfloor\frac{b}{2}\right\rfloor$$
+	///
+	/// # Example
+	/// ```
+	/// use nit::balanced::{BalancedNit, BalancedTrit};
+	/// assert_eq!(BalancedTrit::new(-1).unwrap(), BalancedTrit::NEGATIVE_ONE);
+	/// assert!(BalancedTrit::new(2).is_err());
+	/// assert_eq!(BalancedNit::<5>::MAX.get_value(), 2);
+	/// ```
+	#[repr(transparent)]
+	#[derive(Debug, Clone, Copy, Eq, Hash, PartialOrd, Ord)]
+	pub struct BalancedNit<const BASE: BaseMaximum>(BaseMaximumSigned);
+});
+
+impl<const BASE: BaseMaximum> BalancedNit<BASE> {
+	/// The magnitude of the largest digit, `BASE / 2`.
+	///
+	/// This is evaluated at compile-time, failing if the base isn't odd, or is less than three or beyond the range of [`BaseMaximumSigned`].
+	#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+	pub const HALF: BaseMaximumSigned = {
+		assert!(BASE % 2 == 1 && BASE >= 3 && BASE <= BaseMaximumSigned::MAX as BaseMaximum, "Balanced digits require an odd base of at least three, within the range of a `BaseMaximumSigned`.");
+		(BASE / 2) as BaseMaximumSigned
+	};
+	/// The smallest digit, `-(BASE / 2)`.
+	pub const MIN: Self = Self(-Self::HALF);
+	/// The digit with a value of zero.
+	pub const ZERO: Self = Self(0);
+	/// The largest digit, `BASE / 2`.
+	pub const MAX: Self = Self(Self::HALF);
+
+	/// Creates a new [`BalancedNit`] with the given value.
+	///
+	/// # Errors
+	/// - If the value is not within the range of `-(BASE / 2)..=(BASE / 2)`.
+	pub const fn new(value: BaseMaximumSigned) -> Result<Self, BalancedNitCreationError> {
+		if -Self::HALF <= value && value <= Self::HALF {
+			Ok(Self(value))
+		} else {
+			Err(BalancedNitCreationError)
+		}
+	}
+
+	/// Returns a new [`BalancedNit`] with the given value, without checking if it is valid.
+	///
+	/// # Safety
+	/// - The value must be within the range of `-(BASE / 2)..=(BASE / 2)`.
+	#[must_use]
+	pub const unsafe fn new_unchecked(value: BaseMaximumSigned) -> Self {
+		Self(value)
+	}
+
+	/// Returns the underlying value; the balanced digit in the relevant base.
+	#[must_use]
+	pub const fn get_value(&self) -> BaseMaximumSigned {
+		self.0
+	}
+
+	/// Converts the [`BalancedNit`] into the underlying value.
+	#[must_use]
+	pub const fn into_value(self) -> BaseMaximumSigned {
+		self.0
+	}
+
+	/// Returns the standard digit which is `BASE / 2` greater than this one, mapping `-(BASE / 2)..=(BASE / 2)` onto `0..BASE` in order.
+	///
+	/// This is a per-digit mapping, useful for storing balanced digits within a [`NitDataContainer`](crate::data_container::NitDataContainer);
+	/// the digits of the same integer in either representation are instead converted with [`balanced_from_standard`] and [`standard_from_balanced`].
+	///
+	/// # Example
+	/// ```
+	/// use nit::balanced::BalancedTrit;
+	/// use nit::Trit;
+	/// assert_eq!(BalancedTrit::NEGATIVE_ONE.into_offset(), Trit::ZERO);
+	/// assert_eq!(BalancedTrit::ONE.into_offset(), Trit::TWO);
+	/// assert_eq!(BalancedTrit::from_offset(Trit::ONE), BalancedTrit::ZERO);
+	/// ```
+	#[must_use]
+	pub const fn into_offset<const BYTES: usize>(self) -> Nit<BASE, BYTES> {
+		#[allow(clippy::cast_sign_loss)]
+		let digit = (self.0 + Self::HALF) as BaseMaximum;
+		// SAFETY: The value is within `-(BASE / 2)..=(BASE / 2)`, so the sum is within `0..BASE`.
+		unsafe { Nit::new_unchecked(digit) }
+	}
+
+	/// Returns the balanced digit which is `BASE / 2` less than the standard one; the inverse of [`Self::into_offset`].
+	#[must_use]
+	pub const fn from_offset<const BYTES: usize>(nit: Nit<BASE, BYTES>) -> Self {
+		#[allow(clippy::cast_possible_wrap)]
+		let digit = nit.get_value() as BaseMaximumSigned;
+		// The digit is less than `BASE`, which is within the range of a `BaseMaximumSigned` as checked by `HALF`.
+		Self(digit - Self::HALF)
+	}
+}
+
+impl<const BASE: BaseMaximum> TryFrom<BaseMaximumSigned> for BalancedNit<BASE> {
+	type Error = BalancedNitCreationError;
+
+	/// Attempts to create and return a new [`BalancedNit`] from a [`BaseMaximumSigned`] integer primitive.
+	///
+	/// # Errors
+	/// - If the value is not within the range of `-(BASE / 2)..=(BASE / 2)`.
+	fn try_from(value: BaseMaximumSigned) -> Result<Self, Self::Error> {
+		Self::new(value)
+	}
+}
+
+/// A balanced ternary [`BalancedNit`] (having a base of `3`).
+pub type BalancedTrit = BalancedNit<3>;
+impl BalancedTrit {
+	/// A balanced trit with a value of negative one.
+	pub const NEGATIVE_ONE: Self = Self(-1);
+	/// A balanced trit with a value of one.
+	pub const ONE: Self = Self(1);
+}
+
+deriving_const!((PartialEq) for {
+	/// An error that occurred when attempting to access or convert balanced digits.
+	#[derive(Debug, Eq, Clone, Copy, Hash)]
+	pub enum BalancedNitAccessError {
+		/// The place goes beyond the amount of bits of the type.
+		OutOfBounds,
+		/// The resulting value doesn't fit within the type or the amount of digits.
+		Overflow,
+		/// The value is negative, so it has no standard representation.
+		Negative,
+	}
+});
+impl BalancedNitAccessError {
+	/// Returns the error message as a string.
+	#[must_use]
+	#[cfg(not(tarpaulin_include))]
+	pub const fn get_str(&self) -> &str {
+		match self {
+			Self::OutOfBounds => "The place goes beyond the amount of bits of the type.",
+			Self::Overflow => "The resulting value doesn't fit within the type or the amount of digits.",
+			Self::Negative => "The value is negative, so it has no standard representation.",
+		}
+	}
+}
+#[cfg(not(tarpaulin_include))]
+impl core::fmt::Display for BalancedNitAccessError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "{}", self.get_str())
+	}
+}
+impl_error!(BalancedNitAccessError);
+
+/// Converts the standard digits of an integer into its balanced digits, both starting from the least significant digit (right-hand side).
+///
+/// The balanced representation can need one more digit than the standard one, so `out` is filled entirely, with missing standard digits being treated as zero.
+/// On error, `out` holds the lowest `out.len()` balanced digits.
+///
+/// # Errors
+/// - [`BalancedNitAccessError::Overflow`]: If the integer doesn't fit within `out.len()` balanced digits.
+///
+/// # Example
+/// ```
+/// use nit::balanced::{balanced_from_standard, BalancedTrit};
+/// use nit::Trit;
+/// // 8 = 2 * 3 + 2 = 9 - 1
+/// let mut out = [BalancedTrit::ZERO; 3];
+/// assert_eq!(balanced_from_standard(&[Trit::TWO, Trit::TWO], &mut out), Ok(()));
+/// assert_eq!(out, [BalancedTrit::NEGATIVE_ONE, BalancedTrit::ZERO, BalancedTrit::ONE]);
+/// assert!(balanced_from_standard(&[Trit::TWO, Trit::TWO], &mut out[..2]).is_err());
+/// ```
+pub fn balanced_from_standard<const BASE: BaseMaximum, const BYTES: usize>(digits: &[Nit<BASE, BYTES>], out: &mut [BalancedNit<BASE>]) -> Result<(), BalancedNitAccessError> {
+	let half = BalancedNit::<BASE>::HALF;
+	#[allow(clippy::cast_possible_wrap, clippy::cast_possible_truncation)]
+	let base = BASE as BaseMaximumSigned;
+	let mut carry = 0;
+	for (i, slot) in out.iter_mut().enumerate() {
+		#[allow(clippy::cast_possible_wrap)]
+		let digit = digits.get(i).map_or(0, |nit| nit.get_value() as BaseMaximumSigned) + carry;
+		// The digit is within `0..=BASE`, so it's brought into the balanced range by carrying at most one into the next place.
+		(*slot, carry) = if digit > half { (BalancedNit(digit - base), 1) } else { (BalancedNit(digit), 0) };
+	}
+	let remaining = digits.get(out.len()..).unwrap_or_default();
+	if carry != 0 || remaining.iter().any(|nit| nit.get_value() != 0) {
+		return Err(BalancedNitAccessError::Overflow);
+	}
+	Ok(())
+}
+
+/// Converts the balanced digits of an integer into its standard digits, both starting from the least significant digit (right-hand side).
+///
+/// `out` is filled entirely, with missing balanced digits being treated as zero.
+/// On error, `out` holds the lowest `out.len()` standard digits of the integer modulo `BASE.pow(out.len())`.
+///
+/// # Errors
+/// - [`BalancedNitAccessError::Negative`]: If the integer is negative.
+/// - [`BalancedNitAccessError::Overflow`]: If the integer doesn't fit within `out.len()` standard digits.
+///
+/// # Example
+/// ```
+/// use nit::balanced::{standard_from_balanced, BalancedNitAccessError, BalancedTrit};
+/// use nit::Trit;
+/// let mut out = [Trit::ZERO; 2];
+/// assert_eq!(standard_from_balanced(&[BalancedTrit::NEGATIVE_ONE, BalancedTrit::ONE], &mut out), Ok(()));
+/// assert_eq!(out, [Trit::TWO, Trit::ZERO]); // 3 - 1 = 2
+/// assert_eq!(standard_from_balanced(&[BalancedTrit::ONE, BalancedTrit::NEGATIVE_ONE], &mut out), Err(BalancedNitAccessError::Negative));
+/// ```
+pub fn standard_from_balanced<const BASE: BaseMaximum, const BYTES: usize>(digits: &[BalancedNit<BASE>], out: &mut [Nit<BASE, BYTES>]) -> Result<(), BalancedNitAccessError> {
+	#[allow(clippy::cast_possible_wrap, clippy::cast_possible_truncation)]
+	let base = BASE as BaseMaximumSigned;
+	let mut borrow = 0;
+	let mut overflowed = false;
+	for i in 0..digits.len().max(out.len()) {
+		let digit = digits.get(i).map_or(0, BalancedNit::get_value) + borrow;
+		// The digit is within `-(BASE / 2) - 1..=(BASE / 2)`, so it's brought into the standard range by borrowing at most one from the next place.
+		let digit = if digit < 0 { borrow = -1; digit + base } else { borrow = 0; digit };
+		#[allow(clippy::cast_sign_loss)]
+		let digit = digit as BaseMaximum;
+		match out.get_mut(i) {
+			// SAFETY: The digit was just brought into the range of `0..BASE`.
+			Some(slot) => *slot = unsafe { Nit::new_unchecked(digit) },
+			None => overflowed |= digit != 0,
+		}
+	}
+	// A borrow out of the most significant digit can only come from a negative integer.
+	if borrow != 0 {
+		return Err(BalancedNitAccessError::Negative);
+	}
+	if overflowed {
+		return Err(BalancedNitAccessError::Overflow);
+	}
+	Ok(())
+}
+
+/// A value that contains numeric data which can be read and written as balanced digits.
+///
+/// The balanced digits are those of the integer value itself, so a negative integer has negative digits rather than those of its two's complement.
+///
+/// # Example
+/// ```
+/// use nit::balanced::{BalancedNitDataContainer, BalancedTrit};
+/// let mut value: i8 = -5; // -9 + 3 + 1
+/// assert_eq!(value.get_balanced_nit::<3>(0), Some(BalancedTrit::ONE));
+/// assert_eq!(value.get_balanced_nit::<3>(1), Some(BalancedTrit::ONE));
+/// assert_eq!(value.get_balanced_nit::<3>(2), Some(BalancedTrit::NEGATIVE_ONE));
+/// assert_eq!(value.get_balanced_nit::<3>(3), Some(BalancedTrit::ZERO));
+/// assert_eq!(value.set_balanced_nit(2, BalancedTrit::ONE), Ok(BalancedTrit::NEGATIVE_ONE));
+/// assert_eq!(value, 13);
+/// ```
+pub trait BalancedNitDataContainer {
+	/// Returns the balanced base-`BASE` digit at the `n`th place, falling in the range of `-(BASE / 2)..=(BASE / 2)`.
+	/// Returns [`None`] if the place goes beyond the amount of bits of the type.
+	#[must_use]
+	fn get_balanced_nit<const BASE: BaseMaximum>(&self, n: FitsMaximumBits) -> Option<BalancedNit<BASE>>;
+
+	/// Sets the balanced base-`BASE` digit at the `n`th place, returning the previous digit at that place.
+	///
+	/// # Errors
+	/// - [`BalancedNitAccessError::OutOfBounds`]: If the place goes beyond the amount of bits of the type.
+	/// - [`BalancedNitAccessError::Overflow`]: If the resulting value doesn't fit within the type.
+	fn set_balanced_nit<const BASE: BaseMaximum>(&mut self, n: FitsMaximumBits, value: BalancedNit<BASE>) -> Result<BalancedNit<BASE>, BalancedNitAccessError>;
+
+	/// Returns the lowest `CAP` balanced base-`BASE` digits, starting from the least significant digit (right-hand side).
+	///
+	/// # Errors
+	/// - [`BalancedNitAccessError::Overflow`]: If the value doesn't fit within `CAP` balanced digits.
+	///
+	/// # Example
+	/// ```
+	/// use nit::balanced::{BalancedNitDataContainer, BalancedNitAccessError, BalancedTrit};
+	/// let value: i32 = -8;
+	/// let trits = value.to_balanced_nits::<3, 3>().unwrap();
+	/// assert_eq!(trits, [BalancedTrit::ONE, BalancedTrit::ZERO, BalancedTrit::NEGATIVE_ONE]);
+	/// assert_eq!(i32::from_balanced_nits(&trits), Ok(-8));
+	/// assert_eq!(value.to_balanced_nits::<3, 2>(), Err(BalancedNitAccessError::Overflow));
+	/// ```
+	fn to_balanced_nits<const BASE: BaseMaximum, const CAP: usize>(&self) -> Result<[BalancedNit<BASE>; CAP], BalancedNitAccessError>;
+
+	/// Creates a value from its balanced base-`BASE` digits, starting from the least significant digit (right-hand side).
+	///
+	/// # Errors
+	/// - [`BalancedNitAccessError::Overflow`]: If the value doesn't fit within the type.
+	fn from_balanced_nits<const BASE: BaseMaximum>(nits: &[BalancedNit<BASE>]) -> Result<Self, BalancedNitAccessError> where Self: Sized;
+}
+
+/// Generates an implementation of the [`BalancedNitDataContainer`] trait for each primitive integer type provided.
+macro_rules! impl_balanced_data_container {
+	($($type: ty),*) => {
+		$(
+			impl BalancedNitDataContainer for $type {
+				fn get_balanced_nit<const BASE: BaseMaximum>(&self, n: FitsMaximumBits) -> Option<BalancedNit<BASE>> {
+					if u32::from(n) >= <$type>::BITS {
+						return None;
+					}
+					let mut remaining = *self;
+					let mut i = 0;
+					loop {
+						let (digit, quotient) = remaining.split_balanced::<BASE>();
+						if i == n {
+							return Some(digit);
+						}
+						remaining = quotient;
+						i += 1;
+					}
+				}
+
+				fn set_balanced_nit<const BASE: BaseMaximum>(&mut self, n: FitsMaximumBits, value: BalancedNit<BASE>) -> Result<BalancedNit<BASE>, BalancedNitAccessError> {
+					if u32::from(n) >= <$type>::BITS {
+						return Err(BalancedNitAccessError::OutOfBounds);
+					}
+					// Every value fits within as many balanced digits as it has bits, as the base is at least three.
+					// The digits are replaced as a whole, as the shifter of a place can be greater than the type's maximum even when the place is in use.
+					let mut nits = self.to_balanced_nits::<BASE, { <$type>::BITS as usize }>()?;
+					let previous = core::mem::replace(&mut nits[usize::from(n)], value);
+					*self = Self::from_balanced_nits(&nits)?;
+					Ok(previous)
+				}
+
+				fn to_balanced_nits<const BASE: BaseMaximum, const CAP: usize>(&self) -> Result<[BalancedNit<BASE>; CAP], BalancedNitAccessError> {
+					let mut nits = [BalancedNit::ZERO; CAP];
+					let mut remaining = *self;
+					for slot in &mut nits {
+						(*slot, remaining) = remaining.split_balanced::<BASE>();
+					}
+					if remaining != 0 {
+						return Err(BalancedNitAccessError::Overflow);
+					}
+					Ok(nits)
+				}
+
+				fn from_balanced_nits<const BASE: BaseMaximum>(nits: &[BalancedNit<BASE>]) -> Result<Self, BalancedNitAccessError> {
+					#[allow(clippy::cast_lossless, clippy::cast_possible_wrap, clippy::cast_possible_truncation)]
+					let base = BASE as $type;
+					let mut value: $type = 0;
+					// Horner's method, starting from the most significant digit.
+					// A partial value can overflow even if the whole doesn't, so this wraps, being exact modulo `2^N`.
+					for nit in nits.iter().rev() {
+						#[allow(clippy::cast_lossless, clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+						let digit = nit.get_value() as $type;
+						value = value.wrapping_mul(base).wrapping_add(digit);
+					}
+					// The balanced representation is unique, so the value is correct if and only if its digits are the given ones.
+					let mut remaining = value;
+					for nit in nits {
+						let (digit, quotient) = remaining.split_balanced::<BASE>();
+						if digit != *nit {
+							return Err(BalancedNitAccessError::Overflow);
+						}
+						remaining = quotient;
+					}
+					if remaining != 0 {
+						return Err(BalancedNitAccessError::Overflow);
+					}
+					Ok(value)
+				}
+			}
+
+			impl SplitBalanced for $type {
+				fn split_balanced<const BASE: BaseMaximum>(self) -> (BalancedNit<BASE>, Self) {
+					let half = BalancedNit::<BASE>::HALF;
+					// Where the base is beyond the range of the type, the type is narrower than a `BaseMaximumSigned`, which then holds the value.
+					// The value is then a single digit, other than carrying one to the next place if it is beyond the digits' range.
+					#[allow(clippy::cast_lossless, clippy::cast_possible_wrap, clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+					if BASE as u128 > <$type>::MAX as u128 {
+						let value = self as BaseMaximumSigned;
+						let base = BASE as BaseMaximumSigned;
+						let quotient: BaseMaximumSigned = if value > half { 1 } else if value < -half { -1 } else { 0 };
+						return (BalancedNit(value - quotient * base), quotient as $type);
+					}
+					#[allow(clippy::cast_lossless, clippy::cast_possible_wrap, clippy::cast_possible_truncation)]
+					let base = BASE as $type;
+					#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap, clippy::cast_lossless)]
+					let digit = self.rem_euclid(base) as BaseMaximumSigned;
+					let quotient = self.div_euclid(base);
+					// The quotient is at most a third of the type's maximum, so incrementing it can't overflow.
+					#[allow(clippy::cast_possible_wrap, clippy::cast_possible_truncation)]
+					if digit > half { (BalancedNit(digit - BASE as BaseMaximumSigned), quotient + 1) } else { (BalancedNit(digit), quotient) }
+				}
+			}
+		)*
+	};
+}
+
+/// Splits an integer into its lowest balanced digit and the integer formed by the rest of its balanced digits.
+trait SplitBalanced: Sized {
+	/// Returns the lowest balanced base-`BASE` digit, and the value divided by `BASE` with it removed.
+	fn split_balanced<const BASE: BaseMaximum>(self) -> (BalancedNit<BASE>, Self);
+}
+
+impl_balanced_data_container!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::missing_docs_in_private_items)]
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+	use crate::data_container::NitDataContainer;
+
+	#[test]
+	fn round_trip_i8() {
+		for value in i8::MIN..=i8::MAX {
+			let trits = value.to_balanced_nits::<3, 6>().unwrap();
+			assert_eq!(i8::from_balanced_nits(&trits), Ok(value));
+			for (n, trit) in (0..).zip(trits) {
+				assert_eq!(value.get_balanced_nit::<3>(n), Some(trit));
+			}
+			let fives = value.to_balanced_nits::<5, 4>().unwrap();
+			assert_eq!(i8::from_balanced_nits(&fives), Ok(value));
+		}
+	}
+
+	#[test]
+	fn bases_beyond_the_type() {
+		// A base beyond the range of the type, such that each value is at most two digits.
+		for value in i8::MIN..=i8::MAX {
+			let digits = value.to_balanced_nits::<129, 2>().unwrap();
+			assert_eq!(i8::from_balanced_nits(&digits), Ok(value));
+			assert_eq!(i32::from(value).to_balanced_nits::<129, 2>(), Ok(digits));
+		}
+		for value in 0..=u16::MAX {
+			let digits = value.to_balanced_nits::<40_001, 2>().unwrap();
+			assert_eq!(u16::from_balanced_nits(&digits), Ok(value));
+			assert_eq!(u32::from(value).to_balanced_nits::<40_001, 2>(), Ok(digits));
+			let mut standard = [Nit::<40_001, 2>::new(0).unwrap(); 2];
+			standard_from_balanced(&digits, &mut standard).unwrap();
+			assert_eq!(u32::from_nits(&standard), Ok(u32::from(value)));
+			let mut balanced = [BalancedNit::ZERO; 2];
+			balanced_from_standard(&standard, &mut balanced).unwrap();
+			assert_eq!(balanced, digits);
+			assert!(digits.iter().all(|&digit| BalancedNit::from_offset(digit.into_offset::<2>()) == digit));
+		}
+		assert_eq!(BalancedNit::<{ i32::MAX as u32 }>::MAX.get_value(), i32::MAX / 2);
+		let base = i64::from(i32::MAX);
+		let value = 1 - 2 * base + base * base;
+		let digits = value.to_balanced_nits::<{ i32::MAX as u32 }, 3>().unwrap();
+		assert_eq!(digits.map(|digit| digit.get_value()), [1, -2, 1]);
+		assert_eq!(i64::from_balanced_nits(&digits), Ok(value));
+	}
+
+	#[test]
+	fn standard_round_trip_u8() {
+		// Every value which fits within five trits.
+		for value in 0..243_u8 {
+			let standard = value.to_nits::<3, 1, 5>();
+			let mut balanced = [BalancedNit::ZERO; 6];
+			balanced_from_standard(&standard, &mut balanced).unwrap();
+			assert_eq!(Ok(balanced), value.to_balanced_nits::<3, 6>());
+			let mut back = [Nit::new(0).unwrap(); 5];
+			standard_from_balanced(&balanced, &mut back).unwrap();
+			assert_eq!(back, standard);
+		}
+	}
+
+	#[test]
+	fn checked_setting() {
+		let mut value = i8::MIN; // -243 + 81 + 27 + 9 - 3 + 1
+		assert_eq!(value.set_balanced_nit(5, BalancedTrit::ZERO), Ok(BalancedTrit::NEGATIVE_ONE));
+		assert_eq!(value, 115);
+		let mut value = i8::MAX;
+		assert_eq!(value.set_balanced_nit(4, BalancedTrit::ONE), Err(BalancedNitAccessError::Overflow));
+		assert_eq!(value, i8::MAX);
+		assert_eq!(value.set_balanced_nit(8, BalancedTrit::ZERO), Err(BalancedNitAccessError::OutOfBounds));
+		let mut value = 0_u8;
+		assert_eq!(value.set_balanced_nit(0, BalancedTrit::NEGATIVE_ONE), Err(BalancedNitAccessError::Overflow));
+		assert_eq!(value.set_balanced_nit(7, BalancedTrit::ZERO), Ok(BalancedTrit::ZERO));
+		assert_eq!(value.set_balanced_nit(5, BalancedTrit::ONE), Ok(BalancedTrit::ZERO));
+		assert_eq!(value, 243);
+	}
+}
@@ -1,9 +1,12 @@
 #[cfg(all(test, not(tarpaulin), not(debug_assertions)))]
 use no_panic::no_panic;
 
+use core::marker::PhantomData;
 #[allow(clippy::wildcard_imports)]
 use crate::internal_macros::*;
+use crate::max_nits::compute_max_nits_in_bits;
 use crate::places::{PlacesIndex, PlacesShifter};
+use crate::reciprocal::Reciprocal;
 use crate::supported::{BaseMaximum, FitsMaximumBits};
 
 define_const_trait!(
@@ -14,26 +17,156 @@ define_const_trait!(
 		/// This should be functionally equivalent to `BASE.pow(n)`.
 		#[must_use]
 		fn get_places_shifter(n: PlacesIndex<TYPE_BIT_WIDTH, BASE>) -> PlacesShifter<T, BASE>;
+
+		/// Returns the [`Reciprocal`] of the [`PlacesShifter`] for the `n`th place, used to divide by it without a hardware division.
+		#[must_use]
+		fn get_places_reciprocal(n: PlacesIndex<TYPE_BIT_WIDTH, BASE>) -> Reciprocal<T>;
 	}
 );
 
-/// Generates an implementation of the `Base` trait for each primitive integer type provided.
+/// The powers of `BASE` which fit within an integer of type `T`, and their [`Reciprocal`]s, computed at compile-time.
+///
+/// # Example
+/// ```
+/// use nit::base::Powers;
+/// assert_eq!(Powers::<u8, 3>::TABLE, [1, 3, 9, 27, 81]);
+/// assert_eq!(Powers::<u8, 2>::TABLE.len(), 8);
+/// assert!(Powers::<u8, 256>::TABLE.is_empty());
+/// assert_eq!(Powers::<u64, 10>::TABLE[18], 10_u64.pow(18));
+/// assert_eq!(Powers::<u64, 10>::RECIPROCALS[18].divide(u64::MAX), u64::MAX / 10_u64.pow(18));
+/// assert_eq!(Powers::<u64, 10>::BASE_RECIPROCAL.remainder(1234), 4);
+/// ```
+pub struct Powers<T, const BASE: BaseMaximum>(PhantomData<T>);
+
+/// Generates an implementation of the `Base` trait and a table of [`Powers`] for each primitive integer type provided.
 macro_rules! impl_base_variants {
 	($($type: ty),*) => {
 		$(
-			// TODO: specialized const
+			impl<const BASE: BaseMaximum> Powers<$type, BASE> {
+				/// The storage of [`TABLE`](Self::TABLE), which is as long as the type has bits, as an array can't be sized by the nit capacity of a generic base.
+				///
+				/// Entries beyond the nit capacity are zero, as are all of them if the base is erroneous for the type.
+				const TABLE_STORAGE: [$type; <$type>::BITS as usize] = {
+					let mut table = [0; <$type>::BITS as usize];
+					#[allow(clippy::cast_possible_truncation)]
+					if let Ok(max) = compute_max_nits_in_bits::<BASE, { <$type>::BITS as FitsMaximumBits }>() {
+						// The capacity is at least one, so the base fits.
+						#[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+						let base = BASE as $type;
+						let mut power: $type = 1;
+						let mut i = 0;
+						while i < max as usize {
+							table[i] = power;
+							i += 1;
+							// `BASE.pow(max)` doesn't fit within the type for a base of two.
+							if i < max as usize {
+								power *= base;
+							}
+						}
+					}
+					table
+				};
+
+				/// `BASE.pow(n)` for each place `n` within the nit capacity of the type for the base, starting from `BASE.pow(0)`.
+				///
+				/// This holds exactly as many entries as the nit capacity, and none if the base is erroneous for the type.
+				pub const TABLE: &'static [$type] = Self::TABLE_STORAGE_REF.split_at(Self::CAPACITY as usize).0;
+
+				/// A reference to [`TABLE_STORAGE`](Self::TABLE_STORAGE), which lives for as long as the program does.
+				const TABLE_STORAGE_REF: &'static [$type; <$type>::BITS as usize] = &Self::TABLE_STORAGE;
+
+				/// The storage of [`RECIPROCALS`](Self::RECIPROCALS), which is as long as the type has bits.
+				///
+				/// Entries beyond the nit capacity are the reciprocal of one rather than zero, as zero has no reciprocal.
+				const RECIPROCALS_STORAGE: [Reciprocal<$type>; <$type>::BITS as usize] = {
+					let mut table = [Self::ONE_RECIPROCAL; <$type>::BITS as usize];
+					let mut i = 0;
+					while i < table.len() {
+						if let Some(reciprocal) = Reciprocal::<$type>::new(Self::TABLE_STORAGE[i]) {
+							table[i] = reciprocal;
+						}
+						i += 1;
+					}
+					table
+				};
+
+				/// A reference to [`RECIPROCALS_STORAGE`](Self::RECIPROCALS_STORAGE), which lives for as long as the program does.
+				const RECIPROCALS_STORAGE_REF: &'static [Reciprocal<$type>; <$type>::BITS as usize] = &Self::RECIPROCALS_STORAGE;
+
+				/// The [`Reciprocal`] of each entry of [`TABLE`](Self::TABLE), holding exactly as many entries.
+				pub const RECIPROCALS: &'static [Reciprocal<$type>] = Self::RECIPROCALS_STORAGE_REF.split_at(Self::CAPACITY as usize).0;
+
+				/// The [`Reciprocal`] of one, which is `BASE.pow(0)`.
+				const ONE_RECIPROCAL: Reciprocal<$type> = match Reciprocal::<$type>::new(1) {
+					Some(one) => one,
+					None => unreachable!(),
+				};
+
+				/// The [`Reciprocal`] of `BASE` itself, used to take the remainder of a quotient.
+				///
+				/// This is the reciprocal of one if the base is erroneous for the type.
+				// The base is only truncated where it doesn't fit, in which case the capacity is zero.
+				#[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+				pub const BASE_RECIPROCAL: Reciprocal<$type> = match Reciprocal::<$type>::new(BASE as $type) {
+					Some(reciprocal) if Self::CAPACITY > 0 => reciprocal,
+					_ => Self::ONE_RECIPROCAL,
+				};
+
+				/// The nit capacity of the type for the base, or zero if the base is erroneous for the type.
+				#[allow(clippy::cast_possible_truncation)]
+				pub(crate) const CAPACITY: FitsMaximumBits = match compute_max_nits_in_bits::<BASE, { <$type>::BITS as FitsMaximumBits }>() {
+					Ok(max) => max,
+					Err(_) => 0,
+				};
+
+				/// Returns `BASE.pow(k)`, wrapping, for any `k` up to and including the nit capacity.
+				#[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+				pub(crate) const fn power(k: FitsMaximumBits) -> $type {
+					debug_assert!(k <= Self::CAPACITY, "The place is beyond the nit capacity.");
+					if k == 0 {
+						return 1;
+					}
+					// This wraps to zero only for a base of two at full capacity.
+					Self::TABLE[k as usize - 1].wrapping_mul(BASE as $type)
+				}
+
+				/// Splits a value at the `k`th place into `(n / BASE.pow(k), n % BASE.pow(k))`, for any `k` up to and including the nit capacity.
+				pub(crate) const fn split_at_place(n: $type, k: FitsMaximumBits) -> ($type, $type) {
+					debug_assert!(k <= Self::CAPACITY, "The place is beyond the nit capacity.");
+					if k == 0 {
+						return (n, 0);
+					}
+					let high = Self::BASE_RECIPROCAL.divide(Self::RECIPROCALS[k as usize - 1].divide(n));
+					// Where the power wraps to zero, the quotient is zero as well.
+					(high, n - high * Self::power(k))
+				}
+			}
+
 			const_impl_base!(Base<$type, { #[allow(clippy::cast_possible_truncation)] { <$type>::BITS as FitsMaximumBits } }, BASE> | $type {
 				#[cfg_attr(all(test, not(tarpaulin), not(debug_assertions)), no_panic)]
 				fn get_places_shifter(n: PlacesIndex<{ #[allow(clippy::cast_possible_truncation)] { <$type>::BITS as FitsMaximumBits } }, BASE>) -> PlacesShifter<$type, BASE> {
-					// If the `PlacesIndex` precondition was correctly followed, it won't ever overflow.
-					// TODO: Can we optimize based on that fact?
-					#[allow(clippy::cast_lossless)]
-					let shift = (BASE as $type).pow(n.get() as u32); // u32..?
-					// SAFETY:
-					//  - Any power of `BASE` is guaranteed to be non-zero.
-					//  - It will fit the range based on the `PlacesIndex` precondition.
+					let (i, table) = (n.get() as usize, Powers::<$type, BASE>::TABLE);
+					debug_assert!(i < table.len(), "The `PlacesIndex` precondition was violated.");
+					// The `PlacesIndex` precondition keeps the index within the table, but a violated one is clamped to its last power rather than reading beyond it,
+					// or to `BASE.pow(0)` if it has none.
+					let shift = match (table.get(i), table.last()) {
+						(Some(&power), _) | (None, Some(&power)) => power,
+						(None, None) => 1,
+					};
+					// SAFETY: The table only holds the powers of `BASE` within the nit capacity, each of which fits within the type and so is non-zero, as is `BASE.pow(0)`.
 					unsafe { PlacesShifter::new(shift) }
 				}
+
+				#[cfg_attr(all(test, not(tarpaulin), not(debug_assertions)), no_panic)]
+				fn get_places_reciprocal(n: PlacesIndex<{ #[allow(clippy::cast_possible_truncation)] { <$type>::BITS as FitsMaximumBits } }, BASE>) -> Reciprocal<$type> {
+					let (i, table) = (n.get() as usize, Powers::<$type, BASE>::RECIPROCALS);
+					debug_assert!(i < table.len(), "The `PlacesIndex` precondition was violated.");
+					// The `PlacesIndex` precondition keeps the index within the table, but a violated one is clamped to its last reciprocal, as with the shifter.
+					match (table.get(i), table.last()) {
+						(Some(&reciprocal), _) | (None, Some(&reciprocal)) => reciprocal,
+						(None, None) => Powers::<$type, BASE>::ONE_RECIPROCAL,
+					}
+				}
 			});
 		)*
 	};
@@ -70,7 +203,8 @@ mod tests {
 			$(
 				define_const_func!(#[test] $name() {
 					use crate::data_container::NitDataContainer;
-					const fn get_nth_bit(value: $type, n: u8) -> u8 { ((value >> n) & 1) as u8 }
+					#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+					const fn get_nth_bit(value: $type, n: FitsMaximumBits) -> u32 { ((value >> n) & 1) as u32 }
 					#[allow(clippy::cast_possible_truncation)]
 					const BITS: FitsMaximumBits = <$type>::BITS as FitsMaximumBits;
 					const VALUE: $type = $bits;
@@ -78,7 +212,7 @@ mod tests {
 
 					while i < BITS {
 						let bit = get_nth_bit(VALUE, i);
-						let nit = VALUE.get_nit::<2>(i);
+						let nit = VALUE.get_nit::<2, 1>(i);
 						match nit {
 							Some(nit) => assert!(bit == nit.into_value()),
 							None => assert!(false, "Failed to get nit!"),
@@ -96,10 +230,67 @@ mod tests {
 		(test_binary_equivalency_u16, u16, 0b1011_0000_1101_1111),
 		(test_binary_equivalency_u32, u32, 0b1011_1010_1100_0010_1101_1111_0000_1110),
 		(test_binary_equivalency_u64, u64, 0b0001_1111_1101_0111_0111_1010_1001_0011_0101_1110_1110_0001_1011_1110_1100_1110),
-		(test_binary_equivalency_u128, u128, 0b0010_0011_0011_0000_0000_1011_0111_0010_0001_1011_0001_1100_1111_1111_1000_0100_1011_1100_0001_0000_0111_0101_1011_0001_0001_0110_0000_1111_0011_0010_1000_1101)
+		(test_binary_equivalency_u128, u128, 0b0010_0011_0011_0000_0000_1011_0111_0010_0001_1011_0001_1100_1111_1111_1000_0100_1011_1100_0001_0000_0111_0101_1011_0001_0001_0110_0000_1111_0011_0010_1000_1101),
+		(test_binary_equivalency_usize, usize, 0b1011_0000_1101_1111),
+		(test_binary_equivalency_i8, i8, -0b0100_0110),
+		(test_binary_equivalency_i16, i16, -0b0100_1111_0010_0001),
+		(test_binary_equivalency_i32, i32, 0b0011_1010_1100_0010_1101_1111_0000_1110),
+		(test_binary_equivalency_i64, i64, -0b0001_1111_1101_0111_0111_1010_1001_0011_0101_1110_1110_0001_1011_1110_1100_1110),
+		(test_binary_equivalency_i128, i128, -0b0010_0011_0011_0000_0000_1011_0111_0010_0001_1011_0001_1100_1111_1111_1000_0100_1011_1100_0001_0000_0111_0101_1011_0001_0001_0110_0000_1111_0011_0010_1000_1101),
+		(test_binary_equivalency_isize, isize, -0b1011_0000_1101_1111)
 	);
 
 
+	macro_rules! make_power_table_tests {
+		($(($name: ident, $type: ty)),*) => {
+			$(
+				#[test]
+				#[allow(clippy::cast_possible_truncation)]
+				fn $name() {
+					#[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+					fn check<const BASE: BaseMaximum>() {
+						let max = crate::max_nits::compute_max_nits_in_bits::<BASE, { <$type>::BITS as FitsMaximumBits }>().unwrap_or(0);
+						assert_eq!(Powers::<$type, BASE>::TABLE.len(), max as usize);
+						assert_eq!(Powers::<$type, BASE>::RECIPROCALS.len(), max as usize);
+						for (i, &power) in Powers::<$type, BASE>::TABLE.iter().enumerate() {
+							assert_eq!(power, (BASE as $type).pow(i as u32));
+						}
+					}
+					check::<2>();
+					check::<3>();
+					check::<7>();
+					check::<10>();
+					check::<127>();
+					check::<128>();
+					check::<255>();
+					check::<1000>();
+					check::<60000>();
+				}
+			)*
+		};
+	}
+
+	make_power_table_tests!(
+		(test_power_table_u8, u8),
+		(test_power_table_u16, u16),
+		(test_power_table_u32, u32),
+		(test_power_table_u64, u64),
+		(test_power_table_u128, u128)
+	);
+
+	// The violated precondition is only clamped where it isn't caught by a debug assertion.
+	#[test]
+	#[cfg(not(debug_assertions))]
+	fn clamps_to_the_capacity() {
+		// SAFETY: Not actually safe, but this checks that a violated precondition is clamped to a non-zero power of the base.
+		let (shifter, reciprocal) = unsafe { (<u8 as Base<u8, 8, 3>>::get_places_shifter(PlacesIndex::new_unchecked(7)), <u8 as Base<u8, 8, 3>>::get_places_reciprocal(PlacesIndex::new_unchecked(7))) };
+		assert_eq!(shifter.get(), 81);
+		assert_eq!(reciprocal.divisor(), 81);
+		// SAFETY: As above, for a base which has no places within a byte at all.
+		let shifter = unsafe { <u8 as Base<u8, 8, 256>>::get_places_shifter(PlacesIndex::new_unchecked(0)) };
+		assert_eq!(shifter.get(), 1);
+	}
+
 	// mfw only const way to extract values of errors in const context is pattern matching
 	macro_rules! assert_result {
 		($val: expr, Ok($expected: expr)) => {
@@ -142,7 +333,9 @@ mod tests {
 		define_const_func!(#[test] too_many_bits() {
 			assert!(PlacesIndex::<127, 2>::new(0).is_ok());
 			assert!(PlacesIndex::<128, 2>::new(0).is_ok());
-			assert_result!(PlacesIndex::<129, 2>::new(0), Err(PlacesIndexCreationError::BadNitLimitEvaluation(MaxNitComputationFailure::BitsTooLarge)));
+			assert!(PlacesIndex::<129, 2>::new(0).is_ok());
+			assert!(PlacesIndex::<1024, 2>::new(1023).is_ok());
+			assert_result!(PlacesIndex::<1025, 2>::new(0), Err(PlacesIndexCreationError::BadNitLimitEvaluation(MaxNitComputationFailure::BitsTooLarge)));
 		});
 		define_const_func!(#[test] base_higher_than_bits() {
 			assert!(PlacesIndex::<1, 2>::new(0).is_ok());
@@ -152,7 +345,11 @@ mod tests {
 		define_const_func!(#[test] too_large_of_a_base() {
 			assert!(PlacesIndex::<128, 127>::new(0).is_ok());
 			assert!(PlacesIndex::<128, 128>::new(0).is_ok());
-			assert_result!(PlacesIndex::<128, 129>::new(0), Err(PlacesIndexCreationError::BadNitLimitEvaluation(MaxNitComputationFailure::BaseTooLarge)));
+			assert!(PlacesIndex::<128, 129>::new(0).is_ok());
+			assert!(PlacesIndex::<64, 1000>::new(5).is_ok());
+			assert_result!(PlacesIndex::<64, 1000>::new(6), Err(PlacesIndexCreationError::OutOfBounds));
+			assert!(PlacesIndex::<8, 255>::new(0).is_ok());
+			assert_result!(PlacesIndex::<8, 256>::new(0), Err(PlacesIndexCreationError::BadNitLimitEvaluation(MaxNitComputationFailure::BaseExceedsMaxBitValues)));
 		});
 
 		define_const_func!(#[test] too_small_of_a_base() {
@@ -0,0 +1,385 @@
+use core::iter::FusedIterator;
+use crate::Nit;
+use crate::base::Powers;
+use crate::internal_macros::{deriving_const, impl_error};
+use crate::max_nits::{compute_max_nits_in_bits, log2};
+use crate::supported::{BaseMaximum, FitsMaximumBits};
+
+deriving_const!((PartialEq) for {
+	/// The reason reading or writing a bitstream of nits failed.
+	#[derive(Debug, Clone, Copy, Eq, Hash)]
+	pub enum BitstreamError {
+		/// The group size is zero, or its digits don't fit within 64 bits.
+		InvalidGroupSize,
+		/// The buffer doesn't have room for the next group.
+		BufferTooSmall,
+		/// The buffer ended before the next group.
+		UnexpectedEnd,
+		/// A group holds a value which isn't the value of any of its digits.
+		InvalidGroup,
+	}
+});
+impl BitstreamError {
+	/// Returns the error message as a string.
+	#[must_use]
+	#[cfg(not(tarpaulin_include))]
+	pub const fn get_str(&self) -> &str {
+		match self {
+			Self::InvalidGroupSize => "The group size is zero, or its digits don't fit within 64 bits.",
+			Self::BufferTooSmall => "The buffer doesn't have room for the next group.",
+			Self::UnexpectedEnd => "The buffer ended before the next group.",
+			Self::InvalidGroup => "A group holds a value which isn't the value of any of its digits.",
+		}
+	}
+}
+#[cfg(not(tarpaulin_include))]
+impl core::fmt::Display for BitstreamError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "{}", self.get_str())
+	}
+}
+impl_error!(BitstreamError);
+
+/// Returns the amount of bits that a group of `k` base-`BASE` digits is written as, which is $\left\lceil k \log\_2 b \right\rceil$.
+///
+/// Returns [`None`] if `k` is zero, or the digits don't fit within 64 bits.
+///
+/// # Example
+/// ```
+/// use nit::bitstream::group_bits;
+/// assert_eq!(group_bits::<3>(3), Some(5));
+/// assert_eq!(group_bits::<3>(5), Some(8));
+/// assert_eq!(group_bits::<3>(17), Some(27));
+/// assert_eq!(group_bits::<3>(41), None);
+/// ```
+#[must_use]
+pub const fn group_bits<const BASE: BaseMaximum>(k: FitsMaximumBits) -> Option<u32> {
+	match compute_max_nits_in_bits::<BASE, 64>() {
+		Ok(capacity) if k != 0 && k <= capacity => {},
+		_ => return None,
+	}
+	// `BASE.pow(k) - 1` is the largest value of the group, which fits within 64 bits.
+	let max = Powers::<u64, BASE>::power(k).wrapping_sub(1);
+	Some(max.ilog2() + 1)
+}
+
+/// Returns the ratio of the information within a group of `k` base-`BASE` digits to the bits it's written as, from zero to one.
+///
+/// Returns [`None`] if `k` is zero, or the digits don't fit within 64 bits.
+///
+/// # Example
+/// ```
+/// use nit::bitstream::efficiency;
+/// // Five trits are 7.92 bits of information, written as eight bits.
+/// assert!((efficiency::<3>(5).unwrap() - 0.9906).abs() < 0.0001);
+/// assert_eq!(efficiency::<4>(3), Some(1.0));
+/// ```
+#[must_use]
+pub fn efficiency<const BASE: BaseMaximum>(k: FitsMaximumBits) -> Option<f64> {
+	let bits = group_bits::<BASE>(k)?;
+	Some(f64::from(k) * log2(BASE) / f64::from(bits))
+}
+
+/// Returns the smallest group size whose [`efficiency`] is at least `target`, or [`None`] if no group which fits within 64 bits is that efficient.
+///
+/// # Example
+/// ```
+/// use nit::bitstream::choose_group_size;
+/// assert_eq!(choose_group_size::<3>(0.9), Some(3));
+/// assert_eq!(choose_group_size::<3>(0.99), Some(5));
+/// assert_eq!(choose_group_size::<3>(0.997), Some(17));
+/// assert_eq!(choose_group_size::<3>(1.0), None);
+/// ```
+#[must_use]
+pub fn choose_group_size<const BASE: BaseMaximum>(target: f64) -> Option<FitsMaximumBits> {
+	let capacity = compute_max_nits_in_bits::<BASE, 64>().ok()?;
+	(1..=capacity).find(|&k| efficiency::<BASE>(k).is_some_and(|efficiency| efficiency >= target))
+}
+
+/// Writes base-`BASE` digits into a buffer of bytes as a bitstream, grouping every `k` digits into $\left\lceil k \log\_2 b \right\rceil$ bits (see [`group_bits`]).
+///
+/// The bits are written from the most significant bit of each byte, and each group is the value of its digits, with the first being the least significant.
+/// A trailing partial group of `r` digits is written as [`group_bits`] of `r` bits once [`finish`](Self::finish)ed,
+/// so the amount of digits is needed to read them back (see [`NitReader`]).
+///
+/// # Example
+/// ```
+/// use nit::bitstream::{NitReader, NitWriter};
+/// use nit::Trit;
+///
+/// let trits = [Trit::TWO, Trit::ONE, Trit::ZERO, Trit::TWO, Trit::ONE, Trit::ONE, Trit::TWO];
+/// let mut buffer = [0; 2];
+/// let mut writer = NitWriter::<3>::new(&mut buffer, 5).unwrap();
+/// for &trit in &trits {
+///     writer.write(trit).unwrap();
+/// }
+/// // Five trits as eight bits, and the last two as four.
+/// assert_eq!(writer.finish(), Ok(12));
+///
+/// let reader = NitReader::<3>::new(&buffer, 5, trits.len()).unwrap();
+/// assert_eq!(reader.collect::<Result<Vec<_>, _>>(), Ok(trits.to_vec()));
+/// ```
+#[derive(Debug)]
+pub struct NitWriter<'a, const BASE: BaseMaximum, const BYTES: usize = 1> {
+	/// The buffer the bits are written into.
+	buffer: &'a mut [u8],
+	/// The amount of bits written so far.
+	position: usize,
+	/// The amount of digits within each group.
+	group_size: FitsMaximumBits,
+	/// The value of the digits of the group being written.
+	group: u64,
+	/// The amount of digits within the group being written.
+	count: FitsMaximumBits,
+}
+
+impl<'a, const BASE: BaseMaximum, const BYTES: usize> NitWriter<'a, BASE, BYTES> {
+	/// Creates a writer into the buffer, grouping every `group_size` digits.
+	///
+	/// # Errors
+	/// - [`BitstreamError::InvalidGroupSize`]: If the group size is zero, or its digits don't fit within 64 bits.
+	pub const fn new(buffer: &'a mut [u8], group_size: FitsMaximumBits) -> Result<Self, BitstreamError> {
+		if group_bits::<BASE>(group_size).is_none() {
+			return Err(BitstreamError::InvalidGroupSize);
+		}
+		Ok(Self { buffer, position: 0, group_size, group: 0, count: 0 })
+	}
+
+	/// Creates a writer into the buffer, with the smallest group size whose [`efficiency`] is at least `target`.
+	///
+	/// # Errors
+	/// - [`BitstreamError::InvalidGroupSize`]: If no group which fits within 64 bits is that efficient.
+	pub fn with_efficiency(buffer: &'a mut [u8], target: f64) -> Result<Self, BitstreamError> {
+		Self::new(buffer, choose_group_size::<BASE>(target).ok_or(BitstreamError::InvalidGroupSize)?)
+	}
+
+	/// Returns the amount of digits within each group.
+	#[must_use]
+	pub const fn group_size(&self) -> FitsMaximumBits {
+		self.group_size
+	}
+
+	/// Returns the amount of bits written so far, not including the group being written.
+	#[must_use]
+	pub const fn bits_written(&self) -> usize {
+		self.position
+	}
+
+	/// Writes a digit, writing the bits of its group once it's full.
+	///
+	/// # Errors
+	/// - [`BitstreamError::BufferTooSmall`]: If the buffer doesn't have room for the group; the digit isn't written.
+	pub fn write(&mut self, nit: Nit<BASE, BYTES>) -> Result<(), BitstreamError> {
+		let shifter = Powers::<u64, BASE>::TABLE[usize::from(self.count)];
+		let group = self.group + u64::from(nit.get_value()) * shifter;
+		if self.count + 1 == self.group_size {
+			self.write_bits(group, self.group_size)?;
+			(self.group, self.count) = (0, 0);
+		} else {
+			(self.group, self.count) = (group, self.count + 1);
+		}
+		Ok(())
+	}
+
+	/// Writes the trailing partial group, returning the total amount of bits written.
+	///
+	/// # Errors
+	/// - [`BitstreamError::BufferTooSmall`]: If the buffer doesn't have room for the trailing group.
+	pub fn finish(mut self) -> Result<usize, BitstreamError> {
+		if self.count != 0 {
+			self.write_bits(self.group, self.count)?;
+		}
+		Ok(self.position)
+	}
+
+	/// Writes the bits of a group of `k` digits, from the most significant.
+	fn write_bits(&mut self, value: u64, k: FitsMaximumBits) -> Result<(), BitstreamError> {
+		let Some(bits) = group_bits::<BASE>(k) else { return Err(BitstreamError::InvalidGroupSize) };
+		let bits = bits as usize;
+		if self.position + bits > self.buffer.len() * 8 {
+			return Err(BitstreamError::BufferTooSmall);
+		}
+		let mut remaining = bits;
+		while remaining > 0 {
+			let (index, offset) = (self.position / 8, self.position % 8);
+			let take = remaining.min(8 - offset);
+			let shift = 8 - offset - take;
+			let mask = (u8::MAX >> (8 - take)) << shift;
+			#[allow(clippy::cast_possible_truncation)]
+			let chunk = ((value >> (remaining - take)) as u8) << shift;
+			self.buffer[index] = (self.buffer[index] & !mask) | (chunk & mask);
+			self.position += take;
+			remaining -= take;
+		}
+		Ok(())
+	}
+}
+
+/// Reads base-`BASE` digits from a bitstream written by a [`NitWriter`] with the same group size.
+///
+/// This is an iterator over exactly the given amount of digits, which ends after the first error.
+#[derive(Debug, Clone)]
+pub struct NitReader<'a, const BASE: BaseMaximum, const BYTES: usize = 1> {
+	/// The buffer the bits are read from.
+	buffer: &'a [u8],
+	/// The amount of bits read so far.
+	position: usize,
+	/// The amount of digits within each group.
+	group_size: FitsMaximumBits,
+	/// The value of the group being read, divided by `BASE` for each digit yielded from it.
+	group: u64,
+	/// The amount of digits left to yield from the group being read.
+	pending: FitsMaximumBits,
+	/// The amount of digits left to read.
+	remaining: usize,
+}
+
+impl<'a, const BASE: BaseMaximum, const BYTES: usize> NitReader<'a, BASE, BYTES> {
+	/// Creates a reader of `len` digits from the buffer, which were grouped every `group_size` digits.
+	///
+	/// # Errors
+	/// - [`BitstreamError::InvalidGroupSize`]: If the group size is zero, or its digits don't fit within 64 bits.
+	pub const fn new(buffer: &'a [u8], group_size: FitsMaximumBits, len: usize) -> Result<Self, BitstreamError> {
+		if group_bits::<BASE>(group_size).is_none() {
+			return Err(BitstreamError::InvalidGroupSize);
+		}
+		Ok(Self { buffer, position: 0, group_size, group: 0, pending: 0, remaining: len })
+	}
+
+	/// Reads the bits of a group of `k` digits, from the most significant.
+	fn read_bits(&mut self, k: FitsMaximumBits) -> Result<u64, BitstreamError> {
+		let Some(bits) = group_bits::<BASE>(k) else { return Err(BitstreamError::InvalidGroupSize) };
+		let bits = bits as usize;
+		if self.position + bits > self.buffer.len() * 8 {
+			return Err(BitstreamError::UnexpectedEnd);
+		}
+		let mut value: u64 = 0;
+		let mut remaining = bits;
+		while remaining > 0 {
+			let (index, offset) = (self.position / 8, self.position % 8);
+			let take = remaining.min(8 - offset);
+			let chunk = (self.buffer[index] >> (8 - offset - take)) & (u8::MAX >> (8 - take));
+			// Shifting in two steps, as a whole shift of 64 bits isn't allowed.
+			value = (value << (take - 1) << 1) | u64::from(chunk);
+			self.position += take;
+			remaining -= take;
+		}
+		Ok(value)
+	}
+}
+
+impl<const BASE: BaseMaximum, const BYTES: usize> Iterator for NitReader<'_, BASE, BYTES> {
+	type Item = Result<Nit<BASE, BYTES>, BitstreamError>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.remaining == 0 {
+			return None;
+		}
+		if self.pending == 0 {
+			#[allow(clippy::cast_possible_truncation)]
+			let k = if self.remaining < usize::from(self.group_size) { self.remaining as FitsMaximumBits } else { self.group_size };
+			let group = self.read_bits(k).and_then(|group| {
+				// The largest value of the group is one less than `BASE.pow(k)`, which wraps to zero for a base of two.
+				if group <= Powers::<u64, BASE>::power(k).wrapping_sub(1) { Ok(group) } else { Err(BitstreamError::InvalidGroup) }
+			});
+			let group = match group {
+				Ok(group) => group,
+				Err(err) => {
+					self.remaining = 0;
+					return Some(Err(err));
+				},
+			};
+			(self.group, self.pending) = (group, k);
+		}
+		let reciprocal = Powers::<u64, BASE>::BASE_RECIPROCAL;
+		let quotient = reciprocal.divide(self.group);
+		let digit = self.group - quotient * reciprocal.divisor();
+		(self.group, self.pending, self.remaining) = (quotient, self.pending - 1, self.remaining - 1);
+		// SAFETY: The value will be always within the range of `0..BASE` because it is the remainder of a division by `BASE`.
+		unsafe { #[allow(clippy::cast_possible_truncation, clippy::cast_lossless)] let digit = digit as BaseMaximum; Some(Ok(Nit::new_unchecked(digit))) }
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.remaining, Some(self.remaining))
+	}
+}
+
+impl<const BASE: BaseMaximum, const BYTES: usize> FusedIterator for NitReader<'_, BASE, BYTES> {}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::missing_docs_in_private_items)]
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+	use crate::Trit;
+
+	fn digits<const BASE: BaseMaximum, const BYTES: usize>(n: usize) -> Vec<Nit<BASE, BYTES>> {
+		let mut state = 0x9E37_79B9_u32;
+		(0..n).map(|_| {
+			state = state.wrapping_mul(0x5851_F42D).wrapping_add(0x1405_7B7E);
+			Nit::new((state >> 16) % BASE).unwrap()
+		}).collect()
+	}
+
+	fn check_round_trips<const BASE: BaseMaximum, const BYTES: usize>() {
+		let original = digits::<BASE, BYTES>(200);
+		let capacity = compute_max_nits_in_bits::<BASE, 64>().unwrap();
+		for group_size in 1..=capacity {
+			for len in [0, 1, 2, 7, 64, 199, 200] {
+				let mut buffer = [0xA5; 512];
+				let mut writer = NitWriter::<BASE, BYTES>::new(&mut buffer, group_size).unwrap();
+				for &nit in &original[..len] {
+					writer.write(nit).unwrap();
+				}
+				let full = len / usize::from(group_size);
+				#[allow(clippy::cast_possible_truncation)]
+				let partial = group_bits::<BASE>((len % usize::from(group_size)) as FitsMaximumBits).unwrap_or(0) as usize;
+				assert_eq!(writer.finish(), Ok(full * group_bits::<BASE>(group_size).unwrap() as usize + partial));
+				let reader = NitReader::<BASE, BYTES>::new(&buffer, group_size, len).unwrap();
+				assert_eq!(reader.collect::<Result<Vec<_>, _>>().unwrap(), &original[..len], "{len} digits in groups of {group_size} in base {BASE}");
+			}
+		}
+	}
+
+	#[test]
+	fn round_trips() {
+		check_round_trips::<2, 1>();
+		check_round_trips::<3, 1>();
+		check_round_trips::<10, 1>();
+		check_round_trips::<85, 1>();
+		check_round_trips::<128, 1>();
+		check_round_trips::<1000, 2>();
+		check_round_trips::<60000, 2>();
+		check_round_trips::<100_000, 4>();
+	}
+
+	#[test]
+	fn group_sizes() {
+		assert_eq!(group_bits::<2>(64), Some(64));
+		assert_eq!(group_bits::<2>(65), None);
+		assert_eq!(group_bits::<3>(0), None);
+		assert_eq!(group_bits::<3>(40), Some(64));
+		assert_eq!(choose_group_size::<2>(1.0), Some(1));
+		assert!(NitWriter::<3>::new(&mut [], 41).is_err());
+	}
+
+	#[test]
+	fn errors() {
+		let mut buffer = [0; 1];
+		let mut writer = NitWriter::<3>::new(&mut buffer, 5).unwrap();
+		for _ in 0..4 {
+			writer.write(Trit::TWO).unwrap();
+		}
+		writer.write(Trit::TWO).unwrap();
+		assert_eq!(writer.write(Trit::TWO), Ok(()));
+		assert_eq!(writer.finish(), Err(BitstreamError::BufferTooSmall));
+		// 255 isn't the value of any five trits.
+		let mut reader = NitReader::<3>::new(&[0xFF], 5, 5).unwrap();
+		assert_eq!(reader.next(), Some(Err(BitstreamError::InvalidGroup)));
+		assert_eq!(reader.next(), None);
+		let mut reader = NitReader::<3>::new(&[0x00], 5, 6).unwrap();
+		assert_eq!(reader.size_hint(), (6, Some(6)));
+		assert_eq!(reader.nth(5), Some(Err(BitstreamError::UnexpectedEnd)));
+	}
+
+}
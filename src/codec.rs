@@ -0,0 +1,356 @@
+#[cfg(feature = "alloc")] use alloc::{string::String, vec, vec::Vec};
+use crate::Nit;
+use crate::base::Powers;
+use crate::data_container::NitDataContainer;
+use crate::internal_macros::{deriving_const, impl_error};
+use crate::max_nits::compute_max_nits_in_bits;
+use crate::supported::{BaseMaximum, FitsMaximumBitsAsType};
+
+deriving_const!((PartialEq) for {
+	/// The reason encoding or decoding text failed.
+	#[derive(Debug, Clone, Copy, Eq, Hash)]
+	pub enum CodecError {
+		/// The alphabet doesn't have exactly `BASE` symbols, or they aren't all distinct ASCII characters.
+		InvalidAlphabet,
+		/// The output buffer is too small to hold the result.
+		BufferTooSmall,
+		/// The symbol at the given position of the text isn't within the alphabet.
+		InvalidSymbol(usize),
+		/// The amount of symbols in the trailing block isn't the amount that any amount of bytes is encoded as.
+		InvalidLength,
+		/// The block starting at the given position of the text holds a value that doesn't fit within its bytes, so no bytes are encoded as it.
+		NonCanonical(usize),
+		/// The number doesn't fit within the type it's decoded as.
+		Overflow,
+		/// The check symbol doesn't match the number.
+		ChecksumMismatch,
+	}
+});
+impl CodecError {
+	/// Returns the error message as a string.
+	#[must_use]
+	#[cfg(not(tarpaulin_include))]
+	pub const fn get_str(&self) -> &str {
+		match self {
+			Self::InvalidAlphabet => "The alphabet doesn't have exactly `BASE` symbols, or they aren't all distinct ASCII characters.",
+			Self::BufferTooSmall => "The output buffer is too small to hold the result.",
+			Self::InvalidSymbol(_) => "A symbol of the text isn't within the alphabet.",
+			Self::InvalidLength => "The amount of symbols in the trailing block isn't the amount that any amount of bytes is encoded as.",
+			Self::NonCanonical(_) => "A block holds a value that doesn't fit within its bytes, so no bytes are encoded as it.",
+			Self::Overflow => "The number doesn't fit within the type it's decoded as.",
+			Self::ChecksumMismatch => "The check symbol doesn't match the number.",
+		}
+	}
+}
+#[cfg(not(tarpaulin_include))]
+impl core::fmt::Display for CodecError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "{}", self.get_str())
+	}
+}
+impl_error!(CodecError);
+
+/// [Z85](https://rfc.zeromq.org/spec/32/), `ZeroMQ`'s encoding of every four bytes as five base-85 symbols.
+pub mod z85;
+/// [Ascii85](https://en.wikipedia.org/wiki/Ascii85), Adobe's and `btoa`'s encoding of every four bytes as five base-85 symbols, with `z` as a shorthand for four zero bytes.
+pub mod ascii85;
+/// [Crockford's Base32](https://www.crockford.com/base32.html), an encoding of numbers meant to be read and typed by people, with an optional check symbol.
+pub mod crockford;
+
+/// Returns the five base-85 digits of a word, most significant first.
+#[allow(clippy::cast_possible_truncation)]
+fn base85_digits(word: u32) -> [u8; 5] {
+	// Only four base-85 digits fit within the nit capacity of a `u32`; the fifth is what remains above them, which is less than 85.
+	let (high, low) = Powers::<u32, 85>::split_at_place(word, 4);
+	let [first, second, third, fourth]: [Nit<85>; 4] = low.to_nits();
+	[high, fourth.get_value(), third.get_value(), second.get_value(), first.get_value()].map(|digit| digit as u8)
+}
+
+/// Returns the word with the five base-85 digits, most significant first, or [`None`] if it doesn't fit within a `u32`.
+fn base85_word(digits: [u8; 5]) -> Option<u32> {
+	let [high, rest @ ..] = digits;
+	let low = rest.iter().rev().map(|&digit| Nit::<85>::new(digit.into())).collect::<Result<u32, _>>().ok()?;
+	u32::from(high).checked_mul(Powers::<u32, 85>::power(4))?.checked_add(low)
+}
+
+/// The most bytes within a block; any more, and the largest value of the symbols they're encoded as may not fit within a [`u128`].
+const MAXIMUM_BLOCK_BYTES: usize = 15;
+
+/// A marker within the table of symbols for a byte which isn't a symbol.
+const NOT_A_SYMBOL: u8 = u8::MAX;
+
+/// Encodes bytes as text with an alphabet of `BASE` symbols, and decodes them back.
+///
+/// The bytes are split into blocks, each of which is read as a big-endian integer and written as its base-`BASE` digits, most significant first.
+/// The size of the blocks is chosen to have the most bits per symbol possible (see [`BLOCK`](Self::BLOCK)), so any base can be used efficiently, not just powers of two.
+/// A trailing partial block is written as the fewest symbols which can hold any value of its bytes.
+///
+/// Decoding is strict; anything but the exact text that some bytes are encoded as is rejected.
+/// As blocks are independent, a buffer can be encoded or decoded piecewise, as long as every piece but the last is a whole amount of blocks.
+///
+/// This doesn't allocate; see `encode_to_string` and `decode_to_vec` for convenience functions with the `alloc` feature.
+///
+/// # Example
+/// ```
+/// use nit::codec::{Codec, CodecError};
+/// let codec = Codec::<36>::new(b"0123456789abcdefghijklmnopqrstuvwxyz").unwrap();
+/// // Nine bytes are encoded as fourteen symbols, which holds 5.14 bits per symbol out of a possible 5.17.
+/// assert_eq!(Codec::<36>::BLOCK, (9, 14));
+///
+/// let mut text = [0; 16];
+/// assert_eq!(codec.encode(b"Hello!", &mut text), Ok(10));
+/// assert_eq!(&text[..10], b"0s7rxvhd0h");
+///
+/// let mut bytes = [0; 6];
+/// assert_eq!(codec.decode(&text[..10], &mut bytes), Ok(6));
+/// assert_eq!(&bytes, b"Hello!");
+/// assert_eq!(codec.decode(b"0s7rxvhd0!", &mut bytes), Err(CodecError::InvalidSymbol(9)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Codec<'a, const BASE: BaseMaximum> {
+	/// The symbol of each digit, in order from zero.
+	alphabet: &'a [u8],
+	/// The digit of each byte which is a symbol, or [`NOT_A_SYMBOL`].
+	table: [u8; 256],
+}
+
+impl<'a, const BASE: BaseMaximum> Codec<'a, BASE> {
+	/// The amount of bytes within each block, and the amount of symbols each block is encoded as.
+	///
+	/// This is the amount of bytes, up to fifteen, which has the most bits per symbol, preferring fewer bytes.
+	/// This fails to evaluate at compile-time if the base is erroneous.
+	pub const BLOCK: (usize, usize) = {
+		if let Err(err) = compute_max_nits_in_bits::<BASE, 8>() {
+			panic!("{}", err.get_str());
+		}
+		let mut best = (1, Self::symbols_for(1));
+		let mut bytes = 2;
+		while bytes <= MAXIMUM_BLOCK_BYTES {
+			let symbols = Self::symbols_for(bytes);
+			// Comparing `bytes / symbols` against the best ratio, without dividing.
+			if bytes * best.1 > best.0 * symbols {
+				best = (bytes, symbols);
+			}
+			bytes += 1;
+		}
+		best
+	};
+
+	/// Returns the fewest symbols which can hold any value of the given amount of bytes, up to [`MAXIMUM_BLOCK_BYTES`].
+	#[allow(clippy::cast_lossless, clippy::cast_possible_truncation)]
+	const fn symbols_for(bytes: usize) -> usize {
+		if bytes == 0 {
+			return 0;
+		}
+		let max = FitsMaximumBitsAsType::MAX >> (FitsMaximumBitsAsType::BITS - 8 * bytes as u32);
+		match max.checked_ilog(BASE as FitsMaximumBitsAsType) {
+			Some(log) => log as usize + 1,
+			None => 1,
+		}
+	}
+
+	/// Returns the amount of bytes within a partial block of the given amount of symbols, or [`None`] if no amount of bytes is encoded as it.
+	const fn bytes_for(symbols: usize) -> Option<usize> {
+		let mut bytes = 0;
+		while bytes < Self::BLOCK.0 {
+			if Self::symbols_for(bytes) == symbols {
+				return Some(bytes);
+			}
+			bytes += 1;
+		}
+		None
+	}
+
+	/// Creates a codec with the given symbols, in order from zero.
+	///
+	/// # Errors
+	/// - [`CodecError::InvalidAlphabet`]: If there aren't exactly `BASE` symbols, or they aren't all distinct ASCII characters.
+	pub const fn new(alphabet: &'a [u8]) -> Result<Self, CodecError> {
+		if alphabet.len() != BASE as usize {
+			return Err(CodecError::InvalidAlphabet);
+		}
+		let mut table = [NOT_A_SYMBOL; 256];
+		let mut i = 0;
+		while i < alphabet.len() {
+			let symbol = alphabet[i] as usize;
+			if !alphabet[i].is_ascii() || table[symbol] != NOT_A_SYMBOL {
+				return Err(CodecError::InvalidAlphabet);
+			}
+			#[allow(clippy::cast_possible_truncation)]
+			let digit = i as u8;
+			table[symbol] = digit;
+			i += 1;
+		}
+		Ok(Self { alphabet, table })
+	}
+
+	/// Returns the amount of symbols that the given amount of bytes are encoded as.
+	#[must_use]
+	pub const fn encoded_len(bytes: usize) -> usize {
+		let (size, symbols) = Self::BLOCK;
+		bytes / size * symbols + Self::symbols_for(bytes % size)
+	}
+
+	/// Returns the amount of bytes that the given amount of symbols are decoded as.
+	///
+	/// # Errors
+	/// - [`CodecError::InvalidLength`]: If no amount of bytes is encoded as the given amount of symbols.
+	pub const fn decoded_len(symbols: usize) -> Result<usize, CodecError> {
+		let (size, encoded) = Self::BLOCK;
+		match Self::bytes_for(symbols % encoded) {
+			Some(bytes) => Ok(symbols / encoded * size + bytes),
+			None => Err(CodecError::InvalidLength),
+		}
+	}
+
+	/// Encodes the bytes as symbols into `out`, returning the amount of symbols written, which is [`encoded_len`](Self::encoded_len) of the input.
+	///
+	/// # Errors
+	/// - [`CodecError::BufferTooSmall`]: If `out` is shorter than the amount of symbols; nothing is written.
+	pub fn encode(&self, bytes: &[u8], out: &mut [u8]) -> Result<usize, CodecError> {
+		let len = Self::encoded_len(bytes.len());
+		let out = out.get_mut(..len).ok_or(CodecError::BufferTooSmall)?;
+		let (size, symbols) = Self::BLOCK;
+		for (block, text) in bytes.chunks(size).zip(out.chunks_mut(symbols)) {
+			let value = block.iter().fold(0, |value: FitsMaximumBitsAsType, &byte| value << 8 | FitsMaximumBitsAsType::from(byte));
+			// A trailing partial block has fewer symbols, which the chunk of the text is already limited to.
+			for (slot, nit) in text.iter_mut().rev().zip(value.iter_nits::<BASE, 1>()) {
+				*slot = self.alphabet[nit.get_value() as usize];
+			}
+		}
+		Ok(len)
+	}
+
+	/// Decodes the symbols back into bytes in `out`, returning the amount of bytes written, which is [`decoded_len`](Self::decoded_len) of the input.
+	///
+	/// # Errors
+	/// - [`CodecError::InvalidLength`]: If no amount of bytes is encoded as the amount of symbols; nothing is written.
+	/// - [`CodecError::BufferTooSmall`]: If `out` is shorter than the amount of bytes; nothing is written.
+	/// - [`CodecError::InvalidSymbol`]: If a symbol isn't within the alphabet.
+	/// - [`CodecError::NonCanonical`]: If a block holds a value that doesn't fit within its bytes.
+	///
+	/// The blocks before an invalid one will have been written.
+	pub fn decode(&self, text: &[u8], out: &mut [u8]) -> Result<usize, CodecError> {
+		let len = Self::decoded_len(text.len())?;
+		let out = out.get_mut(..len).ok_or(CodecError::BufferTooSmall)?;
+		let (size, symbols) = Self::BLOCK;
+		#[allow(clippy::cast_lossless)]
+		let base = BASE as FitsMaximumBitsAsType;
+		for (index, (block, bytes)) in text.chunks(symbols).zip(out.chunks_mut(size)).enumerate() {
+			let start = index * symbols;
+			let mut value: FitsMaximumBitsAsType = 0;
+			for (offset, &symbol) in block.iter().enumerate() {
+				let digit = self.table[usize::from(symbol)];
+				if digit == NOT_A_SYMBOL {
+					return Err(CodecError::InvalidSymbol(start + offset));
+				}
+				// The largest value of a block's symbols fits within a `u128`, as there are at most fifteen bytes within a block.
+				value = value * base + FitsMaximumBitsAsType::from(digit);
+			}
+			// The value must fit within the bytes, which are at most fifteen, so this can't overflow.
+			if value >> (8 * bytes.len()) != 0 {
+				return Err(CodecError::NonCanonical(start));
+			}
+			for (slot, byte) in bytes.iter_mut().rev().zip(value.to_le_bytes()) {
+				*slot = byte;
+			}
+		}
+		Ok(len)
+	}
+
+	/// Encodes the bytes as a string of symbols.
+	///
+	/// # Example
+	/// ```
+	/// use nit::codec::Codec;
+	/// let codec = Codec::<3>::new(b"012").unwrap();
+	/// assert_eq!(codec.encode_to_string(&[255]), "100110");
+	/// assert_eq!(codec.decode_to_vec("100110"), Ok(vec![255]));
+	/// ```
+	#[cfg(feature = "alloc")]
+	#[must_use]
+	pub fn encode_to_string(&self, bytes: &[u8]) -> String {
+		let mut text = vec![0; Self::encoded_len(bytes.len())];
+		// The buffer is exactly as long as it needs to be.
+		let _ = self.encode(bytes, &mut text);
+		text.into_iter().map(char::from).collect()
+	}
+
+	/// Decodes the symbols of a string back into bytes.
+	///
+	/// # Errors
+	/// See: [`decode`](Self::decode)
+	#[cfg(feature = "alloc")]
+	pub fn decode_to_vec(&self, text: &str) -> Result<Vec<u8>, CodecError> {
+		let mut bytes = vec![0; Self::decoded_len(text.len())?];
+		self.decode(text.as_bytes(), &mut bytes)?;
+		Ok(bytes)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::missing_docs_in_private_items)]
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+
+	const ALPHABET: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ!#$%&()*+-;<=>?@^_`{|}~[]:,./'\"\\ ";
+
+	#[cfg(feature = "alloc")]
+	fn check_round_trips<const BASE: BaseMaximum>() {
+		let codec = Codec::<BASE>::new(&ALPHABET[..BASE as usize]).unwrap();
+		let mut state = 0x9E37_79B9_u32;
+		let bytes: Vec<u8> = (0..100).map(|_| {
+			state = state.wrapping_mul(0x5851_F42D).wrapping_add(0x1405_7B7E);
+			(state >> 24).to_le_bytes()[0]
+		}).collect();
+		for len in 0..bytes.len() {
+			let text = codec.encode_to_string(&bytes[..len]);
+			assert_eq!(text.len(), Codec::<BASE>::encoded_len(len));
+			assert_eq!(codec.decode_to_vec(&text).unwrap(), &bytes[..len], "{len} bytes in base {BASE}");
+		}
+		// Every amount of bytes is encoded as a distinct amount of symbols.
+		for len in 0..bytes.len() {
+			assert_eq!(Codec::<BASE>::decoded_len(Codec::<BASE>::encoded_len(len)), Ok(len));
+		}
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn round_trips() {
+		check_round_trips::<2>();
+		check_round_trips::<3>();
+		check_round_trips::<10>();
+		check_round_trips::<36>();
+		check_round_trips::<62>();
+		check_round_trips::<85>();
+		check_round_trips::<95>();
+	}
+
+	#[test]
+	fn block_sizes() {
+		assert_eq!(Codec::<2>::BLOCK, (1, 8));
+		assert_eq!(Codec::<3>::BLOCK, (15, 76));
+		assert_eq!(Codec::<62>::BLOCK, (14, 19));
+		assert_eq!(Codec::<64>::BLOCK, (3, 4));
+		assert_eq!(Codec::<85>::BLOCK, (4, 5));
+	}
+
+	#[test]
+	fn strictness() {
+		let codec = Codec::<85>::new(&ALPHABET[..85]).unwrap();
+		let mut bytes = [0; 8];
+		// The largest symbols of a full block are beyond `u32::MAX`.
+		let largest = [ALPHABET[84]; 5];
+		assert_eq!(codec.decode(&largest, &mut bytes), Err(CodecError::NonCanonical(0)));
+		// As are those of a trailing block of two bytes, which is three symbols.
+		assert_eq!(codec.decode(b"00000~~~", &mut bytes), Err(CodecError::NonCanonical(5)));
+		assert_eq!(codec.decode(b"000000", &mut bytes), Err(CodecError::InvalidLength));
+		assert_eq!(codec.decode(b"0000 ", &mut bytes), Err(CodecError::InvalidSymbol(4)));
+		assert_eq!(codec.decode(b"00000000", &mut bytes[..5]), Err(CodecError::BufferTooSmall));
+		assert_eq!(codec.encode(&[0; 4], &mut bytes[..4]), Err(CodecError::BufferTooSmall));
+		assert_eq!(Codec::<3>::new(b"001"), Err(CodecError::InvalidAlphabet));
+		assert_eq!(Codec::<3>::new(b"01"), Err(CodecError::InvalidAlphabet));
+		assert_eq!(Codec::<3>::new("0é".as_bytes()), Err(CodecError::InvalidAlphabet));
+	}
+}
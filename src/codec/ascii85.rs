@@ -0,0 +1,196 @@
+#[cfg(feature = "alloc")] use alloc::{string::String, vec, vec::Vec};
+use super::{base85_digits, base85_word, CodecError};
+
+/// The symbol of the zero digit; the rest follow it in ASCII order, up to `u`.
+const FIRST_SYMBOL: u8 = b'!';
+
+/// The symbol standing in for a whole group of four zero bytes.
+const ZERO_GROUP_SYMBOL: u8 = b'z';
+
+/// The highest digit, which a trailing partial group of symbols is padded with.
+const HIGHEST_DIGIT: u8 = 84;
+
+/// Returns the most symbols that the given amount of bytes can be encoded as, which is when none of the groups are zero.
+///
+/// A trailing partial group of `n` bytes is encoded as `n + 1` symbols.
+#[must_use]
+pub const fn max_encoded_len(bytes: usize) -> usize {
+	let remainder = bytes % 4;
+	bytes / 4 * 5 + if remainder == 0 { 0 } else { remainder + 1 }
+}
+
+/// Returns the most bytes that the given amount of symbols can be decoded as, which is when all of them are `z`.
+#[must_use]
+pub const fn max_decoded_len(symbols: usize) -> usize {
+	symbols * 4
+}
+
+/// Encodes the bytes into `out`, returning the amount of symbols written.
+///
+/// Every group of four zero bytes is encoded as `z`, and a trailing partial group of `n` bytes is encoded as the first `n + 1` symbols of the group padded with zeroes.
+/// The `<~` and `~>` delimiters of Adobe's variant aren't written.
+///
+/// # Errors
+/// - [`CodecError::BufferTooSmall`]: If `out` is too short to hold the symbols. The groups before the one which didn't fit will have been written.
+///
+/// # Example
+/// ```
+/// use nit::codec::ascii85;
+/// let mut text = [0; 16];
+/// assert_eq!(ascii85::encode(b"Man \0\0\0\0.", &mut text), Ok(8));
+/// assert_eq!(&text[..8], b"9jqo^z/c");
+/// ```
+pub fn encode(bytes: &[u8], out: &mut [u8]) -> Result<usize, CodecError> {
+	let mut written = 0;
+	for group in bytes.chunks(4) {
+		let mut word = [0; 4];
+		word[..group.len()].copy_from_slice(group);
+		let word = u32::from_be_bytes(word);
+		if word == 0 && group.len() == 4 {
+			*out.get_mut(written).ok_or(CodecError::BufferTooSmall)? = ZERO_GROUP_SYMBOL;
+			written += 1;
+			continue;
+		}
+		let len = group.len() + 1;
+		let text = out.get_mut(written..written + len).ok_or(CodecError::BufferTooSmall)?;
+		for (slot, digit) in text.iter_mut().zip(base85_digits(word)) {
+			*slot = FIRST_SYMBOL + digit;
+		}
+		written += len;
+	}
+	Ok(written)
+}
+
+/// Decodes the symbols into `out`, returning the amount of bytes written.
+///
+/// ASCII whitespace is ignored, and `z` is decoded as four zero bytes when it isn't within a group.
+/// A trailing partial group of `n` symbols is decoded as the first `n - 1` bytes of the group padded with `u`, the highest digit.
+///
+/// # Errors
+/// - [`CodecError::InvalidSymbol`]: If a symbol isn't within `!..=u`, nor `z` between groups, nor whitespace.
+/// - [`CodecError::InvalidLength`]: If the trailing group is a single symbol, which no bytes are encoded as.
+/// - [`CodecError::NonCanonical`]: If a group of symbols holds a value beyond [`u32::MAX`].
+/// - [`CodecError::BufferTooSmall`]: If `out` is too short to hold the bytes.
+///
+/// The groups before an invalid one will have been written.
+///
+/// # Example
+/// ```
+/// use nit::codec::{ascii85, CodecError};
+/// let mut bytes = [0; 16];
+/// assert_eq!(ascii85::decode(b"9jqo^ z\n/c", &mut bytes), Ok(9));
+/// assert_eq!(&bytes[..9], b"Man \0\0\0\0.");
+/// assert_eq!(ascii85::decode(b"9jqo^/", &mut bytes), Err(CodecError::InvalidLength));
+/// assert_eq!(ascii85::decode(b"9jzo^", &mut bytes), Err(CodecError::InvalidSymbol(2)));
+/// ```
+pub fn decode(text: &[u8], out: &mut [u8]) -> Result<usize, CodecError> {
+	let mut written = 0;
+	let mut digits = [0; 5];
+	let mut count = 0;
+	let mut start = 0;
+	let mut flush = |digits: [u8; 5], count: usize, start: usize| -> Result<(), CodecError> {
+		let word = base85_word(digits).ok_or(CodecError::NonCanonical(start))?;
+		let len = count - 1;
+		let bytes = out.get_mut(written..written + len).ok_or(CodecError::BufferTooSmall)?;
+		bytes.copy_from_slice(&word.to_be_bytes()[..len]);
+		written += len;
+		Ok(())
+	};
+	for (position, &symbol) in text.iter().enumerate() {
+		if symbol.is_ascii_whitespace() {
+			continue;
+		}
+		if symbol == ZERO_GROUP_SYMBOL && count == 0 {
+			flush([0; 5], 5, position)?;
+			continue;
+		}
+		if !(FIRST_SYMBOL..=FIRST_SYMBOL + HIGHEST_DIGIT).contains(&symbol) {
+			return Err(CodecError::InvalidSymbol(position));
+		}
+		if count == 0 {
+			start = position;
+		}
+		digits[count] = symbol - FIRST_SYMBOL;
+		count += 1;
+		if count == 5 {
+			flush(digits, count, start)?;
+			count = 0;
+		}
+	}
+	match count {
+		0 => {},
+		1 => return Err(CodecError::InvalidLength),
+		_ => {
+			// The padding makes the value the greatest with those leading digits, which is then truncated to the leading bytes.
+			digits[count..].fill(HIGHEST_DIGIT);
+			flush(digits, count, start)?;
+		},
+	}
+	Ok(written)
+}
+
+/// Encodes the bytes as a string.
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn encode_to_string(bytes: &[u8]) -> String {
+	let mut text = vec![0; max_encoded_len(bytes.len())];
+	// The buffer is long enough for any bytes.
+	let len = encode(bytes, &mut text).unwrap_or_default();
+	text.truncate(len);
+	text.into_iter().map(char::from).collect()
+}
+
+/// Decodes the symbols of a string back into bytes.
+///
+/// # Errors
+/// See: [`decode`]
+#[cfg(feature = "alloc")]
+pub fn decode_to_vec(text: &str) -> Result<Vec<u8>, CodecError> {
+	let mut bytes = vec![0; max_decoded_len(text.len())];
+	let len = decode(text.as_bytes(), &mut bytes)?;
+	bytes.truncate(len);
+	Ok(bytes)
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+	#![allow(clippy::missing_docs_in_private_items)]
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+
+	/// The example from Wikipedia's article, the start of a passage from Thomas Hobbes's *Leviathan*.
+	const LEVIATHAN: &str = "Man is distinguished, not only by his reason, but by this singular passion from other animals, which is a lust of the mind, that by a perseverance of delight in the continued and indefatigable generation of knowledge, exceeds the short vehemence of any carnal pleasure.";
+	const LEVIATHAN_ENCODED: &str = concat!(
+		"9jqo^BlbD-BleB1DJ+*+F(f,q/0JhKF<GL>Cj@.4Gp$d7F!,L7@<6@)/0JDEF<G%<+EV:2F!,O<DJ+*.@<*K0@<6L(Df-\\0Ec5e;DffZ(EZee.Bl.9pF\"AGXBPCsi+DGm>@3BB/F*&OCAfu2/AKYi(",
+		"DIb:@FD,*)+C]U=@3BN#EcYf8ATD3s@q?d$AftVqCh[NqF<G:8+EV:.+Cf>-FD5W8ARlolDIal(DId<j@<?3r@:F%a+D58'ATD4$Bl@l3De:,-DJs`8ARoFb/0JMK@qB4^F!,R<AKZ&-DfTqBG%G>u",
+		"D.RTpAKYo'+CT/5+Cei#DII?(E,9)oF*2M7/c",
+	);
+
+	#[test]
+	fn published_vectors() {
+		assert_eq!(encode_to_string(LEVIATHAN.as_bytes()), LEVIATHAN_ENCODED);
+		assert_eq!(decode_to_vec(LEVIATHAN_ENCODED).unwrap(), LEVIATHAN.as_bytes());
+		for (bytes, text) in [
+			(&b""[..], ""),
+			(b".", "/c"),
+			(b"\0\0\0", "!!!!"),
+			(b"\0\0\0\0", "z"),
+			(b"\0\0\0\0\0", "z!!"),
+			(b"\xFF\xFF\xFF\xFF", "s8W-!"),
+			(b"Hello, World!", "87cURD_*#4DfTZ)+T"),
+		] {
+			assert_eq!(encode_to_string(bytes), text);
+			assert_eq!(decode_to_vec(text).unwrap(), bytes);
+		}
+	}
+
+	#[test]
+	fn strictness() {
+		assert_eq!(decode_to_vec("s8W-\""), Err(CodecError::NonCanonical(0)));
+		assert_eq!(decode_to_vec("zs8W-\""), Err(CodecError::NonCanonical(1)));
+		assert_eq!(decode_to_vec("!!!!v"), Err(CodecError::InvalidSymbol(4)));
+		assert_eq!(decode_to_vec("!!!!!!"), Err(CodecError::InvalidLength));
+		let mut text = [0; 4];
+		assert_eq!(encode(b"Man ", &mut text), Err(CodecError::BufferTooSmall));
+	}
+}
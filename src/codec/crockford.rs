@@ -0,0 +1,208 @@
+#[cfg(feature = "alloc")] use alloc::string::String;
+use crate::Nit;
+use crate::base::Powers;
+use crate::data_container::NitDataContainer;
+use super::{CodecError, NOT_A_SYMBOL};
+
+/// The symbols of the digits, in order from zero, followed by the five extra check symbols.
+pub const ALPHABET: &[u8; 37] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ*~$=U";
+
+/// The amount of symbols which are digits; the rest can only be check symbols.
+const DIGITS: u8 = 32;
+
+/// The most symbols a `u32` is encoded as, including the check symbol.
+pub const MAX_ENCODED_LEN: usize = 8;
+
+/// The digit of each byte which is a symbol, or [`NOT_A_SYMBOL`], accepting lowercase letters and the letters that are easily mistaken for digits.
+const TABLE: [u8; 256] = {
+	let mut table = [NOT_A_SYMBOL; 256];
+	let mut i = 0;
+	while i < ALPHABET.len() {
+		#[allow(clippy::cast_possible_truncation)]
+		let digit = i as u8;
+		table[ALPHABET[i] as usize] = digit;
+		table[ALPHABET[i].to_ascii_lowercase() as usize] = digit;
+		i += 1;
+	}
+	table[b'O' as usize] = 0;
+	table[b'o' as usize] = 0;
+	table[b'I' as usize] = 1;
+	table[b'i' as usize] = 1;
+	table[b'L' as usize] = 1;
+	table[b'l' as usize] = 1;
+	table
+};
+
+/// The separator which may be placed anywhere for readability, and is ignored when decoding.
+const SEPARATOR: u8 = b'-';
+
+/// Encodes the number into `out` as the fewest digits, returning the amount of symbols written.
+///
+/// # Errors
+/// - [`CodecError::BufferTooSmall`]: If `out` is too short to hold the symbols; nothing is written.
+///
+/// # Example
+/// ```
+/// use nit::codec::crockford;
+/// let mut text = [0; crockford::MAX_ENCODED_LEN];
+/// assert_eq!(crockford::encode(1234, &mut text), Ok(3));
+/// assert_eq!(&text[..3], b"16J");
+/// ```
+pub fn encode(value: u32, out: &mut [u8]) -> Result<usize, CodecError> {
+	// Only six base-32 digits fit within the nit capacity of a `u32`; the seventh is what remains above them, which is less than four.
+	let (high, low) = Powers::<u32, 32>::split_at_place(value, 6);
+	let low: [Nit<32>; 6] = low.to_nits();
+	let mut digits = [high; 7];
+	for (digit, nit) in digits.iter_mut().rev().zip(low) {
+		*digit = nit.get_value();
+	}
+	#[allow(clippy::cast_possible_truncation)]
+	let digits = digits.map(|digit| digit as u8);
+	// Zero is still written as a single digit.
+	let leading = digits[..6].iter().take_while(|&&digit| digit == 0).count();
+	let digits = &digits[leading..];
+	let out = out.get_mut(..digits.len()).ok_or(CodecError::BufferTooSmall)?;
+	for (slot, &digit) in out.iter_mut().zip(digits) {
+		*slot = ALPHABET[usize::from(digit)];
+	}
+	Ok(digits.len())
+}
+
+/// Encodes the number into `out` as the fewest digits followed by its check symbol, which is the number modulo 37, returning the amount of symbols written.
+///
+/// # Errors
+/// - [`CodecError::BufferTooSmall`]: If `out` is too short to hold the symbols; nothing is written.
+///
+/// # Example
+/// ```
+/// use nit::codec::crockford;
+/// let mut text = [0; crockford::MAX_ENCODED_LEN];
+/// assert_eq!(crockford::encode_with_check(1234, &mut text), Ok(4));
+/// assert_eq!(&text[..4], b"16JD");
+/// ```
+pub fn encode_with_check(value: u32, out: &mut [u8]) -> Result<usize, CodecError> {
+	let mut text = [0; MAX_ENCODED_LEN];
+	let len = encode(value, &mut text)?;
+	let out = out.get_mut(..=len).ok_or(CodecError::BufferTooSmall)?;
+	out[..len].copy_from_slice(&text[..len]);
+	out[len] = ALPHABET[(value % 37) as usize];
+	Ok(len + 1)
+}
+
+/// Decodes the symbols into a number.
+///
+/// Lowercase letters are accepted, `O` is read as `0`, `I` and `L` are read as `1`, and hyphens are ignored.
+///
+/// # Errors
+/// - [`CodecError::InvalidSymbol`]: If a symbol isn't a digit nor a hyphen.
+/// - [`CodecError::InvalidLength`]: If there are no digits.
+/// - [`CodecError::Overflow`]: If the number doesn't fit within a `u32`.
+///
+/// # Example
+/// ```
+/// use nit::codec::{crockford, CodecError};
+/// assert_eq!(crockford::decode(b"16J"), Ok(1234));
+/// assert_eq!(crockford::decode(b"1-6j"), Ok(1234));
+/// assert_eq!(crockford::decode(b"L6J"), Ok(1234));
+/// assert_eq!(crockford::decode(b"16U"), Err(CodecError::InvalidSymbol(2)));
+/// assert_eq!(crockford::decode(b"4000000"), Err(CodecError::Overflow));
+/// ```
+pub fn decode(text: &[u8]) -> Result<u32, CodecError> {
+	let mut value: u32 = 0;
+	let mut any = false;
+	for (position, &symbol) in text.iter().enumerate() {
+		if symbol == SEPARATOR {
+			continue;
+		}
+		let digit = TABLE[usize::from(symbol)];
+		if digit >= DIGITS {
+			return Err(CodecError::InvalidSymbol(position));
+		}
+		value = value.checked_mul(u32::from(DIGITS)).and_then(|value| value.checked_add(u32::from(digit))).ok_or(CodecError::Overflow)?;
+		any = true;
+	}
+	if any { Ok(value) } else { Err(CodecError::InvalidLength) }
+}
+
+/// Decodes the symbols into a number, verifying the check symbol at the end.
+///
+/// # Errors
+/// - [`CodecError::ChecksumMismatch`]: If the check symbol isn't the number modulo 37.
+/// - See: [`decode`]
+///
+/// # Example
+/// ```
+/// use nit::codec::{crockford, CodecError};
+/// assert_eq!(crockford::decode_with_check(b"16JD"), Ok(1234));
+/// assert_eq!(crockford::decode_with_check(b"16JE"), Err(CodecError::ChecksumMismatch));
+/// assert_eq!(crockford::decode_with_check(b"16J="), Err(CodecError::ChecksumMismatch));
+/// ```
+pub fn decode_with_check(text: &[u8]) -> Result<u32, CodecError> {
+	let Some(position) = text.iter().rposition(|&symbol| symbol != SEPARATOR) else { return Err(CodecError::InvalidLength) };
+	let check = TABLE[usize::from(text[position])];
+	if check == NOT_A_SYMBOL {
+		return Err(CodecError::InvalidSymbol(position));
+	}
+	let value = decode(&text[..position])?;
+	if value % 37 == u32::from(check) { Ok(value) } else { Err(CodecError::ChecksumMismatch) }
+}
+
+/// Encodes the number as a string of the fewest digits.
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn encode_to_string(value: u32) -> String {
+	let mut text = [0; MAX_ENCODED_LEN];
+	// The buffer is long enough for any number.
+	let len = encode(value, &mut text).unwrap_or_default();
+	text[..len].iter().copied().map(char::from).collect()
+}
+
+/// Encodes the number as a string of the fewest digits followed by its check symbol.
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn encode_to_string_with_check(value: u32) -> String {
+	let mut text = [0; MAX_ENCODED_LEN];
+	// The buffer is long enough for any number.
+	let len = encode_with_check(value, &mut text).unwrap_or_default();
+	text[..len].iter().copied().map(char::from).collect()
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::missing_docs_in_private_items)]
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn vectors() {
+		for (value, text, checked) in [
+			(0, "0", "00"),
+			(1, "1", "11"),
+			(31, "Z", "ZZ"),
+			(32, "10", "10*"),
+			(36, "14", "14U"),
+			(1234, "16J", "16JD"),
+			(5_111, "4ZQ", "4ZQ5"),
+			(u32::MAX, "3ZZZZZZ", "3ZZZZZZ6"),
+		] {
+			assert_eq!(encode_to_string(value), text);
+			assert_eq!(encode_to_string_with_check(value), checked);
+			assert_eq!(decode(text.as_bytes()), Ok(value));
+			assert_eq!(decode(text.to_lowercase().as_bytes()), Ok(value));
+			assert_eq!(decode_with_check(checked.as_bytes()), Ok(value));
+		}
+	}
+
+	#[test]
+	fn errors() {
+		assert_eq!(decode(b""), Err(CodecError::InvalidLength));
+		assert_eq!(decode(b"--"), Err(CodecError::InvalidLength));
+		assert_eq!(decode(b"1*"), Err(CodecError::InvalidSymbol(1)));
+		assert_eq!(decode(b"1 "), Err(CodecError::InvalidSymbol(1)));
+		assert_eq!(decode_with_check(b"-"), Err(CodecError::InvalidLength));
+		assert_eq!(decode_with_check(b"1!"), Err(CodecError::InvalidSymbol(1)));
+		let mut text = [0; 3];
+		assert_eq!(encode_with_check(1234, &mut text), Err(CodecError::BufferTooSmall));
+	}
+}
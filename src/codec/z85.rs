@@ -0,0 +1,150 @@
+#[cfg(feature = "alloc")] use alloc::{string::String, vec, vec::Vec};
+use super::{base85_digits, base85_word, CodecError, NOT_A_SYMBOL};
+
+/// The symbols of the digits, in order from zero.
+pub const ALPHABET: &[u8; 85] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+/// The digit of each byte which is a symbol, or [`NOT_A_SYMBOL`].
+const TABLE: [u8; 256] = {
+	let mut table = [NOT_A_SYMBOL; 256];
+	let mut i = 0;
+	while i < ALPHABET.len() {
+		#[allow(clippy::cast_possible_truncation)]
+		let digit = i as u8;
+		table[ALPHABET[i] as usize] = digit;
+		i += 1;
+	}
+	table
+};
+
+/// Returns the amount of symbols that the given amount of bytes are encoded as, or [`None`] if it isn't a multiple of four.
+#[must_use]
+pub const fn encoded_len(bytes: usize) -> Option<usize> {
+	if bytes.is_multiple_of(4) { Some(bytes / 4 * 5) } else { None }
+}
+
+/// Returns the amount of bytes that the given amount of symbols are decoded as, or [`None`] if it isn't a multiple of five.
+#[must_use]
+pub const fn decoded_len(symbols: usize) -> Option<usize> {
+	if symbols.is_multiple_of(5) { Some(symbols / 5 * 4) } else { None }
+}
+
+/// Encodes the bytes into `out`, returning the amount of symbols written.
+///
+/// # Errors
+/// - [`CodecError::InvalidLength`]: If the amount of bytes isn't a multiple of four, which Z85 requires; nothing is written.
+/// - [`CodecError::BufferTooSmall`]: If `out` is shorter than the amount of symbols; nothing is written.
+///
+/// # Example
+/// ```
+/// use nit::codec::z85;
+/// let mut text = [0; 10];
+/// assert_eq!(z85::encode(&[0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B], &mut text), Ok(10));
+/// assert_eq!(&text, b"HelloWorld");
+/// ```
+pub fn encode(bytes: &[u8], out: &mut [u8]) -> Result<usize, CodecError> {
+	let len = encoded_len(bytes.len()).ok_or(CodecError::InvalidLength)?;
+	let out = out.get_mut(..len).ok_or(CodecError::BufferTooSmall)?;
+	for (word, text) in bytes.chunks_exact(4).zip(out.chunks_exact_mut(5)) {
+		let word = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+		for (slot, digit) in text.iter_mut().zip(base85_digits(word)) {
+			*slot = ALPHABET[usize::from(digit)];
+		}
+	}
+	Ok(len)
+}
+
+/// Decodes the symbols into `out`, returning the amount of bytes written.
+///
+/// # Errors
+/// - [`CodecError::InvalidLength`]: If the amount of symbols isn't a multiple of five; nothing is written.
+/// - [`CodecError::BufferTooSmall`]: If `out` is shorter than the amount of bytes; nothing is written.
+/// - [`CodecError::InvalidSymbol`]: If a symbol isn't within the alphabet.
+/// - [`CodecError::NonCanonical`]: If a group of five symbols holds a value beyond [`u32::MAX`].
+///
+/// The groups before an invalid one will have been written.
+///
+/// # Example
+/// ```
+/// use nit::codec::{z85, CodecError};
+/// let mut bytes = [0; 8];
+/// assert_eq!(z85::decode(b"HelloWorld", &mut bytes), Ok(8));
+/// assert_eq!(bytes, [0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B]);
+/// assert_eq!(z85::decode(b"%nSc0", &mut bytes), Ok(4));
+/// assert_eq!(&bytes[..4], [0xFF; 4]);
+/// // One more than `u32::MAX`.
+/// assert_eq!(z85::decode(b"%nSc1", &mut bytes), Err(CodecError::NonCanonical(0)));
+/// assert_eq!(z85::decode(b"Hello", &mut bytes[..3]), Err(CodecError::BufferTooSmall));
+/// ```
+pub fn decode(text: &[u8], out: &mut [u8]) -> Result<usize, CodecError> {
+	let len = decoded_len(text.len()).ok_or(CodecError::InvalidLength)?;
+	let out = out.get_mut(..len).ok_or(CodecError::BufferTooSmall)?;
+	for (index, (group, bytes)) in text.chunks_exact(5).zip(out.chunks_exact_mut(4)).enumerate() {
+		let start = index * 5;
+		let mut digits = [0; 5];
+		for (offset, (digit, &symbol)) in digits.iter_mut().zip(group).enumerate() {
+			*digit = TABLE[usize::from(symbol)];
+			if *digit == NOT_A_SYMBOL {
+				return Err(CodecError::InvalidSymbol(start + offset));
+			}
+		}
+		let word = base85_word(digits).ok_or(CodecError::NonCanonical(start))?;
+		bytes.copy_from_slice(&word.to_be_bytes());
+	}
+	Ok(len)
+}
+
+/// Encodes the bytes as a string.
+///
+/// # Errors
+/// - [`CodecError::InvalidLength`]: If the amount of bytes isn't a multiple of four.
+#[cfg(feature = "alloc")]
+pub fn encode_to_string(bytes: &[u8]) -> Result<String, CodecError> {
+	let mut text = vec![0; encoded_len(bytes.len()).ok_or(CodecError::InvalidLength)?];
+	encode(bytes, &mut text)?;
+	Ok(text.into_iter().map(char::from).collect())
+}
+
+/// Decodes the symbols of a string back into bytes.
+///
+/// # Errors
+/// See: [`decode`]
+#[cfg(feature = "alloc")]
+pub fn decode_to_vec(text: &str) -> Result<Vec<u8>, CodecError> {
+	let mut bytes = vec![0; decoded_len(text.len()).ok_or(CodecError::InvalidLength)?];
+	decode(text.as_bytes(), &mut bytes)?;
+	Ok(bytes)
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+	#![allow(clippy::missing_docs_in_private_items)]
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+
+	#[test]
+	fn specification_vectors() {
+		// The test case from the specification (https://rfc.zeromq.org/spec/32/).
+		let bytes = [0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B];
+		assert_eq!(encode_to_string(&bytes).unwrap(), "HelloWorld");
+		assert_eq!(decode_to_vec("HelloWorld").unwrap(), bytes);
+		// The first key pair from the CurveZMQ specification (https://rfc.zeromq.org/spec/26/).
+		let public = [
+			0xBB, 0x88, 0x47, 0x1D, 0x65, 0xE2, 0x65, 0x9B, 0x30, 0xC5, 0x5A, 0x53, 0x21, 0xCE, 0xBB, 0x5A,
+			0xAB, 0x2B, 0x70, 0xA3, 0x98, 0x64, 0x5C, 0x26, 0xDC, 0xA2, 0xB2, 0xFC, 0xB4, 0x3F, 0xC5, 0x18,
+		];
+		assert_eq!(encode_to_string(&public).unwrap(), "Yne@$w-vo<fVvi]a<NY6T1ed:M$fCG*[IaLV{hID");
+		assert_eq!(decode_to_vec("Yne@$w-vo<fVvi]a<NY6T1ed:M$fCG*[IaLV{hID").unwrap(), public);
+	}
+
+	#[test]
+	fn extremes() {
+		assert_eq!(encode_to_string(&[0; 4]).unwrap(), "00000");
+		assert_eq!(encode_to_string(&[0xFF; 4]).unwrap(), "%nSc0");
+		assert_eq!(encode_to_string(&[]).unwrap(), "");
+		assert_eq!(encode_to_string(&[0; 3]), Err(CodecError::InvalidLength));
+		assert_eq!(decode_to_vec("0000"), Err(CodecError::InvalidLength));
+		assert_eq!(decode_to_vec("0000~"), Err(CodecError::InvalidSymbol(4)));
+		assert_eq!(decode_to_vec("%nSc0%nSc1"), Err(CodecError::NonCanonical(5)));
+	}
+}
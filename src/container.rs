@@ -0,0 +1,702 @@
+#[cfg(feature = "std")] use std::io::{self, ErrorKind, Read, Write};
+use core::iter::FusedIterator;
+use crate::Nit;
+use crate::array::{NitArrayWord, WORD_BITS, words_required};
+use crate::base::Powers;
+use crate::internal_macros::{deriving_const, impl_error};
+use crate::max_nits::compute_max_nits_in_bits;
+use crate::supported::{BaseMaximum, FitsMaximumBits, FitsMaximumBitsAsType};
+#[cfg(feature = "std")] use crate::vec::NitVec;
+
+/// The bytes every container starts with.
+pub const MAGIC: [u8; 4] = *b"NITS";
+
+/// The version of the container format which is written, and the only one which can be read.
+pub const VERSION: u8 = 2;
+
+/// The amount of bytes within the header.
+pub const HEADER_LEN: usize = 23;
+
+/// The amount of bytes within the header which the checksum covers, which is all of them but the checksum itself.
+const CHECKED_HEADER_LEN: usize = 19;
+
+/// The most bytes within a word; any more, and its value may not fit within a [`FitsMaximumBitsAsType`].
+const MAXIMUM_WORD_BYTES: u8 = 16;
+
+/// The width of the words which are written, which is that of a [`NitArrayWord`].
+#[allow(clippy::cast_possible_truncation)]
+const WORD_BYTES: u8 = size_of::<NitArrayWord>() as u8;
+
+deriving_const!((PartialEq) for {
+	/// The reason a container couldn't be written or read.
+	#[derive(Debug, Clone, Copy, Eq, Hash)]
+	pub enum ContainerError {
+		/// The buffer doesn't have room for the container.
+		BufferTooSmall,
+		/// The container ends before its header or payload does, or there are fewer words than the amount of digits needs.
+		Truncated,
+		/// There are bytes after the payload.
+		TrailingData,
+		/// The container doesn't start with [`MAGIC`].
+		BadMagic,
+		/// The container is of a version other than [`VERSION`].
+		UnsupportedVersion(u8),
+		/// The width of the words is zero or more than sixteen bytes, or too narrow to hold a digit of the base.
+		UnsupportedWordWidth(u8),
+		/// The base is less than two; this holds the base of the container.
+		UnsupportedBase(BaseMaximum),
+		/// The digit order isn't that of any [`DigitOrder`].
+		UnsupportedDigitOrder(u8),
+		/// The base of the container isn't the expected one; this holds the base of the container.
+		BaseMismatch(BaseMaximum),
+		/// The checksum isn't that of the header and payload.
+		ChecksumMismatch,
+		/// The word at the index holds a value which isn't the value of any of its digits, or a place past the amount of digits which isn't zero.
+		NonCanonical(usize),
+	}
+});
+impl ContainerError {
+	/// Returns the error message as a string.
+	#[must_use]
+	#[cfg(not(tarpaulin_include))]
+	pub const fn get_str(&self) -> &str {
+		match self {
+			Self::BufferTooSmall => "The buffer doesn't have room for the container.",
+			Self::Truncated => "The container ends before its header or payload does, or there are fewer words than the amount of digits needs.",
+			Self::TrailingData => "There are bytes after the payload.",
+			Self::BadMagic => "The container doesn't start with the magic bytes.",
+			Self::UnsupportedVersion(_) => "The container is of an unsupported version.",
+			Self::UnsupportedWordWidth(_) => "The width of the words is unsupported, or too narrow to hold a digit of the base.",
+			Self::UnsupportedBase(_) => "The base is less than two.",
+			Self::UnsupportedDigitOrder(_) => "The digit order is unsupported.",
+			Self::BaseMismatch(_) => "The base of the container isn't the expected one.",
+			Self::ChecksumMismatch => "The checksum isn't that of the header and payload.",
+			Self::NonCanonical(_) => "A word holds a value which isn't the value of any of its digits, or a place past the amount of digits which isn't zero.",
+		}
+	}
+}
+#[cfg(not(tarpaulin_include))]
+impl core::fmt::Display for ContainerError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "{}", self.get_str())
+	}
+}
+impl_error!(ContainerError);
+#[cfg(feature = "std")]
+impl From<ContainerError> for io::Error {
+	fn from(err: ContainerError) -> Self {
+		let kind = if err == ContainerError::Truncated { ErrorKind::UnexpectedEof } else { ErrorKind::InvalidData };
+		Self::new(kind, err)
+	}
+}
+
+deriving_const!((PartialEq) for {
+	/// The order of the digits within each word of a container.
+	#[derive(Debug, Clone, Copy, Eq, Hash)]
+	pub enum DigitOrder {
+		/// The first digit is the least significant place of the word, as within a [`NitArray`](crate::array::NitArray).
+		LeastSignificantFirst,
+		/// The first digit is the most significant place of the word, as the digits are when written out.
+		MostSignificantFirst,
+	}
+});
+
+/// The header of a container.
+///
+/// The container is a header of [`HEADER_LEN`] bytes followed by the payload of packed words, with every integer being little-endian:
+///
+/// | Bytes    | Field |
+/// |----------|-------|
+/// | `0..4`   | [`MAGIC`] |
+/// | `4`      | [`VERSION`] |
+/// | `5`      | The width of each word, in bytes, from 1 to 16 |
+/// | `6`      | The [`DigitOrder`] within each word |
+/// | `7..11`  | The base of the digits, as a `u32` |
+/// | `11..19` | The amount of digits, as a `u64` |
+/// | `19..23` | The CRC-32 of the first nineteen bytes of the header and the payload |
+///
+/// Each word holds as many digits as [`compute_max_nits_in_bits`] allows for its width,
+/// and every place past the amount of digits within the last word is zero, so any amount of digits has exactly one container.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Header {
+	/// The base of the digits.
+	pub base: BaseMaximum,
+	/// The width of each word, in bytes.
+	pub word_bytes: u8,
+	/// The order of the digits within each word.
+	pub order: DigitOrder,
+	/// The amount of digits.
+	pub len: u64,
+	/// The CRC-32 of the first nineteen bytes of the header and the payload.
+	pub checksum: u32,
+}
+
+impl Header {
+	/// Returns the amount of digits each word holds, or [`None`] if the base or width is unsupported.
+	#[must_use]
+	pub const fn digits_per_word(&self) -> Option<FitsMaximumBits> {
+		if self.base < 2 || self.word_bytes == 0 || self.word_bytes > MAXIMUM_WORD_BYTES {
+			return None;
+		}
+		#[allow(clippy::cast_lossless)]
+		let bits = self.word_bytes as FitsMaximumBits * 8;
+		if self.base == 2 {
+			return Some(bits);
+		}
+		let max = FitsMaximumBitsAsType::MAX >> (FitsMaximumBitsAsType::BITS - bits as u32);
+		#[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+		match max.ilog(self.base as FitsMaximumBitsAsType) {
+			0 => None,
+			digits => Some(digits as FitsMaximumBits),
+		}
+	}
+
+	/// Returns the amount of bytes within the payload, or [`None`] if the base or width is unsupported, or it doesn't fit within a [`usize`].
+	#[must_use]
+	pub fn payload_len(&self) -> Option<usize> {
+		let per_word = u64::from(self.digits_per_word()?);
+		usize::try_from(self.len.div_ceil(per_word)).ok()?.checked_mul(usize::from(self.word_bytes))
+	}
+
+	/// Returns the bytes of the header.
+	#[must_use]
+	pub fn to_bytes(&self) -> [u8; HEADER_LEN] {
+		let mut bytes = [0; HEADER_LEN];
+		bytes[..4].copy_from_slice(&MAGIC);
+		bytes[4] = VERSION;
+		bytes[5] = self.word_bytes;
+		bytes[6] = self.order as u8;
+		bytes[7..11].copy_from_slice(&self.base.to_le_bytes());
+		bytes[11..19].copy_from_slice(&self.len.to_le_bytes());
+		bytes[19..23].copy_from_slice(&self.checksum.to_le_bytes());
+		bytes
+	}
+
+	/// Parses the header at the start of the bytes, validating everything but the checksum.
+	///
+	/// # Errors
+	/// - [`ContainerError::Truncated`]: If there are fewer than [`HEADER_LEN`] bytes.
+	/// - [`ContainerError::BadMagic`]: If the bytes don't start with [`MAGIC`].
+	/// - [`ContainerError::UnsupportedVersion`]: If the version isn't [`VERSION`].
+	/// - [`ContainerError::UnsupportedDigitOrder`]: If the digit order isn't that of any [`DigitOrder`].
+	/// - [`ContainerError::UnsupportedBase`]: If the base is less than two.
+	/// - [`ContainerError::UnsupportedWordWidth`]: If the words can't hold a digit of the base.
+	pub fn parse(bytes: &[u8]) -> Result<Self, ContainerError> {
+		let bytes = bytes.get(..HEADER_LEN).ok_or(ContainerError::Truncated)?;
+		if bytes[..4] != MAGIC {
+			return Err(ContainerError::BadMagic);
+		}
+		if bytes[4] != VERSION {
+			return Err(ContainerError::UnsupportedVersion(bytes[4]));
+		}
+		let order = match bytes[6] {
+			0 => DigitOrder::LeastSignificantFirst,
+			1 => DigitOrder::MostSignificantFirst,
+			order => return Err(ContainerError::UnsupportedDigitOrder(order)),
+		};
+		let mut base = [0; 4];
+		base.copy_from_slice(&bytes[7..11]);
+		let mut len = [0; 8];
+		len.copy_from_slice(&bytes[11..19]);
+		let mut checksum = [0; 4];
+		checksum.copy_from_slice(&bytes[19..23]);
+		let header = Self { base: BaseMaximum::from_le_bytes(base), word_bytes: bytes[5], order, len: u64::from_le_bytes(len), checksum: u32::from_le_bytes(checksum) };
+		if header.base < 2 {
+			return Err(ContainerError::UnsupportedBase(header.base));
+		}
+		match header.digits_per_word() {
+			Some(_) => Ok(header),
+			None => Err(ContainerError::UnsupportedWordWidth(header.word_bytes)),
+		}
+	}
+}
+
+/// The CRC-32 of each byte, with the polynomial used by zlib and PNG.
+const CRC_TABLE: [u32; 256] = {
+	let mut table = [0; 256];
+	let mut i = 0;
+	while i < 256 {
+		#[allow(clippy::cast_possible_truncation)]
+		let mut crc = i as u32;
+		let mut bit = 0;
+		while bit < 8 {
+			crc = if crc & 1 == 1 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+			bit += 1;
+		}
+		table[i] = crc;
+		i += 1;
+	}
+	table
+};
+
+/// A CRC-32 which is computed piecewise.
+#[derive(Debug, Clone, Copy)]
+struct Crc32(u32);
+
+impl Crc32 {
+	/// Starts a checksum over no bytes.
+	const fn new() -> Self {
+		Self(u32::MAX)
+	}
+
+	/// Continues the checksum over the bytes.
+	const fn update(self, bytes: &[u8]) -> Self {
+		let mut crc = self.0;
+		let mut i = 0;
+		while i < bytes.len() {
+			crc = CRC_TABLE[((crc ^ bytes[i] as u32) & 0xFF) as usize] ^ (crc >> 8);
+			i += 1;
+		}
+		Self(crc)
+	}
+
+	/// Returns the checksum of the bytes so far.
+	const fn finish(self) -> u32 {
+		!self.0
+	}
+}
+
+/// Reverses the order of the first `digits` base-`base` digits of a value.
+fn reverse_digits(mut value: FitsMaximumBitsAsType, digits: FitsMaximumBits, base: BaseMaximum) -> FitsMaximumBitsAsType {
+	let base = FitsMaximumBitsAsType::from(base);
+	let mut reversed = 0;
+	for _ in 0..digits {
+		reversed = reversed * base + value % base;
+		value /= base;
+	}
+	reversed
+}
+
+/// Returns the amount of bytes of a container of `len` base-`BASE` digits, as [`write`] writes it.
+///
+/// # Example
+/// ```
+/// use nit::container::{encoded_len, HEADER_LEN};
+/// // 40 trits fit within each word of eight bytes.
+/// assert_eq!(encoded_len::<3>(100), HEADER_LEN + 3 * 8);
+/// ```
+#[must_use]
+pub const fn encoded_len<const BASE: BaseMaximum>(len: usize) -> usize {
+	HEADER_LEN + words_required::<BASE>(len) * WORD_BYTES as usize
+}
+
+/// Returns the header of `len` digits packed within the words as a [`NitArray`](crate::array::NitArray) packs them, written in the given order.
+///
+/// The words must hold exactly the amount of digits; see [`ContainerError`] for when they don't.
+fn header_of<const BASE: BaseMaximum>(words: &[NitArrayWord], len: usize, order: DigitOrder) -> Result<(Header, Crc32), ContainerError> {
+	if BASE < 2 {
+		return Err(ContainerError::UnsupportedBase(BASE));
+	}
+	let words = words.get(..words_required::<BASE>(len)).ok_or(ContainerError::Truncated)?;
+	#[allow(clippy::cast_possible_truncation)]
+	let mut header = Header { base: BASE, word_bytes: WORD_BYTES, order, len: len as u64, checksum: 0 };
+	let per_word = header.digits_per_word().ok_or(ContainerError::UnsupportedWordWidth(WORD_BYTES))?;
+	let mut crc = Crc32::new().update(&header.to_bytes()[..CHECKED_HEADER_LEN]);
+	for (i, &word) in words.iter().enumerate() {
+		// Only the last word can be partially filled, and every place past the length must be zero.
+		let digits = (len - i * usize::from(per_word)).min(usize::from(per_word));
+		#[allow(clippy::cast_possible_truncation)]
+		if word > Powers::<NitArrayWord, BASE>::power(digits as FitsMaximumBits).wrapping_sub(1) {
+			return Err(ContainerError::NonCanonical(i));
+		}
+		crc = crc.update(&encode_word::<BASE>(word, per_word, order));
+	}
+	header.checksum = crc.finish();
+	Ok((header, crc))
+}
+
+/// Returns the bytes of a word as a [`NitArray`](crate::array::NitArray) packs it, written in the given order.
+fn encode_word<const BASE: BaseMaximum>(word: NitArrayWord, per_word: FitsMaximumBits, order: DigitOrder) -> [u8; WORD_BYTES as usize] {
+	match order {
+		DigitOrder::LeastSignificantFirst => word.to_le_bytes(),
+		#[allow(clippy::cast_possible_truncation)]
+		DigitOrder::MostSignificantFirst => (reverse_digits(FitsMaximumBitsAsType::from(word), per_word, BASE) as NitArrayWord).to_le_bytes(),
+	}
+}
+
+/// Writes a container of `len` digits into `out`, returning the amount of bytes written.
+///
+/// The digits are packed within the words as a [`NitArray`](crate::array::NitArray) or [`NitVec`](crate::vec::NitVec) packs them,
+/// and are written with the digits of each word in the given order. Only the words holding the digits are read; any words after them are ignored.
+///
+/// # Errors
+/// - [`ContainerError::UnsupportedBase`]: If `BASE` is less than two.
+/// - [`ContainerError::Truncated`]: If there are fewer words than the amount of digits needs.
+/// - [`ContainerError::NonCanonical`]: If a word holds a value beyond the value of its digits, or a place past the amount of digits which isn't zero.
+/// - [`ContainerError::BufferTooSmall`]: If `out` is shorter than [`encoded_len`].
+///
+/// Nothing is written upon an error.
+///
+/// # Example
+/// ```
+/// use nit::array::{NitArray, words_required};
+/// use nit::container::{self, ContainerReader, DigitOrder};
+/// use nit::Trit;
+///
+/// let mut trits = NitArray::<3, 50, { words_required::<3>(50) }>::new();
+/// trits.set(0, Trit::TWO).unwrap();
+/// trits.set(49, Trit::ONE).unwrap();
+///
+/// let mut bytes = [0; container::encoded_len::<3>(50)];
+/// assert_eq!(container::write::<3>(trits.as_words(), 50, DigitOrder::LeastSignificantFirst, &mut bytes), Ok(39));
+/// assert_eq!(&bytes[..11], b"NITS\x02\x08\x00\x03\x00\x00\x00");
+///
+/// let reader = ContainerReader::<3>::new(&bytes).unwrap();
+/// assert_eq!(reader.len(), 50);
+/// assert!(reader.nits().eq((0..50).map(|i| trits.get(i).unwrap())));
+/// ```
+pub fn write<const BASE: BaseMaximum>(words: &[NitArrayWord], len: usize, order: DigitOrder, out: &mut [u8]) -> Result<usize, ContainerError> {
+	let (header, _) = header_of::<BASE>(words, len, order)?;
+	let total = encoded_len::<BASE>(len);
+	let out = out.get_mut(..total).ok_or(ContainerError::BufferTooSmall)?;
+	let (head, payload) = out.split_at_mut(HEADER_LEN);
+	head.copy_from_slice(&header.to_bytes());
+	let per_word = header.digits_per_word().unwrap_or_default();
+	for (bytes, &word) in payload.chunks_exact_mut(usize::from(WORD_BYTES)).zip(words) {
+		bytes.copy_from_slice(&encode_word::<BASE>(word, per_word, order));
+	}
+	Ok(total)
+}
+
+/// Writes `len` digits packed within the words as [`write`] does, to a stream.
+///
+/// # Errors
+/// - See: [`write`], as the inner error of an [`io::Error`]; nothing is written upon one of these errors.
+/// - Any error from writing to the stream.
+#[cfg(feature = "std")]
+pub fn write_to<W: Write, const BASE: BaseMaximum>(mut writer: W, words: &[NitArrayWord], len: usize, order: DigitOrder) -> io::Result<()> {
+	let (header, _) = header_of::<BASE>(words, len, order)?;
+	writer.write_all(&header.to_bytes())?;
+	let per_word = header.digits_per_word().unwrap_or_default();
+	for &word in &words[..words_required::<BASE>(len)] {
+		writer.write_all(&encode_word::<BASE>(word, per_word, order))?;
+	}
+	writer.flush()
+}
+
+/// Reads a container of base-`BASE` digits from a stream, into a [`NitVec`].
+///
+/// The payload is read into memory and validated before any digit is returned.
+/// The digits of bases above 256 don't fit within the nits of the [`NitVec`], and are read through a [`ContainerReader`] of wider nits instead.
+///
+/// # Errors
+/// - See: [`ContainerReader::new`], as the inner error of an [`io::Error`].
+/// - Any error from reading the stream.
+#[cfg(feature = "std")]
+pub fn read_from<R: Read, const BASE: BaseMaximum>(reader: R) -> io::Result<NitVec<BASE>> {
+	let mut reader = reader;
+	let mut bytes = std::vec![0; HEADER_LEN];
+	reader.read_exact(&mut bytes).map_err(|err| if err.kind() == ErrorKind::UnexpectedEof { ContainerError::Truncated.into() } else { err })?;
+	let header = Header::parse(&bytes)?;
+	if header.base != BASE {
+		return Err(ContainerError::BaseMismatch(header.base).into());
+	}
+	let payload_len = header.payload_len().ok_or(ContainerError::Truncated)?;
+	// The payload is read piecewise, rather than allocating whatever length the header claims up front.
+	let read = reader.take(payload_len as u64).read_to_end(&mut bytes)?;
+	if read != payload_len {
+		return Err(ContainerError::Truncated.into());
+	}
+	Ok(ContainerReader::<BASE>::new(&bytes)?.nits().collect())
+}
+
+/// A validated container of base-`BASE` digits, borrowed from a buffer of bytes.
+///
+/// The digits are read as [`Nit<BASE, BYTES>`](Nit)s, so bases above 256 need a wider `BYTES`.
+///
+/// # Example
+/// ```
+/// use nit::container::{self, ContainerReader, DigitOrder};
+///
+/// // Six base-1000 digits fit within each word.
+/// let words = [7 + 999 * 1000 + 512 * 1000 * 1000];
+/// let mut bytes = [0; container::encoded_len::<1000>(3)];
+/// container::write::<1000>(&words, 3, DigitOrder::LeastSignificantFirst, &mut bytes).unwrap();
+/// let reader = ContainerReader::<1000, 2>::new(&bytes).unwrap();
+/// assert_eq!(reader.header().base, 1000);
+/// assert!(reader.nits().map(|nit| nit.get_value()).eq([7, 999, 512]));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ContainerReader<'a, const BASE: BaseMaximum, const BYTES: usize = 1> {
+	/// The header of the container.
+	header: Header,
+	/// The amount of digits.
+	len: usize,
+	/// The payload of the container.
+	payload: &'a [u8],
+}
+
+impl<'a, const BASE: BaseMaximum, const BYTES: usize> ContainerReader<'a, BASE, BYTES> {
+	/// Validates the container of base-`BASE` digits which the bytes hold.
+	///
+	/// # Errors
+	/// - See: [`Header::parse`]
+	/// - [`ContainerError::BaseMismatch`]: If the base of the container isn't `BASE`.
+	/// - [`ContainerError::Truncated`]: If the bytes end before the payload does.
+	/// - [`ContainerError::TrailingData`]: If there are bytes after the payload.
+	/// - [`ContainerError::ChecksumMismatch`]: If the checksum isn't that of the header and payload.
+	/// - [`ContainerError::NonCanonical`]: If a word holds a value beyond the value of its digits, or a place past the amount of digits which isn't zero.
+	///
+	/// # Example
+	/// ```
+	/// use nit::container::{self, ContainerError, ContainerReader, DigitOrder};
+	/// let mut bytes = [0; container::encoded_len::<3>(5)];
+	/// container::write::<3>(&[1 + 2 * 81], 5, DigitOrder::MostSignificantFirst, &mut bytes).unwrap();
+	/// assert_eq!(ContainerReader::<5>::new(&bytes).unwrap_err(), ContainerError::BaseMismatch(3));
+	/// assert_eq!(ContainerReader::<3>::new(&bytes[..27]).unwrap_err(), ContainerError::Truncated);
+	/// bytes[23] ^= 1;
+	/// assert_eq!(ContainerReader::<3>::new(&bytes).unwrap_err(), ContainerError::ChecksumMismatch);
+	/// ```
+	pub fn new(bytes: &'a [u8]) -> Result<Self, ContainerError> {
+		let header = Header::parse(bytes)?;
+		if header.base != BASE {
+			return Err(ContainerError::BaseMismatch(header.base));
+		}
+		let payload_len = header.payload_len().ok_or(ContainerError::Truncated)?;
+		let len = usize::try_from(header.len).map_err(|_| ContainerError::Truncated)?;
+		let payload = &bytes[HEADER_LEN..];
+		match payload.len().cmp(&payload_len) {
+			core::cmp::Ordering::Less => return Err(ContainerError::Truncated),
+			core::cmp::Ordering::Greater => return Err(ContainerError::TrailingData),
+			core::cmp::Ordering::Equal => {},
+		}
+		if Crc32::new().update(&bytes[..CHECKED_HEADER_LEN]).update(payload).finish() != header.checksum {
+			return Err(ContainerError::ChecksumMismatch);
+		}
+		let reader = Self { header, len, payload };
+		let per_word = usize::from(header.digits_per_word().unwrap_or_default());
+		for i in 0..payload_len / usize::from(header.word_bytes) {
+			let digits = (len - i * per_word).min(per_word);
+			#[allow(clippy::cast_possible_truncation)]
+			let limit = FitsMaximumBitsAsType::from(BASE).checked_pow(digits as u32);
+			if reader.word(i).is_none_or(|word| limit.is_some_and(|limit| word >= limit)) {
+				return Err(ContainerError::NonCanonical(i));
+			}
+		}
+		Ok(reader)
+	}
+
+	/// Returns the header of the container.
+	#[must_use]
+	pub const fn header(&self) -> &Header {
+		&self.header
+	}
+
+	/// Returns the amount of digits within the container.
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Returns whether the container holds no digits.
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Returns the value of the `i`th word with its digits in least significant first order,
+	/// or [`None`] if it's in most significant first order and holds a value beyond the value of its digits.
+	fn word(&self, i: usize) -> Option<FitsMaximumBitsAsType> {
+		let width = usize::from(self.header.word_bytes);
+		let mut bytes = [0; MAXIMUM_WORD_BYTES as usize];
+		bytes[..width].copy_from_slice(&self.payload[i * width..(i + 1) * width]);
+		let word = FitsMaximumBitsAsType::from_le_bytes(bytes);
+		let per_word = self.header.digits_per_word().unwrap_or_default();
+		match self.header.order {
+			DigitOrder::LeastSignificantFirst => Some(word),
+			DigitOrder::MostSignificantFirst => {
+				let limit = FitsMaximumBitsAsType::from(BASE).checked_pow(u32::from(per_word));
+				if limit.is_some_and(|limit| word >= limit) { None } else { Some(reverse_digits(word, per_word, BASE)) }
+			},
+		}
+	}
+
+	/// Returns an iterator over the digits within the container, in order.
+	#[must_use]
+	pub const fn nits(&self) -> ContainerNits<'a, BASE, BYTES> {
+		ContainerNits { reader: *self, index: 0, word: 0 }
+	}
+
+	/// Packs the digits into the words as a [`NitArray`](crate::array::NitArray) packs them, returning the amount of words written.
+	///
+	/// # Errors
+	/// - [`ContainerError::UnsupportedBase`]: If `BASE` is less than two, which no reader can be created for.
+	/// - [`ContainerError::BufferTooSmall`]: If there are fewer words than the amount of digits needs; nothing is written.
+	pub fn read_words(&self, out: &mut [NitArrayWord]) -> Result<usize, ContainerError> {
+		let per_word = compute_max_nits_in_bits::<BASE, WORD_BITS>().map_err(|_| ContainerError::UnsupportedBase(BASE))?;
+		let per_word = usize::from(per_word);
+		let words = words_required::<BASE>(self.len);
+		let out = out.get_mut(..words).ok_or(ContainerError::BufferTooSmall)?;
+		out.fill(0);
+		for (i, nit) in self.nits().enumerate() {
+			out[i / per_word] += NitArrayWord::from(nit.get_value()) * Powers::<NitArrayWord, BASE>::TABLE[i % per_word];
+		}
+		Ok(words)
+	}
+}
+
+/// An iterator over the digits within a container; see [`ContainerReader::nits`].
+#[derive(Debug, Clone)]
+pub struct ContainerNits<'a, const BASE: BaseMaximum, const BYTES: usize = 1> {
+	/// The container being read.
+	reader: ContainerReader<'a, BASE, BYTES>,
+	/// The index of the next digit.
+	index: usize,
+	/// The value of the word being read, divided by `BASE` for each digit yielded from it.
+	word: FitsMaximumBitsAsType,
+}
+
+impl<const BASE: BaseMaximum, const BYTES: usize> Iterator for ContainerNits<'_, BASE, BYTES> {
+	type Item = Nit<BASE, BYTES>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.index >= self.reader.len {
+			return None;
+		}
+		let per_word = usize::from(self.reader.header.digits_per_word().unwrap_or_default());
+		if self.index.is_multiple_of(per_word) {
+			// The words were validated when the reader was created.
+			self.word = self.reader.word(self.index / per_word).unwrap_or_default();
+		}
+		let base = FitsMaximumBitsAsType::from(BASE);
+		let digit = self.word % base;
+		self.word /= base;
+		self.index += 1;
+		// SAFETY: The value will be always within the range of `0..BASE` because it is the remainder of a division by `BASE`.
+		unsafe { #[allow(clippy::cast_possible_truncation, clippy::cast_lossless)] let digit = digit as BaseMaximum; Some(Nit::new_unchecked(digit)) }
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let remaining = self.reader.len - self.index;
+		(remaining, Some(remaining))
+	}
+}
+
+impl<const BASE: BaseMaximum, const BYTES: usize> ExactSizeIterator for ContainerNits<'_, BASE, BYTES> {}
+impl<const BASE: BaseMaximum, const BYTES: usize> FusedIterator for ContainerNits<'_, BASE, BYTES> {}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::missing_docs_in_private_items)]
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+	#[cfg(feature = "alloc")] use crate::vec::NitVec;
+
+	/// Recomputes the checksum of a container which was tampered with.
+	fn reseal(bytes: &mut [u8]) {
+		let checksum = Crc32::new().update(&bytes[..CHECKED_HEADER_LEN]).update(&bytes[HEADER_LEN..]).finish();
+		bytes[CHECKED_HEADER_LEN..HEADER_LEN].copy_from_slice(&checksum.to_le_bytes());
+	}
+
+	#[cfg(feature = "alloc")]
+	fn check_round_trips<const BASE: BaseMaximum, const BYTES: usize>() {
+		let mut state = 0x1234_5678_u32;
+		let nits: NitVec<BASE, BYTES> = (0..500).map(|_| {
+			state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+			Nit::new((state >> 8) % BASE).unwrap()
+		}).collect();
+		for len in [0, 1, 39, 40, 41, 499] {
+			let mut prefix = nits.clone();
+			prefix.truncate(len);
+			for order in [DigitOrder::LeastSignificantFirst, DigitOrder::MostSignificantFirst] {
+				let mut bytes = vec![0; encoded_len::<BASE>(len)];
+				assert_eq!(write::<BASE>(prefix.as_words(), len, order, &mut bytes), Ok(bytes.len()));
+				let reader = ContainerReader::<BASE, BYTES>::new(&bytes).unwrap();
+				assert_eq!(reader.nits().collect::<NitVec<BASE, BYTES>>(), prefix, "{len} digits in base {BASE}, {order:?}");
+				let mut words = vec![NitArrayWord::MAX; words_required::<BASE>(len)];
+				assert_eq!(reader.read_words(&mut words), Ok(words.len()));
+				assert_eq!(words, prefix.as_words());
+
+				#[cfg(feature = "std")]
+				{
+					let mut stream = Vec::new();
+					write_to::<_, BASE>(&mut stream, prefix.as_words(), len, order).unwrap();
+					assert_eq!(stream, bytes);
+				}
+			}
+		}
+	}
+
+	#[test]
+	#[cfg(feature = "alloc")]
+	fn round_trips() {
+		assert_eq!(Crc32::new().update(b"123456789").finish(), 0xCBF4_3926);
+		check_round_trips::<2, 1>();
+		check_round_trips::<3, 1>();
+		check_round_trips::<10, 1>();
+		check_round_trips::<85, 1>();
+		check_round_trips::<256, 1>();
+		check_round_trips::<1000, 2>();
+		check_round_trips::<60000, 2>();
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn streams() {
+		let trits: NitVec<3> = (0..100).map(|i| Nit::new(i % 3).unwrap()).collect();
+		let mut stream = Vec::new();
+		write_to::<_, 3>(&mut stream, trits.as_words(), trits.len(), DigitOrder::MostSignificantFirst).unwrap();
+		assert_eq!(read_from::<_, 3>(stream.as_slice()).unwrap(), trits);
+	}
+
+	#[test]
+	fn foreign_layouts() {
+		// Five trits within each byte, written most significant first: `21020` and `1` padded to `10000`.
+		let header = Header { base: 3, word_bytes: 1, order: DigitOrder::MostSignificantFirst, len: 6, checksum: 0 };
+		let mut bytes = header.to_bytes().to_vec();
+		bytes.extend([2 * 81 + 27 + 2 * 3, 81]);
+		reseal(&mut bytes);
+		let reader = ContainerReader::<3>::new(&bytes).unwrap();
+		assert_eq!(reader.nits().map(|nit| nit.get_value()).collect::<Vec<_>>(), [2, 1, 0, 2, 0, 1]);
+		// The padding of the last word must be zero.
+		bytes[HEADER_LEN + 1] = 82;
+		reseal(&mut bytes);
+		assert_eq!(ContainerReader::<3>::new(&bytes).unwrap_err(), ContainerError::NonCanonical(1));
+		// 243 isn't the value of any five trits.
+		bytes[HEADER_LEN] = 243;
+		reseal(&mut bytes);
+		assert_eq!(ContainerReader::<3>::new(&bytes).unwrap_err(), ContainerError::NonCanonical(0));
+
+		let header = Header { base: 2, word_bytes: 16, order: DigitOrder::LeastSignificantFirst, len: 128, checksum: 0 };
+		let mut bytes = header.to_bytes().to_vec();
+		bytes.extend([0xFF; 16]);
+		reseal(&mut bytes);
+		assert!(ContainerReader::<2>::new(&bytes).unwrap().nits().all(|nit| nit.get_value() == 1));
+	}
+
+	#[test]
+	fn errors() {
+		let mut bytes = [0; encoded_len::<3>(41)];
+		assert_eq!(write::<3>(&[0], 41, DigitOrder::LeastSignificantFirst, &mut bytes), Err(ContainerError::Truncated));
+		assert_eq!(write::<3>(&[0, 3], 41, DigitOrder::LeastSignificantFirst, &mut bytes), Err(ContainerError::NonCanonical(1)));
+		assert_eq!(write::<3>(&[0, 2], 41, DigitOrder::LeastSignificantFirst, &mut bytes[..35]), Err(ContainerError::BufferTooSmall));
+		assert_eq!(write::<1>(&[], 0, DigitOrder::LeastSignificantFirst, &mut bytes), Err(ContainerError::UnsupportedBase(1)));
+		write::<3>(&[0, 2], 41, DigitOrder::LeastSignificantFirst, &mut bytes).unwrap();
+		assert!(ContainerReader::<3>::new(&bytes).is_ok());
+		assert_eq!(ContainerReader::<3>::new(&bytes[..19]).unwrap_err(), ContainerError::Truncated);
+		let mut trailing = bytes.to_vec();
+		trailing.push(0);
+		assert_eq!(ContainerReader::<3>::new(&trailing).unwrap_err(), ContainerError::TrailingData);
+		#[cfg(feature = "std")]
+		{
+			assert_eq!(read_from::<_, 5>(bytes.as_slice()).unwrap_err().kind(), ErrorKind::InvalidData);
+			assert_eq!(read_from::<_, 3>(&bytes[..30]).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+			assert_eq!(read_from::<_, 3>(&bytes[..10]).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+		}
+
+		for (offset, value, err) in [
+			(0, b'X', ContainerError::BadMagic),
+			(4, 1, ContainerError::UnsupportedVersion(1)),
+			(5, 0, ContainerError::UnsupportedWordWidth(0)),
+			(5, 17, ContainerError::UnsupportedWordWidth(17)),
+			(6, 2, ContainerError::UnsupportedDigitOrder(2)),
+			// The base is the `u32` at `7..11`, which is three.
+			(7, 0, ContainerError::UnsupportedBase(0)),
+			(7, 1, ContainerError::UnsupportedBase(1)),
+		] {
+			let mut bytes = bytes;
+			bytes[offset] = value;
+			assert_eq!(ContainerReader::<3>::new(&bytes).unwrap_err(), err);
+		}
+	}
+}
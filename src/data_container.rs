@@ -1,16 +1,44 @@
 use crate::Nit;
+use crate::iter::NitIter;
 use crate::internal_macros::{const_impl, define_const_trait};
+use crate::max_nits::compute_max_nits_in_bits;
 use crate::places::{PlacesIndex, PlacesIndexCreationError};
-use crate::supported::{BaseMaximum, FitsMaximumBits};
+use crate::repr::NitRepr;
+use crate::supported::{BaseMaximum, FitsMaximumBits, BASE_MAXIMUM_BYTES};
 #[cfg(all(test, not(tarpaulin), not(debug_assertions)))] use no_panic::no_panic;
 
 define_const_trait!{
 	/// A value that contains numeric data which can be extracted as nits.
+	///
+	/// This is implemented for every primitive integer type, and for [`WideUint`](crate::wide::WideUint)s of up to sixteen limbs.
+	/// The nits of a signed integer are those of its two's complement bit pattern, so a negative value has the same nits as the unsigned value with the same bits;
+	/// see [`BalancedNitDataContainer`](crate::balanced::BalancedNitDataContainer) for digits which keep the sign.
+	///
+	/// # Example
+	/// ```
+	/// use nit::prelude::*;
+	/// let mut value: i8 = -1;
+	/// assert_eq!(value.get_nit::<3, 1>(4), 255_u8.get_nit::<3, 1>(4));
+	/// assert_eq!(value.iter_nits::<3, 1>().rev().collect::<Vec<_>>(), 255_u8.iter_nits::<3, 1>().rev().collect::<Vec<_>>());
+	/// assert_eq!(value.iter_nits::<2, 1>().collect::<i8>(), -1);
+	/// assert_eq!(value.set_nit(0, Nit::<2>::new(0).unwrap()), Ok(Nit::new(1).unwrap()));
+	/// assert_eq!(value, -2);
+	/// ```
 	pub NitDataContainer<const TYPE_BIT_WIDTH: FitsMaximumBits> {
 		/// Returns the base-`BASE` digit at the `n`th place, falling in the range of `0..BASE`.
 		/// Takes in a compile-time-checked [`PlacesIndex`].
+		///
+		/// The digit is a [`Nit`] stored within `BYTES` bytes, as every method taking or returning digits is; the digits of bases above 256 need more than one.
+		///
+		/// # Example
+		/// ```
+		/// use nit::prelude::*;
+		/// let value: u64 = 998_001_512_000_999_007;
+		/// let index = PlacesIndex::<64, 1000>::new(3).unwrap();
+		/// assert_eq!(value.get_nit_indexed::<1000, 2>(index), Nit::new(512).unwrap());
+		/// ```
 		#[must_use]
-		fn get_nit_indexed<const BASE: BaseMaximum>(&self, n: PlacesIndex<TYPE_BIT_WIDTH, BASE>) -> Nit<BASE>;
+		fn get_nit_indexed<const BASE: BaseMaximum, const BYTES: usize>(&self, n: PlacesIndex<TYPE_BIT_WIDTH, BASE>) -> Nit<BASE, BYTES>;
 
 		/// Returns the base-`BASE` digit at the `n`th place, falling in the range of `0..BASE`.
 		///
@@ -20,26 +48,35 @@ define_const_trait!{
 		///
 		/// ```
 		/// use nit::prelude::*;
+		/// use nit::Bit;
 		/// let value: u8 = 0;
 		///
-		/// // Panics in debug builds!
-		/// #[cfg(debug_assertions)]
-		/// assert!(std::panic::catch_unwind(|| unsafe { value.get_nit_unchecked::<2>(8) }).is_err());
-		///
-		/// // Undefined behavior in release builds!
-		/// #[cfg(not(debug_assertions))]
-		/// assert_eq!(unsafe { value.get_nit_unchecked::<2>(8) }, Bit::ZERO);
+		/// // Panics in debug builds of the crate, and is an unspecified digit in release builds!
+		/// match std::panic::catch_unwind(|| unsafe { value.get_nit_unchecked::<2, 1>(8) }) {
+		///     Ok(nit) => assert_eq!(nit, Bit::ZERO),
+		///     Err(_) => assert!(cfg!(debug_assertions)),
+		/// }
 		/// ```
 		#[must_use]
-		unsafe fn get_nit_unchecked<const BASE: BaseMaximum>(&self, n: FitsMaximumBits) -> Nit<BASE> {
+		unsafe fn get_nit_unchecked<const BASE: BaseMaximum, const BYTES: usize>(&self, n: FitsMaximumBits) -> Nit<BASE, BYTES> {
 			// SAFETY: The caller is responsible for ensuring that the index is within the valid range.
 			let index = unsafe { PlacesIndex::<TYPE_BIT_WIDTH, BASE>::new_unchecked(n) };
 			self.get_nit_indexed(index)
 		}
 
 		/// Returns the base-`BASE` digit at the `n`th place, falling in the range of `0..BASE`.
+		///
+		/// # Example
+		/// ```
+		/// use nit::prelude::*;
+		/// let value: u64 = 998_001_512_000_999_007;
+		/// assert_eq!(value.get_nit::<1000, 2>(3), Some(Nit::new(512).unwrap()));
+		/// assert_eq!(value.get_nit::<1000, 2>(6), None);
+		/// assert_eq!(value.get_nit::<10, 1>(0), Some(Nit::new(7).unwrap()));
+		/// ```
 		#[must_use]
-		fn get_nit<const BASE: BaseMaximum>(&self, n: FitsMaximumBits) -> Option<Nit<BASE>> {
+		#[allow(clippy::option_if_let_else)] // `Option::map_or` isn't usable in a `const` context.
+		fn get_nit<const BASE: BaseMaximum, const BYTES: usize>(&self, n: FitsMaximumBits) -> Option<Nit<BASE, BYTES>> {
 			match PlacesIndex::<TYPE_BIT_WIDTH, BASE>::new(n) {
 				Ok(v) => Some(self.get_nit_indexed(v)),
 				Err(_) => None
@@ -49,14 +86,14 @@ define_const_trait!{
 		/// Sets the base-`BASE` digit at the `n`th place, falling in the range of `0..BASE`.
 		/// Returns the previous value at that place.
 		/// Takes in a compile-time-checked [`PlacesIndex`].
-		fn set_nit_indexed<const BASE: BaseMaximum>(&mut self, n: PlacesIndex<TYPE_BIT_WIDTH, BASE>, value: Nit<BASE>) -> Nit<BASE>;
+		fn set_nit_indexed<const BASE: BaseMaximum, const BYTES: usize>(&mut self, n: PlacesIndex<TYPE_BIT_WIDTH, BASE>, value: Nit<BASE, BYTES>) -> Nit<BASE, BYTES>;
 		/// Sets the base-`BASE` digit at the `n`th place, falling in the range of `0..BASE`.
 		/// Returns the previous value at that place.
 		///
 		/// # Safety
 		/// It is up to the caller to ensure that the `n`th place is within the valid range for the given base.
 		/// See [`PlacesIndex`] for the valid range.
-		unsafe fn set_nit_unchecked<const BASE: BaseMaximum>(&mut self, n: FitsMaximumBits, value: Nit<BASE>) -> Nit<BASE> {
+		unsafe fn set_nit_unchecked<const BASE: BaseMaximum, const BYTES: usize>(&mut self, n: FitsMaximumBits, value: Nit<BASE, BYTES>) -> Nit<BASE, BYTES> {
 			// SAFETY: The caller is responsible for ensuring that the index is within the valid range.
 			let index = unsafe { PlacesIndex::<TYPE_BIT_WIDTH, BASE>::new_unchecked(n) };
 			self.set_nit_indexed(index, value)
@@ -79,13 +116,311 @@ define_const_trait!{
 		/// assert_eq!(value.set_nit(6, Bit::ONE), Ok(Bit::ZERO));
 		/// assert_eq!(value.set_nit(7, Bit::ONE), Ok(Bit::ZERO));
 		/// assert_eq!(value.set_nit(8, Bit::ONE), Err(PlacesIndexCreationError::OutOfBounds));
+		///
+		/// let mut value: u32 = 0;
+		/// assert_eq!(value.set_nit(2, Nit::<1000, 2>::new(42).unwrap()), Ok(Nit::new(0).unwrap()));
+		/// assert_eq!(value, 42_000_000);
 		/// ```
-		fn set_nit<const BASE: BaseMaximum>(&mut self, n: FitsMaximumBits, value: Nit<BASE>) -> Result<Nit<BASE>, PlacesIndexCreationError> {
+		fn set_nit<const BASE: BaseMaximum, const BYTES: usize>(&mut self, n: FitsMaximumBits, value: Nit<BASE, BYTES>) -> Result<Nit<BASE, BYTES>, PlacesIndexCreationError> {
 			match PlacesIndex::<TYPE_BIT_WIDTH, BASE>::new(n) {
 				Ok(v) => Ok(self.set_nit_indexed(v, value)),
 				Err(e) => Err(e)
 			}
 		}
+
+		/// Returns every base-`BASE` digit, starting from the least significant digit (right-hand side).
+		///
+		/// `CAP` must be the nit capacity of the type for the base (see [`compute_max_nits_in_bits`]), which is checked at compile-time;
+		/// it can usually be inferred from the type of the result.
+		///
+		/// # Example
+		/// ```
+		/// use nit::prelude::*;
+		/// use nit::Trit;
+		/// let value: u16 = 2 + 1 * 3 + 2 * 3_u16.pow(9);
+		/// let trits: [Trit; 10] = value.to_nits();
+		/// assert_eq!(trits[0], Trit::TWO);
+		/// assert_eq!(trits[1], Trit::ONE);
+		/// assert_eq!(trits[9], Trit::TWO);
+		/// assert_eq!(u16::from_nits(&trits), Ok(value));
+		///
+		/// let thousands: [Nit<1000, 2>; 6] = 998_001_512_000_999_007_u64.to_nits();
+		/// assert_eq!(thousands[3], Nit::new(512).unwrap());
+		/// assert_eq!(u64::from_nits(&thousands), Ok(998_001_512_000_999_007));
+		/// ```
+		#[must_use]
+		fn to_nits<const BASE: BaseMaximum, const BYTES: usize, const CAP: usize>(&self) -> [Nit<BASE, BYTES>; CAP];
+
+		/// Creates a value from base-`BASE` digits, starting from the least significant digit (right-hand side).
+		/// Places beyond the given digits are zero.
+		///
+		/// # Errors
+		/// - [`PlacesIndexCreationError::OutOfBounds`]: If there are more digits than the nit capacity of the type for the base.
+		/// - [`PlacesIndexCreationError::BadNitLimitEvaluation`]: If the base or bit width is erroneous.
+		///
+		/// # Example
+		/// ```
+		/// use nit::prelude::*;
+		/// use nit::Trit;
+		/// assert_eq!(u8::from_nits(&[Trit::ONE, Trit::TWO]), Ok(1 + 2 * 3));
+		/// assert_eq!(u8::from_nits(&[Trit::TWO; 5]), Ok(242));
+		/// assert_eq!(u8::from_nits(&[Trit::TWO; 6]), Err(PlacesIndexCreationError::OutOfBounds));
+		/// ```
+		fn from_nits<const BASE: BaseMaximum, const BYTES: usize>(nits: &[Nit<BASE, BYTES>]) -> Result<Self, PlacesIndexCreationError> where Self: Sized;
+
+		/// Shifts every base-`BASE` digit `k` places towards the most significant end, filling the vacated places with zero.
+		/// Returns the digits which fell off the top, as a value of their own.
+		///
+		/// Only the places within the nit capacity of the type for the base are shifted; anything beyond it is discarded, as is everything if the base is erroneous for the type.
+		///
+		/// # Example
+		/// ```
+		/// use nit::prelude::*;
+		/// let mut value: u16 = 1234;
+		/// assert_eq!(value.shl_nits::<10>(1), 1);
+		/// assert_eq!(value, 2340);
+		/// assert_eq!(value.shl_nits::<10>(2), 23);
+		/// assert_eq!(value, 4000);
+		/// ```
+		#[allow(clippy::return_self_not_must_use)] // The dropped digits are often not needed.
+		#[allow(clippy::option_if_let_else, clippy::manual_unwrap_or, clippy::manual_unwrap_or_default)] // `Result::map_or` and `Result::unwrap_or` aren't usable in a `const` context.
+		fn shl_nits<const BASE: BaseMaximum>(&mut self, k: FitsMaximumBits) -> Self where Self: Sized + Copy {
+			let capacity = match compute_max_nits_in_bits::<BASE, TYPE_BIT_WIDTH>() { Ok(capacity) => capacity, Err(_) => 0 };
+			let k = if k < capacity { k } else { capacity };
+			// Every valid bit width holds binary digits, so this is only left as-is for an erroneous bit width, which has no places to move anyways.
+			let zero = match Self::from_nits::<2, 1>(&[]) { Ok(zero) => zero, Err(_) => *self };
+			let value = *self;
+			let mut dropped = zero;
+			*self = zero;
+			let mut place = 0;
+			while place < capacity {
+				// SAFETY: Every place below the capacity is valid, as is every place it's moved to.
+				unsafe {
+					let nit = value.get_nit_indexed::<BASE, BASE_MAXIMUM_BYTES>(PlacesIndex::<TYPE_BIT_WIDTH, BASE>::new_unchecked(place));
+					if place < capacity - k {
+						self.set_nit_indexed(PlacesIndex::new_unchecked(place + k), nit);
+					} else {
+						dropped.set_nit_indexed(PlacesIndex::new_unchecked(place - (capacity - k)), nit);
+					}
+				}
+				place += 1;
+			}
+			dropped
+		}
+
+		/// Shifts every base-`BASE` digit `k` places towards the least significant end, filling the vacated places with zero.
+		/// Returns the digits which fell off the bottom, as a value of their own.
+		///
+		/// Only the places within the nit capacity of the type for the base are shifted; anything beyond it is discarded, as is everything if the base is erroneous for the type.
+		///
+		/// # Example
+		/// ```
+		/// use nit::prelude::*;
+		/// let mut value: u16 = 1234;
+		/// assert_eq!(value.shr_nits::<10>(2), 34);
+		/// assert_eq!(value, 12);
+		/// // Only four decimal places fit within a `u16`, so the `6` of 65535 is beyond the capacity.
+		/// let mut value: u16 = 65535;
+		/// assert_eq!(value.shr_nits::<10>(1), 5);
+		/// assert_eq!(value, 553);
+		/// ```
+		#[allow(clippy::return_self_not_must_use)] // The dropped digits are often not needed.
+		#[allow(clippy::option_if_let_else, clippy::manual_unwrap_or, clippy::manual_unwrap_or_default)] // `Result::map_or` and `Result::unwrap_or` aren't usable in a `const` context.
+		fn shr_nits<const BASE: BaseMaximum>(&mut self, k: FitsMaximumBits) -> Self where Self: Sized + Copy {
+			let capacity = match compute_max_nits_in_bits::<BASE, TYPE_BIT_WIDTH>() { Ok(capacity) => capacity, Err(_) => 0 };
+			let k = if k < capacity { k } else { capacity };
+			// Every valid bit width holds binary digits, so this is only left as-is for an erroneous bit width, which has no places to move anyways.
+			let zero = match Self::from_nits::<2, 1>(&[]) { Ok(zero) => zero, Err(_) => *self };
+			let value = *self;
+			let mut dropped = zero;
+			*self = zero;
+			let mut place = 0;
+			while place < capacity {
+				// SAFETY: Every place below the capacity is valid, as is every place it's moved to.
+				unsafe {
+					let nit = value.get_nit_indexed::<BASE, BASE_MAXIMUM_BYTES>(PlacesIndex::<TYPE_BIT_WIDTH, BASE>::new_unchecked(place));
+					if place < k {
+						dropped.set_nit_indexed(PlacesIndex::new_unchecked(place), nit);
+					} else {
+						self.set_nit_indexed(PlacesIndex::new_unchecked(place - k), nit);
+					}
+				}
+				place += 1;
+			}
+			dropped
+		}
+
+		/// Rotates every base-`BASE` digit `k` places towards the most significant end, wrapping the digits which pass the top around to the bottom.
+		///
+		/// Only the places within the nit capacity of the type for the base are rotated; anything beyond it is discarded, as is everything if the base is erroneous for the type.
+		///
+		/// # Example
+		/// ```
+		/// use nit::prelude::*;
+		/// let mut value: u16 = 1234;
+		/// value.rotate_nits_left::<10>(1);
+		/// assert_eq!(value, 2341);
+		/// value.rotate_nits_left::<10>(6);
+		/// assert_eq!(value, 4123);
+		/// ```
+		#[allow(clippy::option_if_let_else, clippy::manual_unwrap_or, clippy::manual_unwrap_or_default)] // `Result::map_or` and `Result::unwrap_or` aren't usable in a `const` context.
+		fn rotate_nits_left<const BASE: BaseMaximum>(&mut self, k: FitsMaximumBits) where Self: Sized + Copy {
+			let capacity = match compute_max_nits_in_bits::<BASE, TYPE_BIT_WIDTH>() { Ok(capacity) => capacity, Err(_) => 0 };
+			let k = if capacity == 0 { 0 } else { k % capacity };
+			let dropped = self.shl_nits::<BASE>(k);
+			// The digits which fell off the top are moved into the places vacated at the bottom.
+			let mut place = 0;
+			while place < k {
+				// SAFETY: The rotation is less than the capacity, so every place below it is valid.
+				unsafe {
+					let index = PlacesIndex::<TYPE_BIT_WIDTH, BASE>::new_unchecked(place);
+					self.set_nit_indexed(index, dropped.get_nit_indexed::<BASE, BASE_MAXIMUM_BYTES>(index));
+				}
+				place += 1;
+			}
+		}
+
+		/// Rotates every base-`BASE` digit `k` places towards the least significant end, wrapping the digits which pass the bottom around to the top.
+		///
+		/// Only the places within the nit capacity of the type for the base are rotated; anything beyond it is discarded, as is everything if the base is erroneous for the type.
+		///
+		/// # Example
+		/// ```
+		/// use nit::prelude::*;
+		/// let mut value: u16 = 1234;
+		/// value.rotate_nits_right::<10>(1);
+		/// assert_eq!(value, 4123);
+		/// ```
+		#[allow(clippy::option_if_let_else, clippy::manual_unwrap_or, clippy::manual_unwrap_or_default)] // `Result::map_or` and `Result::unwrap_or` aren't usable in a `const` context.
+		fn rotate_nits_right<const BASE: BaseMaximum>(&mut self, k: FitsMaximumBits) where Self: Sized + Copy {
+			let capacity = match compute_max_nits_in_bits::<BASE, TYPE_BIT_WIDTH>() { Ok(capacity) => capacity, Err(_) => 0 };
+			let k = if capacity == 0 { 0 } else { k % capacity };
+			let dropped = self.shr_nits::<BASE>(k);
+			// The digits which fell off the bottom are moved into the places vacated at the top.
+			let mut place = 0;
+			while place < k {
+				// SAFETY: The rotation is less than the capacity, so every place below it, and every place it's moved to, is valid.
+				unsafe {
+					let nit = dropped.get_nit_indexed::<BASE, BASE_MAXIMUM_BYTES>(PlacesIndex::<TYPE_BIT_WIDTH, BASE>::new_unchecked(place));
+					self.set_nit_indexed(PlacesIndex::new_unchecked(capacity - k + place), nit);
+				}
+				place += 1;
+			}
+		}
+
+		/// Shifts every base-`BASE` digit one place towards the most significant end, placing the given digit at the bottom.
+		/// Returns the digit which fell off the top.
+		///
+		/// If the base is erroneous for the type, nothing is stored, and the given digit is returned.
+		///
+		/// # Example
+		/// ```
+		/// use nit::prelude::*;
+		/// use nit::Trit;
+		/// // The last forty tri-state samples, with the most recent at the bottom.
+		/// let mut history: u64 = 0;
+		/// for _ in 0..40 {
+		///     assert_eq!(history.push_nit_low(Trit::TWO), Trit::ZERO);
+		/// }
+		/// assert_eq!(history.push_nit_low(Trit::ONE), Trit::TWO);
+		/// assert_eq!(history.get_nit::<3, 1>(0), Some(Trit::ONE));
+		/// assert_eq!(history.get_nit::<3, 1>(39), Some(Trit::TWO));
+		/// ```
+		fn push_nit_low<const BASE: BaseMaximum, const BYTES: usize>(&mut self, nit: Nit<BASE, BYTES>) -> Nit<BASE, BYTES> where Self: Sized + Copy {
+			let Ok(bottom) = PlacesIndex::<TYPE_BIT_WIDTH, BASE>::new(0) else {
+				return nit;
+			};
+			let dropped = self.shl_nits::<BASE>(1);
+			// The bottom place was vacated by the shift.
+			self.set_nit_indexed(bottom, nit);
+			dropped.get_nit_indexed(bottom)
+		}
+
+		/// Shifts every base-`BASE` digit one place towards the least significant end, placing the given digit at the top of the nit capacity.
+		/// Returns the digit which fell off the bottom.
+		///
+		/// If the base is erroneous for the type, nothing is stored, and the given digit is returned.
+		///
+		/// # Example
+		/// ```
+		/// use nit::prelude::*;
+		/// use nit::Trit;
+		/// let mut value: u8 = 0;
+		/// assert_eq!(value.push_nit_high(Trit::TWO), Trit::ZERO);
+		/// assert_eq!(value, 2 * 81);
+		/// assert_eq!(value.push_nit_high(Trit::ONE), Trit::ZERO);
+		/// assert_eq!(value, 81 + 2 * 27);
+		/// ```
+		fn push_nit_high<const BASE: BaseMaximum, const BYTES: usize>(&mut self, nit: Nit<BASE, BYTES>) -> Nit<BASE, BYTES> where Self: Sized + Copy {
+			let Ok(capacity @ 1..) = compute_max_nits_in_bits::<BASE, TYPE_BIT_WIDTH>() else {
+				return nit;
+			};
+			let dropped = self.shr_nits::<BASE>(1);
+			// SAFETY: The capacity is non-zero, so both the top and bottom places are valid.
+			unsafe {
+				// The top place within the capacity was vacated by the shift.
+				self.set_nit_indexed(PlacesIndex::<TYPE_BIT_WIDTH, BASE>::new_unchecked(capacity - 1), nit);
+				dropped.get_nit_indexed(PlacesIndex::new_unchecked(0))
+			}
+		}
+
+		/// Returns an iterator over every base-`BASE` digit, starting from the least significant digit (right-hand side).
+		///
+		/// # Example
+		/// ```
+		/// use nit::prelude::*;
+		/// let value: u32 = 1234;
+		/// let digits: Vec<u32> = value.iter_nits::<10, 1>().map(|nit| nit.get_value()).collect();
+		/// assert_eq!(digits, [4, 3, 2, 1, 0, 0, 0, 0, 0]);
+		/// assert!(value.iter_nits::<10, 1>().rev().eq(digits.iter().rev().map(|&digit| Nit::<10>::new(digit).unwrap())));
+		/// assert!(value.iter_nits::<1000, 2>().map(|nit| nit.get_value()).eq([234, 1, 0]));
+		/// ```
+		#[must_use]
+		fn iter_nits<const BASE: BaseMaximum, const BYTES: usize>(&self) -> NitIter<Self, TYPE_BIT_WIDTH, BASE, BYTES> where Self: Copy {
+			NitIter::new(*self)
+		}
+
+		/// Returns the value represented by the base-[`NitRepr::BASE`] digit at the `n`th place.
+		///
+		/// # Example
+		/// ```
+		/// use nit::prelude::*;
+		///
+		/// #[derive(Debug, Clone, Copy, PartialEq, Eq, Nit)]
+		/// enum Light { Red, Yellow, Green }
+		///
+		/// let value: u8 = 2 * 3 + 1; // `Yellow` at the 0th place, `Green` at the 1st.
+		/// assert_eq!(value.get_as::<Light>(0), Some(Light::Yellow));
+		/// assert_eq!(value.get_as::<Light>(1), Some(Light::Green));
+		/// assert_eq!(value.get_as::<Light>(5), None);
+		/// ```
+		#[must_use]
+		fn get_as<T: NitRepr>(&self, n: FitsMaximumBits) -> Option<T> {
+			T::get_from(self, n)
+		}
+
+		/// Sets the base-[`NitRepr::BASE`] digit at the `n`th place to the one representing the given value.
+		/// Returns the value represented by the previous digit at that place, or an error if the index is out of bounds or there was an issue relating to the base or bit count.
+		///
+		/// # Errors
+		/// See: [`PlacesIndexCreationError`]
+		///
+		/// # Example
+		/// ```
+		/// use nit::prelude::*;
+		///
+		/// #[derive(Debug, Clone, Copy, PartialEq, Eq, Nit)]
+		/// enum Light { Red, Yellow, Green }
+		///
+		/// let mut value: u8 = 0;
+		/// assert_eq!(value.set_as(1, Light::Green), Ok(Light::Red));
+		/// assert_eq!(value.set_as(1, Light::Yellow), Ok(Light::Green));
+		/// assert_eq!(value, 3);
+		/// assert_eq!(value.set_as(5, Light::Yellow), Err(PlacesIndexCreationError::OutOfBounds));
+		/// ```
+		fn set_as<T: NitRepr>(&mut self, n: FitsMaximumBits, value: T) -> Result<T, PlacesIndexCreationError> {
+			value.set_in(self, n)
+		}
 	}
 }
 /// Generates an implementation of the [`NitDataContainer`] trait for each primitive integer type provided.
@@ -94,32 +429,23 @@ macro_rules! impl_numeric_data_container {
 		$(
 			const_impl!(NitDataContainer<{ #[allow(clippy::cast_possible_truncation)] { <$type>::BITS as FitsMaximumBits } }> | $type {
 				#[cfg_attr(all(test, not(tarpaulin), not(debug_assertions)), no_panic)]
-				fn get_nit_indexed<const BASE: BaseMaximum>(&self, n: PlacesIndex<{ #[allow(clippy::cast_possible_truncation)] { <$type>::BITS as FitsMaximumBits } }, { BASE }>) -> Nit<{ BASE }> {
-					use crate::base::Base;
-					let shifter = <$type>::get_places_shifter(n).get();
-					#[allow(clippy::cast_lossless)]
-					let modulator = BASE as $type;
-					// SAFETY: A shifter is required to be non-zero in its preconditions.
-					//         It is meant to functionally equivalent to `BASE.pow(n)`, which is guaranteed to be non-zero.
-					//         This fact is further gated behind an `unsafe` constructor, so I think it's fair to make this assumption.
-					#[cfg(not(debug_assertions))] unsafe { if shifter == 0 { core::hint::unreachable_unchecked(); } }
-					let digit = ((*self / shifter) % modulator);
+				fn get_nit_indexed<const BASE: BaseMaximum, const BYTES: usize>(&self, n: PlacesIndex<{ #[allow(clippy::cast_possible_truncation)] { <$type>::BITS as FitsMaximumBits } }, { BASE }>) -> Nit<BASE, BYTES> {
+					use crate::base::{Base, Powers};
+					// Equivalent to `(*self / shifter) % BASE`, without any hardware division.
+					let digit = Powers::<$type, BASE>::BASE_RECIPROCAL.remainder(<$type>::get_places_reciprocal(n).divide(*self));
 					#[allow(clippy::cast_possible_truncation)]
-					// SAFETY: The value will be always within the range of `0..BASE` because of the modulo operation.
-					unsafe { #[allow(clippy::cast_possible_truncation)] let digit = digit as FitsMaximumBits; Nit::new_unchecked(digit) }
+					// SAFETY: The value will be always within the range of `0..BASE` because it is the remainder of a division by `BASE`.
+					unsafe { #[allow(clippy::cast_possible_truncation, clippy::cast_lossless)] let digit = digit as BaseMaximum; Nit::new_unchecked(digit) }
 				}
 
-				fn set_nit_indexed<const BASE: BaseMaximum>(&mut self, n: PlacesIndex<{ #[allow(clippy::cast_possible_truncation)] { <$type>::BITS as FitsMaximumBits } }, { BASE }>, value: Nit<{ BASE }>) -> Nit<{ BASE }> {
-					use crate::base::Base;
-					let shifter = <$type>::get_places_shifter(n).get();
-					#[allow(clippy::cast_lossless)]
-					let modulator = BASE as $type;
-					// SAFETY: A shifter is required to be non-zero in its preconditions.
-					//         It is meant to functionally equivalent to `BASE.pow(n)`, which is guaranteed to be non-zero.
-					//         This fact is further gated behind an `unsafe` constructor, so I think it's fair to make this assumption.
-					#[cfg(not(debug_assertions))] unsafe { if shifter == 0 { core::hint::unreachable_unchecked(); } }
-					let digit = ((*self / shifter) % modulator);
+				fn set_nit_indexed<const BASE: BaseMaximum, const BYTES: usize>(&mut self, n: PlacesIndex<{ #[allow(clippy::cast_possible_truncation)] { <$type>::BITS as FitsMaximumBits } }, { BASE }>, value: Nit<BASE, BYTES>) -> Nit<BASE, BYTES> {
+					use crate::base::{Base, Powers};
+					let reciprocal = <$type>::get_places_reciprocal(n);
+					let shifter = reciprocal.divisor();
+					// Equivalent to `(*self / shifter) % BASE`, without any hardware division.
+					let digit = Powers::<$type, BASE>::BASE_RECIPROCAL.remainder(reciprocal.divide(*self));
 					// TODO: Document why we overflow?
+					#[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
 					let diff = (value.get_value() as $type).overflowing_sub(digit).0;
 					// > Assuming that we're not indexing out of bounds, a normal multiplication would overflow on one condition: a set from 0 to B-1 (the stored max value of the nit) on the last nit index.
 					// > In that case, we would reach an `adjust` of $T + 1, which should be semantically equivalent to an `adjust` of 0 / no adjust, so it's the correct behavior to preform an overflow.
@@ -127,11 +453,440 @@ macro_rules! impl_numeric_data_container {
 					let adjust = diff.overflowing_mul(shifter).0;
 					// TODO: Document why we can safely add the adjust
 					*self = self.overflowing_add(adjust).0;
-					// SAFETY: The value will be always within the range of `0..BASE` because of the modulo operation.
-					unsafe { #[allow(clippy::cast_possible_truncation)] let digit = digit as FitsMaximumBits; Nit::new_unchecked(digit) }
+					// SAFETY: The value will be always within the range of `0..BASE` because it is the remainder of a division by `BASE`.
+					unsafe { #[allow(clippy::cast_possible_truncation, clippy::cast_lossless)] let digit = digit as BaseMaximum; Nit::new_unchecked(digit) }
+				}
+
+				fn to_nits<const BASE: BaseMaximum, const BYTES: usize, const CAP: usize>(&self) -> [Nit<BASE, BYTES>; CAP] {
+					const {
+						#[allow(clippy::cast_possible_truncation)]
+						match compute_max_nits_in_bits::<BASE, { <$type>::BITS as FitsMaximumBits }>() {
+							Ok(max) => assert!(max as usize == CAP, "`CAP` must be the nit capacity of the type for the base."),
+							Err(err) => panic!("{}", err.get_str()),
+						}
+					}
+					// SAFETY: Zero is within the range of `0..BASE`.
+					let mut nits = [unsafe { Nit::new_unchecked(0) }; CAP];
+					// The capacity is at least one, so this is the reciprocal of the base itself.
+					let reciprocal = crate::base::Powers::<$type, BASE>::BASE_RECIPROCAL;
+					let mut remaining = *self;
+					let mut i = 0;
+					while i < CAP {
+						// Equivalent to `(remaining / BASE, remaining % BASE)`, without any hardware division.
+						let quotient = reciprocal.divide(remaining);
+						let digit = remaining - quotient * reciprocal.divisor();
+						remaining = quotient;
+						// SAFETY: The value will be always within the range of `0..BASE` because it is the remainder of a division by `BASE`.
+						nits[i] = unsafe { #[allow(clippy::cast_possible_truncation, clippy::cast_lossless)] let digit = digit as BaseMaximum; Nit::new_unchecked(digit) };
+						i += 1;
+					}
+					nits
+				}
+
+				fn from_nits<const BASE: BaseMaximum, const BYTES: usize>(nits: &[Nit<BASE, BYTES>]) -> Result<Self, PlacesIndexCreationError> {
+					#[allow(clippy::cast_possible_truncation)]
+					match compute_max_nits_in_bits::<BASE, { <$type>::BITS as FitsMaximumBits }>() {
+						Err(err) => return Err(PlacesIndexCreationError::BadNitLimitEvaluation(err)),
+						Ok(max) => if nits.len() > max as usize { return Err(PlacesIndexCreationError::OutOfBounds) },
+					}
+					let modulator = crate::base::Powers::<$type, BASE>::BASE_RECIPROCAL.divisor();
+					// Horner's method, from the most significant digit; this can't overflow as the digits fit within the capacity.
+					let mut value: $type = 0;
+					let mut i = nits.len();
+					while i > 0 {
+						i -= 1;
+						#[allow(clippy::cast_lossless, clippy::cast_possible_truncation)]
+						let digit = nits[i].get_value() as $type;
+						value = value * modulator + digit;
+					}
+					Ok(value)
+				}
+
+				fn shl_nits<const BASE: BaseMaximum>(&mut self, k: FitsMaximumBits) -> Self {
+					use crate::base::Powers;
+					let capacity = Powers::<$type, BASE>::CAPACITY;
+					let k = if k < capacity { k } else { capacity };
+					let (_, value) = Powers::<$type, BASE>::split_at_place(*self, capacity);
+					let (dropped, kept) = Powers::<$type, BASE>::split_at_place(value, capacity - k);
+					// The kept digits fit within the capacity once shifted, so this can't overflow.
+					*self = kept * Powers::<$type, BASE>::power(k);
+					dropped
+				}
+
+				fn shr_nits<const BASE: BaseMaximum>(&mut self, k: FitsMaximumBits) -> Self {
+					use crate::base::Powers;
+					let capacity = Powers::<$type, BASE>::CAPACITY;
+					let k = if k < capacity { k } else { capacity };
+					let (_, value) = Powers::<$type, BASE>::split_at_place(*self, capacity);
+					let (kept, dropped) = Powers::<$type, BASE>::split_at_place(value, k);
+					*self = kept;
+					dropped
+				}
+
+				fn rotate_nits_left<const BASE: BaseMaximum>(&mut self, k: FitsMaximumBits) {
+					use crate::base::Powers;
+					let capacity = Powers::<$type, BASE>::CAPACITY;
+					if capacity == 0 {
+						*self = 0;
+						return;
+					}
+					let k = k % capacity;
+					let (_, value) = Powers::<$type, BASE>::split_at_place(*self, capacity);
+					let (high, low) = Powers::<$type, BASE>::split_at_place(value, capacity - k);
+					// The low digits are moved into the places vacated by the high digits, so neither this nor the sum can overflow.
+					*self = low * Powers::<$type, BASE>::power(k) + high;
+				}
+
+				fn rotate_nits_right<const BASE: BaseMaximum>(&mut self, k: FitsMaximumBits) {
+					use crate::base::Powers;
+					let capacity = Powers::<$type, BASE>::CAPACITY;
+					if capacity == 0 {
+						*self = 0;
+						return;
+					}
+					let k = k % capacity;
+					let (_, value) = Powers::<$type, BASE>::split_at_place(*self, capacity);
+					let (high, low) = Powers::<$type, BASE>::split_at_place(value, k);
+					// The low digits are moved into the places vacated by the high digits, so neither this nor the sum can overflow.
+					*self = low * Powers::<$type, BASE>::power(capacity - k) + high;
+				}
+
+				fn push_nit_low<const BASE: BaseMaximum, const BYTES: usize>(&mut self, nit: Nit<BASE, BYTES>) -> Nit<BASE, BYTES> {
+					use crate::base::Powers;
+					if Powers::<$type, BASE>::CAPACITY == 0 {
+						return nit;
+					}
+					let dropped = self.shl_nits::<BASE>(1);
+					#[allow(clippy::cast_lossless, clippy::cast_possible_truncation)]
+					let digit = nit.get_value() as $type;
+					// The bottom place was vacated by the shift.
+					*self += digit;
+					// SAFETY: A single dropped digit is within the range of `0..BASE`.
+					unsafe { #[allow(clippy::cast_possible_truncation, clippy::cast_lossless)] let dropped = dropped as BaseMaximum; Nit::new_unchecked(dropped) }
+				}
+
+				fn push_nit_high<const BASE: BaseMaximum, const BYTES: usize>(&mut self, nit: Nit<BASE, BYTES>) -> Nit<BASE, BYTES> {
+					use crate::base::Powers;
+					let capacity = Powers::<$type, BASE>::CAPACITY;
+					if capacity == 0 {
+						return nit;
+					}
+					let dropped = self.shr_nits::<BASE>(1);
+					#[allow(clippy::cast_lossless, clippy::cast_possible_truncation)]
+					let digit = nit.get_value() as $type;
+					// The top place within the capacity was vacated by the shift.
+					*self += digit * Powers::<$type, BASE>::TABLE[capacity as usize - 1];
+					// SAFETY: A single dropped digit is within the range of `0..BASE`.
+					unsafe { #[allow(clippy::cast_possible_truncation, clippy::cast_lossless)] let dropped = dropped as BaseMaximum; Nit::new_unchecked(dropped) }
 				}
 			});
+
+			impl<const BASE: BaseMaximum, const BYTES: usize> FromIterator<Nit<BASE, BYTES>> for $type {
+				/// Creates a value from base-`BASE` digits, starting from the least significant digit (right-hand side).
+				///
+				/// Digits beyond the nit capacity of the type for the base are ignored, and the value is zero if the base or bit width is erroneous;
+				/// see [`NitDataContainer::from_nits`] for a checked alternative.
+				///
+				/// # Example
+				/// ```
+				/// use nit::prelude::*;
+				/// let value: u32 = 1234;
+				/// assert_eq!(value.iter_nits::<10, 1>().collect::<u32>(), value);
+				/// assert_eq!(value.iter_nits::<10, 1>().skip(1).collect::<u32>(), 123);
+				/// ```
+				fn from_iter<I: IntoIterator<Item = Nit<BASE, BYTES>>>(iter: I) -> Self {
+					#[allow(clippy::cast_possible_truncation)]
+					let Ok(max) = compute_max_nits_in_bits::<BASE, { <$type>::BITS as FitsMaximumBits }>() else { return 0 };
+					#[allow(clippy::cast_lossless, clippy::cast_possible_truncation)]
+					let modulator = BASE as $type;
+					let mut value: $type = 0;
+					let mut shifter: $type = 1;
+					for (i, nit) in iter.into_iter().take(usize::from(max)).enumerate() {
+						#[allow(clippy::cast_lossless, clippy::cast_possible_truncation)]
+						let digit = nit.get_value() as $type;
+						value += digit * shifter;
+						// `BASE.pow(max)` isn't needed, and doesn't fit within the type for a base of two.
+						if i + 1 < usize::from(max) {
+							shifter *= modulator;
+						}
+					}
+					value
+				}
+			}
 		)*
 	};
 }
 impl_numeric_data_container!(u8, u16, u32, u64, u128);
+
+/// Generates an implementation of the [`NitDataContainer`] trait for each primitive integer type provided, delegating to the unsigned type of the same width.
+///
+/// The nits are those of the two's complement bit pattern, so a negative value has the same nits as the unsigned value with the same bits.
+macro_rules! impl_delegated_numeric_data_container {
+	($(($type: ty, $unsigned: ty)),*) => {
+		$(
+			const_impl!(NitDataContainer<{ #[allow(clippy::cast_possible_truncation)] { <$type>::BITS as FitsMaximumBits } }> | $type {
+				#[cfg_attr(all(test, not(tarpaulin), not(debug_assertions)), no_panic)]
+				fn get_nit_indexed<const BASE: BaseMaximum, const BYTES: usize>(&self, n: PlacesIndex<{ #[allow(clippy::cast_possible_truncation)] { <$type>::BITS as FitsMaximumBits } }, { BASE }>) -> Nit<BASE, BYTES> {
+					#[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+					let bits = *self as $unsigned;
+					bits.get_nit_indexed(n)
+				}
+
+				fn set_nit_indexed<const BASE: BaseMaximum, const BYTES: usize>(&mut self, n: PlacesIndex<{ #[allow(clippy::cast_possible_truncation)] { <$type>::BITS as FitsMaximumBits } }, { BASE }>, value: Nit<BASE, BYTES>) -> Nit<BASE, BYTES> {
+					#[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+					let mut bits = *self as $unsigned;
+					let previous = bits.set_nit_indexed(n, value);
+					#[allow(clippy::cast_possible_wrap, clippy::cast_possible_truncation)]
+					let replaced = bits as $type;
+					*self = replaced;
+					previous
+				}
+
+				fn to_nits<const BASE: BaseMaximum, const BYTES: usize, const CAP: usize>(&self) -> [Nit<BASE, BYTES>; CAP] {
+					#[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+					let bits = *self as $unsigned;
+					bits.to_nits::<BASE, BYTES, CAP>()
+				}
+
+				fn from_nits<const BASE: BaseMaximum, const BYTES: usize>(nits: &[Nit<BASE, BYTES>]) -> Result<Self, PlacesIndexCreationError> {
+					match <$unsigned>::from_nits(nits) {
+						#[allow(clippy::cast_possible_wrap, clippy::cast_possible_truncation)]
+						Ok(bits) => Ok(bits as $type),
+						Err(err) => Err(err),
+					}
+				}
+
+				fn shl_nits<const BASE: BaseMaximum>(&mut self, k: FitsMaximumBits) -> Self {
+					#[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+					let mut bits = *self as $unsigned;
+					let dropped = bits.shl_nits::<BASE>(k);
+					#[allow(clippy::cast_possible_wrap, clippy::cast_possible_truncation)]
+					let (shifted, dropped) = (bits as $type, dropped as $type);
+					*self = shifted;
+					dropped
+				}
+
+				fn shr_nits<const BASE: BaseMaximum>(&mut self, k: FitsMaximumBits) -> Self {
+					#[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+					let mut bits = *self as $unsigned;
+					let dropped = bits.shr_nits::<BASE>(k);
+					#[allow(clippy::cast_possible_wrap, clippy::cast_possible_truncation)]
+					let (shifted, dropped) = (bits as $type, dropped as $type);
+					*self = shifted;
+					dropped
+				}
+
+				fn rotate_nits_left<const BASE: BaseMaximum>(&mut self, k: FitsMaximumBits) {
+					#[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+					let mut bits = *self as $unsigned;
+					bits.rotate_nits_left::<BASE>(k);
+					#[allow(clippy::cast_possible_wrap, clippy::cast_possible_truncation)]
+					let rotated = bits as $type;
+					*self = rotated;
+				}
+
+				fn rotate_nits_right<const BASE: BaseMaximum>(&mut self, k: FitsMaximumBits) {
+					#[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+					let mut bits = *self as $unsigned;
+					bits.rotate_nits_right::<BASE>(k);
+					#[allow(clippy::cast_possible_wrap, clippy::cast_possible_truncation)]
+					let rotated = bits as $type;
+					*self = rotated;
+				}
+
+				fn push_nit_low<const BASE: BaseMaximum, const BYTES: usize>(&mut self, nit: Nit<BASE, BYTES>) -> Nit<BASE, BYTES> {
+					#[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+					let mut bits = *self as $unsigned;
+					let dropped = bits.push_nit_low(nit);
+					#[allow(clippy::cast_possible_wrap, clippy::cast_possible_truncation)]
+					let pushed = bits as $type;
+					*self = pushed;
+					dropped
+				}
+
+				fn push_nit_high<const BASE: BaseMaximum, const BYTES: usize>(&mut self, nit: Nit<BASE, BYTES>) -> Nit<BASE, BYTES> {
+					#[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+					let mut bits = *self as $unsigned;
+					let dropped = bits.push_nit_high(nit);
+					#[allow(clippy::cast_possible_wrap, clippy::cast_possible_truncation)]
+					let pushed = bits as $type;
+					*self = pushed;
+					dropped
+				}
+			});
+
+			impl<const BASE: BaseMaximum, const BYTES: usize> FromIterator<Nit<BASE, BYTES>> for $type {
+				/// Creates a value from its base-`BASE` digits, as the unsigned type of the same width does, reinterpreting the resulting bits.
+				fn from_iter<I: IntoIterator<Item = Nit<BASE, BYTES>>>(iter: I) -> Self {
+					#[allow(clippy::cast_possible_wrap, clippy::cast_possible_truncation)]
+					let value = <$unsigned>::from_iter(iter) as $type;
+					value
+				}
+			}
+		)*
+	};
+}
+impl_delegated_numeric_data_container!((i8, u8), (i16, u16), (i32, u32), (i64, u64), (i128, u128));
+#[cfg(target_pointer_width = "16")]
+impl_delegated_numeric_data_container!((usize, u16), (isize, u16));
+#[cfg(target_pointer_width = "32")]
+impl_delegated_numeric_data_container!((usize, u32), (isize, u32));
+#[cfg(target_pointer_width = "64")]
+impl_delegated_numeric_data_container!((usize, u64), (isize, u64));
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::missing_docs_in_private_items)]
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+
+	/// The reference implementation of shifting, moving the digits within an array by `k` places, where a positive `k` is towards the most significant end.
+	#[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+	fn reference_shift<const BASE: BaseMaximum, const CAP: usize>(value: u8, k: isize, rotate: bool) -> u8 {
+		let nits: [Nit<BASE>; CAP] = value.to_nits();
+		let mut shifted = [Nit::new(0).unwrap(); CAP];
+		for (i, &nit) in nits.iter().enumerate() {
+			let target = i as isize + k;
+			let target = if rotate { target.rem_euclid(CAP as isize) } else { target };
+			if (0..CAP as isize).contains(&target) {
+				shifted[target as usize] = nit;
+			}
+		}
+		u8::from_nits(&shifted).unwrap()
+	}
+
+	#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+	fn check_shifts<const BASE: BaseMaximum, const CAP: usize>() {
+		for value in 0..=u8::MAX {
+			for k in 0..=CAP as FitsMaximumBits + 2 {
+				let shift = k as isize;
+				// The digits which fall off are the ones that remain after shifting the other way by the rest of the capacity.
+				let rest = (CAP as isize - shift).max(0);
+				let (mut left, mut right) = (value, value);
+				assert_eq!(left.shl_nits::<BASE>(k), reference_shift::<BASE, CAP>(value, -rest, false), "{value} << {k}");
+				assert_eq!(right.shr_nits::<BASE>(k), reference_shift::<BASE, CAP>(reference_shift::<BASE, CAP>(value, rest, false), -rest, false), "{value} >> {k}");
+				assert_eq!(left, reference_shift::<BASE, CAP>(value, shift, false), "{value} << {k}");
+				assert_eq!(right, reference_shift::<BASE, CAP>(value, -shift, false), "{value} >> {k}");
+				let (mut left, mut right) = (value, value);
+				left.rotate_nits_left::<BASE>(k);
+				right.rotate_nits_right::<BASE>(k);
+				assert_eq!(left, reference_shift::<BASE, CAP>(value, shift, true), "{value} rotated left by {k}");
+				assert_eq!(right, reference_shift::<BASE, CAP>(value, -shift, true), "{value} rotated right by {k}");
+			}
+		}
+	}
+
+	#[test]
+	fn shifts_match_reference() {
+		check_shifts::<2, 8>();
+		check_shifts::<3, 5>();
+		check_shifts::<4, 3>();
+		check_shifts::<7, 2>();
+		check_shifts::<10, 2>();
+		check_shifts::<128, 1>();
+	}
+
+	/// A byte which only implements the required methods, so that it goes through the provided ones.
+	#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+	struct Provided(u8);
+	impl NitDataContainer<8> for Provided {
+		fn get_nit_indexed<const BASE: BaseMaximum, const BYTES: usize>(&self, n: PlacesIndex<8, BASE>) -> Nit<BASE, BYTES> {
+			self.0.get_nit_indexed(n)
+		}
+
+		fn set_nit_indexed<const BASE: BaseMaximum, const BYTES: usize>(&mut self, n: PlacesIndex<8, BASE>, value: Nit<BASE, BYTES>) -> Nit<BASE, BYTES> {
+			self.0.set_nit_indexed(n, value)
+		}
+
+		fn to_nits<const BASE: BaseMaximum, const BYTES: usize, const CAP: usize>(&self) -> [Nit<BASE, BYTES>; CAP] {
+			self.0.to_nits()
+		}
+
+		fn from_nits<const BASE: BaseMaximum, const BYTES: usize>(nits: &[Nit<BASE, BYTES>]) -> Result<Self, PlacesIndexCreationError> {
+			u8::from_nits(nits).map(Self)
+		}
+	}
+
+	#[allow(clippy::cast_possible_truncation)]
+	fn check_provided<const BASE: BaseMaximum>() {
+		for value in 0..=u8::MAX {
+			for k in 0..=10 {
+				let (mut expected, mut provided) = (value, Provided(value));
+				assert_eq!(provided.shl_nits::<BASE>(k), Provided(expected.shl_nits::<BASE>(k)), "{value} << {k}");
+				assert_eq!(provided, Provided(expected));
+				let (mut expected, mut provided) = (value, Provided(value));
+				assert_eq!(provided.shr_nits::<BASE>(k), Provided(expected.shr_nits::<BASE>(k)), "{value} >> {k}");
+				assert_eq!(provided, Provided(expected));
+				let (mut expected, mut provided) = (value, Provided(value));
+				expected.rotate_nits_left::<BASE>(k);
+				provided.rotate_nits_left::<BASE>(k);
+				assert_eq!(provided, Provided(expected), "{value} rotated left by {k}");
+				let (mut expected, mut provided) = (value, Provided(value));
+				expected.rotate_nits_right::<BASE>(k);
+				provided.rotate_nits_right::<BASE>(k);
+				assert_eq!(provided, Provided(expected), "{value} rotated right by {k}");
+				let nit = Nit::<BASE>::new(BaseMaximum::from(k) % BASE).unwrap();
+				let (mut expected, mut provided) = (value, Provided(value));
+				assert_eq!(provided.push_nit_low(nit), expected.push_nit_low(nit), "{value} pushed low");
+				assert_eq!(provided, Provided(expected));
+				let (mut expected, mut provided) = (value, Provided(value));
+				assert_eq!(provided.push_nit_high(nit), expected.push_nit_high(nit), "{value} pushed high");
+				assert_eq!(provided, Provided(expected));
+			}
+		}
+	}
+
+	#[test]
+	fn provided_match_overrides() {
+		check_provided::<2>();
+		check_provided::<3>();
+		check_provided::<10>();
+		check_provided::<200>();
+		// Erroneous for a byte, so everything is discarded.
+		check_provided::<256>();
+	}
+
+	#[test]
+	fn large_bases() {
+		type Thousand = Nit<1000, 2>;
+		let thousands = [7, 999, 0, 512, 1, 998].map(|value| Thousand::new(value).unwrap());
+		let value: u64 = thousands.into_iter().collect();
+		assert_eq!(value, 998_001_512_000_999_007);
+		assert!(NitIter::<u64, 64, 1000, 2>::new(value).eq(thousands));
+		assert_eq!(value.get_as::<Thousand>(3), Some(Nit::new(512).unwrap()));
+		assert_eq!(value.get_as::<Thousand>(6), None);
+		assert_eq!(value.get_nit::<1000, 2>(1), Some(Nit::new(999).unwrap()));
+		assert!(value.iter_nits::<1000, 2>().eq(thousands));
+		assert_eq!(value.to_nits::<1000, 2, 6>(), thousands);
+		assert_eq!(u64::from_nits(&thousands), Ok(value));
+		assert!(u32::from_nits(&thousands).is_err());
+		let mut changed = value;
+		assert_eq!(changed.set_nit(2, Thousand::new(250).unwrap()), Ok(Nit::new(0).unwrap()));
+		assert_eq!(changed, 998_001_512_250_999_007);
+		assert_eq!(changed.push_nit_low(Thousand::new(3).unwrap()), Nit::new(998).unwrap());
+		assert_eq!(changed, 1_512_250_999_007_003);
+		assert_eq!(changed.push_nit_high(Thousand::new(4).unwrap()), Nit::new(3).unwrap());
+		assert_eq!(changed, 4_001_512_250_999_007);
+
+		// Four base-60000 digits fit within a `u64`, as `60000^4 < 2^64`.
+		let mut packed: u64 = 0;
+		for (i, digit) in (0..).zip([59_999, 0, 12_345, 59_998]) {
+			packed.set_as::<Nit<60000, 2>>(i, Nit::new(digit).unwrap()).unwrap();
+		}
+		assert!(NitIter::<u64, 64, 60000, 2>::new(packed).map(|nit| nit.get_value()).eq([59_999, 0, 12_345, 59_998]));
+		assert!(packed.set_as::<Nit<60000, 2>>(4, Nit::new(1).unwrap()).is_err());
+		// The digits of base-256 still fit within a byte.
+		assert_eq!(u32::from_nits(&[Nit::<256>::new(255).unwrap(), Nit::new(1).unwrap()]), Ok(511));
+		assert_eq!(u8::from_nits(&[Nit::<255>::new(254).unwrap()]), Ok(254));
+		assert!(u8::from_nits(&[Nit::<256>::new(0).unwrap()]).is_err());
+	}
+
+	#[test]
+	fn pushing_signed() {
+		let mut value: i8 = -1;
+		assert_eq!(value.push_nit_low(Nit::<2>::new(0).unwrap()), Nit::new(1).unwrap());
+		assert_eq!(value, -2);
+		assert_eq!(value.push_nit_high(Nit::<2>::new(0).unwrap()), Nit::new(0).unwrap());
+		assert_eq!(value, 127);
+	}
+}
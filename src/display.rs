@@ -0,0 +1,387 @@
+use core::fmt::{self, Write};
+use core::str::FromStr;
+use crate::Nit;
+use crate::data_container::NitDataContainer;
+use crate::internal_macros::{deriving_const, impl_error};
+use crate::max_nits::{compute_max_nits_in_bits, MaxNitComputationFailure};
+use crate::supported::{BaseMaximum, FitsMaximumBits, BASE_MAXIMUM_BYTES};
+
+deriving_const!((PartialEq) for {
+	/// The reason parsing nits from a string failed.
+	#[derive(Debug, Clone, Copy, Eq, Hash)]
+	pub enum ParseNitsError {
+		/// The string has no digits.
+		Empty,
+		/// A character isn't a digit of the alphabet within the range of `0..BASE`, nor a separator.
+		InvalidDigit,
+		/// There are more digits than the nit capacity of the type for the base.
+		TooManyDigits,
+		/// The base or bit width is erroneous.
+		BadNitLimitEvaluation(MaxNitComputationFailure),
+	}
+});
+impl ParseNitsError {
+	/// Returns the error message as a string.
+	#[must_use]
+	#[cfg(not(tarpaulin_include))]
+	pub const fn get_str(&self) -> &str {
+		match self {
+			Self::Empty => "The string has no digits.",
+			Self::InvalidDigit => "A character isn't a digit of the alphabet within the range of `0..BASE`, nor a separator.",
+			Self::TooManyDigits => "There are more digits than the nit capacity of the type for the base.",
+			Self::BadNitLimitEvaluation(err) => err.get_str(),
+		}
+	}
+}
+#[cfg(not(tarpaulin_include))]
+impl fmt::Display for ParseNitsError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}", self.get_str())
+	}
+}
+impl_error!(ParseNitsError);
+
+/// How the digits of a value are written as text, and read back from it.
+///
+/// # Example
+/// ```
+/// use nit::display::NitStyle;
+/// use nit::prelude::*;
+/// let style = NitStyle::DEFAULT.alphabet("-0+").grouped(5, ' ');
+/// let value = u16::parse_nits_with::<3>("---0+ -0+0-", &style).unwrap();
+/// assert_eq!(value, u16::parse_nits::<3>("0001201210").unwrap());
+/// assert_eq!(value.display_nits::<3>().style(style).to_string(), "---0+ -0+0-");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NitStyle<'a> {
+	/// The character of each digit, in order from zero.
+	alphabet: &'a str,
+	/// The amount of digits within each group, counted from the least significant digit, and the separator written between groups.
+	group: Option<(usize, char)>,
+}
+
+impl<'a> NitStyle<'a> {
+	/// The alphabet used by default, which has enough digits for bases up to 62.
+	pub const DIGITS: &'static str = "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+	/// Ungrouped digits from [`DIGITS`](Self::DIGITS).
+	pub const DEFAULT: NitStyle<'static> = NitStyle { alphabet: Self::DIGITS, group: None };
+
+	/// Uses the characters of `alphabet` as the digits, in order from zero.
+	/// It needs at least `BASE` characters, and none of them should repeat or be the separator.
+	#[must_use]
+	pub const fn alphabet(self, alphabet: &'a str) -> Self {
+		Self { alphabet, ..self }
+	}
+
+	/// Separates every `size` digits with `separator`, counting from the least significant digit; a `size` of zero doesn't group them.
+	/// Separators are ignored wherever they are when parsing.
+	#[must_use]
+	pub const fn grouped(self, size: usize, separator: char) -> Self {
+		Self { group: if size == 0 { None } else { Some((size, separator)) }, ..self }
+	}
+
+	/// Returns the character of a digit, if the alphabet has one for it.
+	fn character(&self, digit: BaseMaximum) -> Option<char> {
+		self.alphabet.chars().nth(usize::try_from(digit).ok()?)
+	}
+
+	/// Returns the base-`BASE` digit a character is, or [`None`] if it isn't one.
+	fn digit<const BASE: BaseMaximum>(&self, character: char) -> Option<Nit<BASE, BASE_MAXIMUM_BYTES>> {
+		let position = self.alphabet.chars().position(|c| c == character)?;
+		Nit::new(BaseMaximum::try_from(position).ok()?).ok()
+	}
+
+	/// Returns whether a character is the separator between groups.
+	const fn is_separator(&self, character: char) -> bool {
+		matches!(self.group, Some((_, separator)) if separator == character)
+	}
+
+	/// Writes the digits, from the most significant one, separating the groups.
+	fn write<const BASE: BaseMaximum>(&self, f: &mut impl Write, digits: impl ExactSizeIterator<Item = Nit<BASE, BASE_MAXIMUM_BYTES>>) -> fmt::Result {
+		let len = digits.len();
+		for (i, nit) in digits.enumerate() {
+			if let Some((size, separator)) = self.group {
+				if i != 0 && (len - i).is_multiple_of(size) {
+					f.write_char(separator)?;
+				}
+			}
+			f.write_char(self.character(nit.get_value()).ok_or(fmt::Error)?)?;
+		}
+		Ok(())
+	}
+
+	/// Reads the digits, from the most significant one, into the value using `push`, which appends a digit as the least significant one.
+	fn read<const BASE: BaseMaximum>(&self, s: &str, capacity: FitsMaximumBits, mut push: impl FnMut(Nit<BASE, BASE_MAXIMUM_BYTES>)) -> Result<(), ParseNitsError> {
+		let mut count = 0;
+		for character in s.chars() {
+			if self.is_separator(character) {
+				continue;
+			}
+			let nit = self.digit(character).ok_or(ParseNitsError::InvalidDigit)?;
+			count += 1;
+			if count > usize::from(capacity) {
+				return Err(ParseNitsError::TooManyDigits);
+			}
+			push(nit);
+		}
+		if count == 0 { Err(ParseNitsError::Empty) } else { Ok(()) }
+	}
+}
+
+impl Default for NitStyle<'_> {
+	fn default() -> Self {
+		NitStyle::DEFAULT
+	}
+}
+
+/// Formats every base-`BASE` digit of a value, from the most significant one, as a fixed-width string; see [`DisplayNits::display_nits`].
+///
+/// This writes directly to the formatter without allocating, so it can be written to any [`core::fmt::Write`].
+///
+/// Formatting fails with [`fmt::Error`], rather than writing something which doesn't read back as the value:
+/// - If the value has digits beyond the nit capacity of the type for the base, which can't be written within its width.
+///   The nit capacity is the amount of places whose every combination fits, so a `u32` only has seven hexadecimal places, and [`u32::MAX`] can't be written in hexadecimal.
+/// - If the base is erroneous for the type.
+/// - If the [alphabet](NitStyle::alphabet) doesn't have a character for a digit.
+///
+/// Since [`ToString::to_string`] panics on such an error, use [`write!`] where the value may not fit.
+#[derive(Debug, Clone, Copy)]
+pub struct NitDisplay<'a, T, const BASE: BaseMaximum> {
+	/// The value whose digits are written.
+	value: T,
+	/// How the digits are written.
+	style: NitStyle<'a>,
+}
+
+impl<'a, T, const BASE: BaseMaximum> NitDisplay<'a, T, BASE> {
+	/// Writes the digits as described by `style`.
+	#[must_use]
+	pub fn style(self, style: NitStyle<'_>) -> NitDisplay<'_, T, BASE> {
+		NitDisplay { value: self.value, style }
+	}
+
+	/// Uses the characters of `alphabet` as the digits; see [`NitStyle::alphabet`].
+	#[must_use]
+	pub fn alphabet<'b>(self, alphabet: &'b str) -> NitDisplay<'b, T, BASE> where 'a: 'b {
+		NitDisplay { value: self.value, style: self.style.alphabet(alphabet) }
+	}
+
+	/// Separates every `size` digits with `separator`; see [`NitStyle::grouped`].
+	#[must_use]
+	pub fn grouped(self, size: usize, separator: char) -> Self {
+		Self { value: self.value, style: self.style.grouped(size, separator) }
+	}
+
+	/// Returns the value whose digits are written.
+	#[must_use]
+	pub fn into_inner(self) -> T {
+		self.value
+	}
+}
+
+/// Formatting and parsing the base-`BASE` digits of a value as text.
+///
+/// # Example
+/// ```
+/// use nit::prelude::*;
+/// let value = u16::parse_nits::<3>("21002").unwrap();
+/// assert_eq!(value, 2 * 81 + 1 * 27 + 2);
+/// assert_eq!(value.display_nits::<3>().to_string(), "0000021002");
+/// assert_eq!(value.display_nits::<3>().grouped(5, '_').to_string(), "00000_21002");
+/// assert_eq!(u16::parse_nits::<3>("00000_21002"), Err(ParseNitsError::InvalidDigit));
+///
+/// // Writing to a fixed buffer, without any allocation.
+/// use core::fmt::Write;
+/// struct Buffer { bytes: [u8; 16], len: usize }
+/// impl Write for Buffer {
+///     fn write_str(&mut self, s: &str) -> core::fmt::Result {
+///         let end = self.len + s.len();
+///         self.bytes.get_mut(self.len..end).ok_or(core::fmt::Error)?.copy_from_slice(s.as_bytes());
+///         self.len = end;
+///         Ok(())
+///     }
+/// }
+/// let mut buffer = Buffer { bytes: [0; 16], len: 0 };
+/// // Only seven hexadecimal places fit within the nit capacity of a `u32`, as `16^8` isn't below `2^32`.
+/// write!(buffer, "{}", 0xBEEF_u32.display_nits::<16>()).unwrap();
+/// assert_eq!(&buffer.bytes[..buffer.len], b"000beef");
+/// // So a value with an eighth digit can't be written, rather than losing it.
+/// assert!(write!(buffer, "{}", 0xDEAD_BEEF_u32.display_nits::<16>()).is_err());
+/// ```
+pub trait DisplayNits: Sized {
+	/// Returns a wrapper which formats every base-`BASE` digit within the nit capacity, from the most significant one, using [`NitStyle::DEFAULT`].
+	/// Formatting fails if the value has digits beyond the nit capacity; see [`NitDisplay`].
+	#[must_use]
+	fn display_nits<const BASE: BaseMaximum>(self) -> NitDisplay<'static, Self, BASE>;
+
+	/// Parses base-`BASE` digits, from the most significant one, using [`NitStyle::DEFAULT`].
+	/// There can be fewer digits than the nit capacity, which are the least significant ones.
+	///
+	/// # Errors
+	/// See: [`ParseNitsError`]
+	fn parse_nits<const BASE: BaseMaximum>(s: &str) -> Result<Self, ParseNitsError> {
+		Self::parse_nits_with::<BASE>(s, &NitStyle::DEFAULT)
+	}
+
+	/// Parses base-`BASE` digits, from the most significant one, as described by `style`, ignoring any separators.
+	/// There can be fewer digits than the nit capacity, which are the least significant ones.
+	///
+	/// # Errors
+	/// See: [`ParseNitsError`]
+	fn parse_nits_with<const BASE: BaseMaximum>(s: &str, style: &NitStyle) -> Result<Self, ParseNitsError>;
+}
+
+/// Generates implementations of [`DisplayNits`] and the traits of [`NitDisplay`] for each primitive integer type provided, along with the unsigned type of the same width which it is parsed as.
+macro_rules! impl_display_nits {
+	($(($type: ty, $unsigned: ty)),*) => {
+		$(
+			impl DisplayNits for $type {
+				fn display_nits<const BASE: BaseMaximum>(self) -> NitDisplay<'static, Self, BASE> {
+					NitDisplay { value: self, style: NitStyle::DEFAULT }
+				}
+
+				fn parse_nits_with<const BASE: BaseMaximum>(s: &str, style: &NitStyle) -> Result<Self, ParseNitsError> {
+					#[allow(clippy::cast_possible_truncation)]
+					let capacity = compute_max_nits_in_bits::<BASE, { <$type>::BITS as FitsMaximumBits }>().map_err(ParseNitsError::BadNitLimitEvaluation)?;
+					// The capacity is at least one, so the base fits.
+					#[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+					let base = BASE as $unsigned;
+					let mut value: $unsigned = 0;
+					// There are no more digits than the capacity, so this can't overflow.
+					#[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+					style.read::<BASE>(s, capacity, |nit| value = value * base + nit.get_value() as $unsigned)?;
+					#[allow(clippy::cast_possible_wrap, clippy::cast_possible_truncation)]
+					let value = value as $type;
+					Ok(value)
+				}
+			}
+
+			impl<const BASE: BaseMaximum> fmt::Display for NitDisplay<'_, $type, BASE> {
+				fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+					#[allow(clippy::cast_possible_truncation)]
+					let capacity = compute_max_nits_in_bits::<BASE, { <$type>::BITS as FitsMaximumBits }>().map_err(|_| fmt::Error)?;
+					// The power of the base at the nit capacity is below `2^BITS`, so it fits within a `u128`.
+					#[allow(clippy::cast_sign_loss, clippy::cast_lossless)]
+					if self.value as $unsigned as u128 >= u128::from(BASE).pow(u32::from(capacity)) {
+						return Err(fmt::Error);
+					}
+					self.style.write(f, self.value.iter_nits::<BASE, BASE_MAXIMUM_BYTES>().rev())
+				}
+			}
+
+			impl<const BASE: BaseMaximum> FromStr for NitDisplay<'static, $type, BASE> {
+				type Err = ParseNitsError;
+
+				/// Parses base-`BASE` digits using [`NitStyle::DEFAULT`]; see [`DisplayNits::parse_nits`].
+				fn from_str(s: &str) -> Result<Self, Self::Err> {
+					<$type>::parse_nits::<BASE>(s).map(DisplayNits::display_nits)
+				}
+			}
+		)*
+	};
+}
+impl_display_nits!((u8, u8), (u16, u16), (u32, u32), (u64, u64), (u128, u128), (usize, usize));
+impl_display_nits!((i8, u8), (i16, u16), (i32, u32), (i64, u64), (i128, u128), (isize, usize));
+
+impl<const BASE: BaseMaximum, const BYTES: usize> fmt::Display for Nit<BASE, BYTES> {
+	/// Writes the value of the digit in decimal, as with the integer it is.
+	///
+	/// # Example
+	/// ```
+	/// use nit::{Nit, Trit};
+	/// assert_eq!(Trit::TWO.to_string(), "2");
+	/// assert_eq!(Nit::<16>::new(12).unwrap().to_string(), "12");
+	/// ```
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Display::fmt(&self.get_value(), f)
+	}
+}
+
+impl<const BASE: BaseMaximum, const BYTES: usize> FromStr for Nit<BASE, BYTES> {
+	type Err = ParseNitsError;
+
+	/// Parses the value of the digit in decimal, as with the integer it is.
+	///
+	/// # Errors
+	/// - [`ParseNitsError::Empty`]: If the string is empty.
+	/// - [`ParseNitsError::InvalidDigit`]: If the string isn't a decimal integer within the range of `0..BASE`.
+	///
+	/// # Example
+	/// ```
+	/// use nit::{Nit, Trit};
+	/// use nit::display::ParseNitsError;
+	/// assert_eq!("2".parse(), Ok(Trit::TWO));
+	/// assert_eq!("12".parse(), Ok(Nit::<16>::new(12).unwrap()));
+	/// assert_eq!("3".parse::<Trit>(), Err(ParseNitsError::InvalidDigit));
+	/// assert_eq!("".parse::<Trit>(), Err(ParseNitsError::Empty));
+	/// ```
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		if s.is_empty() {
+			return Err(ParseNitsError::Empty);
+		}
+		let value = s.parse::<BaseMaximum>().map_err(|_| ParseNitsError::InvalidDigit)?;
+		Self::new(value).map_err(|_| ParseNitsError::InvalidDigit)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::missing_docs_in_private_items)]
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+
+	#[test]
+	fn round_trips() {
+		for value in [0, 1, 2, 241, 242_u8] {
+			let text = value.display_nits::<3>().to_string();
+			assert_eq!(text.len(), 5);
+			assert_eq!(u8::parse_nits::<3>(&text), Ok(value));
+			assert_eq!(text.parse::<NitDisplay<u8, 3>>().unwrap().into_inner(), value);
+		}
+		// The nits are those of the bits as a `u64`, which has nineteen decimal places.
+		for value in [0, 1, i64::MAX] {
+			let style = NitStyle::DEFAULT.grouped(3, ',');
+			let text = value.display_nits::<10>().style(style).to_string();
+			assert_eq!(i64::parse_nits_with::<10>(&text, &style), Ok(value));
+		}
+		let binary = (-2_i8).display_nits::<2>().grouped(4, ' ').to_string();
+		assert_eq!(binary, "1111 1110");
+		assert_eq!(i8::parse_nits_with::<2>(&binary, &NitStyle::DEFAULT.grouped(4, ' ')), Ok(-2));
+	}
+
+	#[test]
+	fn large_bases() {
+		// An alphabet with a character for every digit of base-1000, of which three fit within a `u32`.
+		let alphabet: String = (0x100..0x100 + 1000).map(|code| char::from_u32(code).unwrap()).collect();
+		let style = NitStyle::DEFAULT.alphabet(&alphabet);
+		for value in [0, 1, 999, 123_456_789, 999_999_999_u32] {
+			let text = value.display_nits::<1000>().style(style).to_string();
+			assert_eq!(text.chars().count(), 3);
+			assert_eq!(u32::parse_nits_with::<1000>(&text, &style), Ok(value));
+		}
+		assert_eq!(123_456_789_u32.display_nits::<1000>().style(style).to_string(), "\u{17b}\u{2c8}\u{415}");
+		assert!(write!(String::new(), "{}", 1_000_000_000_u32.display_nits::<1000>().style(style)).is_err());
+		// The default alphabet only has the digits of bases up to 62.
+		assert!(write!(String::new(), "{}", 999_u32.display_nits::<1000>()).is_err());
+	}
+
+	#[test]
+	fn errors() {
+		assert_eq!(u8::parse_nits::<3>(""), Err(ParseNitsError::Empty));
+		assert_eq!(u8::parse_nits::<3>("3"), Err(ParseNitsError::InvalidDigit));
+		assert_eq!(u8::parse_nits::<3>("000000"), Err(ParseNitsError::TooManyDigits));
+		assert_eq!(u8::parse_nits::<1>("0"), Err(ParseNitsError::BadNitLimitEvaluation(MaxNitComputationFailure::BaseTooSmall)));
+		let mut text = String::new();
+		// Values with digits beyond the nit capacity, which would have to be truncated to be written.
+		assert!(write!(text, "{}", 243_u8.display_nits::<3>()).is_err());
+		assert!(write!(text, "{}", u64::MAX.display_nits::<10>()).is_err());
+		assert!(write!(text, "{}", (-1_i64).display_nits::<10>()).is_err());
+		assert!(write!(text, "{}", 0_u8.display_nits::<256>()).is_err());
+		assert!(text.is_empty());
+		// The default alphabet has no digits beyond 61.
+		assert!(write!(text, "{}", 99_u8.display_nits::<100>()).is_err());
+		assert!(write!(text, "{}", 99_u8.display_nits::<100>().alphabet("0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~¡¢£¤¥¦§¨©ª«¬®¯°±²³´µ")).is_ok());
+		assert_eq!(text.chars().last(), Some('¦'));
+	}
+}
@@ -0,0 +1,199 @@
+use core::iter::FusedIterator;
+use crate::Nit;
+use crate::base::Powers;
+use crate::data_container::NitDataContainer;
+use crate::max_nits::compute_max_nits_in_bits;
+use crate::places::PlacesIndex;
+use crate::supported::{BaseMaximum, FitsMaximumBits};
+use crate::wide::WideUint;
+
+/// An iterator over the base-`BASE` digits of a container, starting from the least significant digit (right-hand side).
+///
+/// Digits are extracted from the front by repeatedly dividing by `BASE` (through its [`Reciprocal`](crate::reciprocal::Reciprocal)), rather than computing `BASE.pow(n)` for each place.
+/// If the base or bit width is erroneous (see [`MaxNitComputationFailure`](crate::max_nits::MaxNitComputationFailure)), the iterator is empty.
+/// The digits are yielded as [`Nit<BASE, BYTES>`](Nit)s, so bases above 256 need a wider `BYTES`, such as `value.iter_nits::<1000, 2>()`.
+///
+/// # See Also
+/// - [`NitDataContainer::iter_nits`]
+///
+/// # Example
+/// ```
+/// use nit::prelude::*;
+/// use nit::Trit;
+/// let value: u8 = 2 + 1 * 3 + 2 * 81;
+/// let mut iter = value.iter_nits::<3, 1>();
+/// assert_eq!(iter.len(), 5);
+/// assert_eq!(iter.next(), Some(Trit::TWO));
+/// assert_eq!(iter.next_back(), Some(Trit::TWO));
+/// assert_eq!(iter.collect::<Vec<_>>(), [Trit::ONE, Trit::ZERO, Trit::ZERO]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct NitIter<T, const TYPE_BIT_WIDTH: FitsMaximumBits, const BASE: BaseMaximum, const BYTES: usize = 1> {
+	/// The original value, used for extracting digits from the back.
+	value: T,
+	/// The original value divided by `BASE.pow(front)`.
+	remaining: T,
+	/// The place of the next digit yielded from the front.
+	front: FitsMaximumBits,
+	/// One past the place of the next digit yielded from the back.
+	back: FitsMaximumBits,
+}
+
+impl<T: Copy, const TYPE_BIT_WIDTH: FitsMaximumBits, const BASE: BaseMaximum, const BYTES: usize> NitIter<T, TYPE_BIT_WIDTH, BASE, BYTES> {
+	/// Creates an iterator over every base-`BASE` digit of the value.
+	#[must_use]
+	pub const fn new(value: T) -> Self {
+		let back = match compute_max_nits_in_bits::<BASE, TYPE_BIT_WIDTH>() {
+			Ok(max) => max,
+			Err(_) => 0,
+		};
+		Self { value, remaining: value, front: 0, back }
+	}
+}
+
+/// Generates implementations of the iterator traits for [`NitIter`] for each primitive integer type provided.
+macro_rules! impl_nit_iter {
+	($($type: ty),*) => {
+		$(
+			impl<const BASE: BaseMaximum, const BYTES: usize> Iterator for NitIter<$type, { #[allow(clippy::cast_possible_truncation)] { <$type>::BITS as FitsMaximumBits } }, BASE, BYTES> {
+				type Item = Nit<BASE, BYTES>;
+
+				fn next(&mut self) -> Option<Self::Item> {
+					if self.front >= self.back {
+						return None;
+					}
+					// The base is at least two, as the iterator would have been empty otherwise.
+					let reciprocal = Powers::<$type, BASE>::BASE_RECIPROCAL;
+					let quotient = reciprocal.divide(self.remaining);
+					let digit = self.remaining - quotient * reciprocal.divisor();
+					self.remaining = quotient;
+					self.front += 1;
+					// SAFETY: The value will be always within the range of `0..BASE` because it is the remainder of a division by `BASE`.
+					unsafe { #[allow(clippy::cast_possible_truncation, clippy::cast_lossless)] let digit = digit as BaseMaximum; Some(Nit::new_unchecked(digit)) }
+				}
+
+				fn size_hint(&self) -> (usize, Option<usize>) {
+					let len = usize::from(self.back - self.front);
+					(len, Some(len))
+				}
+			}
+
+			impl<const BASE: BaseMaximum, const BYTES: usize> DoubleEndedIterator for NitIter<$type, { #[allow(clippy::cast_possible_truncation)] { <$type>::BITS as FitsMaximumBits } }, BASE, BYTES> {
+				fn next_back(&mut self) -> Option<Self::Item> {
+					if self.front >= self.back {
+						return None;
+					}
+					self.back -= 1;
+					// SAFETY: The place is less than the computed nit capacity for this base and bit width.
+					let index = unsafe { PlacesIndex::new_unchecked(self.back) };
+					Some(self.value.get_nit_indexed(index))
+				}
+			}
+
+			impl<const BASE: BaseMaximum, const BYTES: usize> ExactSizeIterator for NitIter<$type, { #[allow(clippy::cast_possible_truncation)] { <$type>::BITS as FitsMaximumBits } }, BASE, BYTES> {}
+			impl<const BASE: BaseMaximum, const BYTES: usize> FusedIterator for NitIter<$type, { #[allow(clippy::cast_possible_truncation)] { <$type>::BITS as FitsMaximumBits } }, BASE, BYTES> {}
+		)*
+	};
+}
+impl_nit_iter!(u8, u16, u32, u64, u128);
+
+/// Generates implementations of the iterator traits for [`NitIter`] for each primitive integer type provided, delegating to the unsigned type of the same width.
+macro_rules! impl_delegated_nit_iter {
+	($(($type: ty, $unsigned: ty)),*) => {
+		$(
+			impl<const BASE: BaseMaximum, const BYTES: usize> NitIter<$type, { #[allow(clippy::cast_possible_truncation)] { <$type>::BITS as FitsMaximumBits } }, BASE, BYTES> {
+				/// Returns the equivalent iterator over the unsigned type of the same width, which has the same digits.
+				#[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+				const fn to_unsigned(&self) -> NitIter<$unsigned, { #[allow(clippy::cast_possible_truncation)] { <$type>::BITS as FitsMaximumBits } }, BASE, BYTES> {
+					NitIter { value: self.value as $unsigned, remaining: self.remaining as $unsigned, front: self.front, back: self.back }
+				}
+
+				/// Advances to the position of the equivalent iterator over the unsigned type of the same width.
+				#[allow(clippy::cast_possible_wrap, clippy::cast_possible_truncation)]
+				const fn advance_to(&mut self, unsigned: &NitIter<$unsigned, { #[allow(clippy::cast_possible_truncation)] { <$type>::BITS as FitsMaximumBits } }, BASE, BYTES>) {
+					self.remaining = unsigned.remaining as $type;
+					self.front = unsigned.front;
+					self.back = unsigned.back;
+				}
+			}
+
+			impl<const BASE: BaseMaximum, const BYTES: usize> Iterator for NitIter<$type, { #[allow(clippy::cast_possible_truncation)] { <$type>::BITS as FitsMaximumBits } }, BASE, BYTES> {
+				type Item = Nit<BASE, BYTES>;
+
+				fn next(&mut self) -> Option<Self::Item> {
+					let mut unsigned = self.to_unsigned();
+					let next = unsigned.next();
+					self.advance_to(&unsigned);
+					next
+				}
+
+				fn size_hint(&self) -> (usize, Option<usize>) {
+					self.to_unsigned().size_hint()
+				}
+			}
+
+			impl<const BASE: BaseMaximum, const BYTES: usize> DoubleEndedIterator for NitIter<$type, { #[allow(clippy::cast_possible_truncation)] { <$type>::BITS as FitsMaximumBits } }, BASE, BYTES> {
+				fn next_back(&mut self) -> Option<Self::Item> {
+					let mut unsigned = self.to_unsigned();
+					let next = unsigned.next_back();
+					self.advance_to(&unsigned);
+					next
+				}
+			}
+
+			impl<const BASE: BaseMaximum, const BYTES: usize> ExactSizeIterator for NitIter<$type, { #[allow(clippy::cast_possible_truncation)] { <$type>::BITS as FitsMaximumBits } }, BASE, BYTES> {}
+			impl<const BASE: BaseMaximum, const BYTES: usize> FusedIterator for NitIter<$type, { #[allow(clippy::cast_possible_truncation)] { <$type>::BITS as FitsMaximumBits } }, BASE, BYTES> {}
+		)*
+	};
+}
+impl_delegated_nit_iter!((i8, u8), (i16, u16), (i32, u32), (i64, u64), (i128, u128));
+#[cfg(target_pointer_width = "16")]
+impl_delegated_nit_iter!((usize, u16), (isize, u16));
+#[cfg(target_pointer_width = "32")]
+impl_delegated_nit_iter!((usize, u32), (isize, u32));
+#[cfg(target_pointer_width = "64")]
+impl_delegated_nit_iter!((usize, u64), (isize, u64));
+
+/// Generates implementations of the iterator traits for [`NitIter`] for a [`WideUint`] of each amount of limbs provided.
+macro_rules! impl_wide_nit_iter {
+	($($limbs: literal),*) => {
+		$(
+			impl<const BASE: BaseMaximum, const BYTES: usize> Iterator for NitIter<WideUint<$limbs>, { $limbs * 64 }, BASE, BYTES> {
+				type Item = Nit<BASE, BYTES>;
+
+				fn next(&mut self) -> Option<Self::Item> {
+					if self.front >= self.back {
+						return None;
+					}
+					// The base is at least two, as the iterator would have been empty otherwise.
+					let (quotient, digit) = self.remaining.div_rem_nonzero(u64::from(BASE));
+					self.remaining = quotient;
+					self.front += 1;
+					// SAFETY: The value will be always within the range of `0..BASE` because it is the remainder of a division by `BASE`.
+					unsafe { #[allow(clippy::cast_possible_truncation)] let digit = digit as BaseMaximum; Some(Nit::new_unchecked(digit)) }
+				}
+
+				fn size_hint(&self) -> (usize, Option<usize>) {
+					let len = usize::from(self.back - self.front);
+					(len, Some(len))
+				}
+			}
+
+			impl<const BASE: BaseMaximum, const BYTES: usize> DoubleEndedIterator for NitIter<WideUint<$limbs>, { $limbs * 64 }, BASE, BYTES> {
+				fn next_back(&mut self) -> Option<Self::Item> {
+					if self.front >= self.back {
+						return None;
+					}
+					self.back -= 1;
+					// SAFETY: The place is less than the computed nit capacity for this base and bit width.
+					let index = unsafe { PlacesIndex::new_unchecked(self.back) };
+					Some(self.value.get_nit_indexed(index))
+				}
+			}
+
+			impl<const BASE: BaseMaximum, const BYTES: usize> ExactSizeIterator for NitIter<WideUint<$limbs>, { $limbs * 64 }, BASE, BYTES> {}
+			impl<const BASE: BaseMaximum, const BYTES: usize> FusedIterator for NitIter<WideUint<$limbs>, { $limbs * 64 }, BASE, BYTES> {}
+		)*
+	};
+}
+impl_wide_nit_iter!(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
@@ -3,17 +3,21 @@
 //! Nit supplies safe non-binary "bit"-sets abstractions over unsigned integers.
 //!
 //! ## Features
-//! - Zero allocations; all operations can be done in-place.
+//! - Zero allocations; all operations can be done in-place. Growable containers are available behind the `alloc` feature.
 //! - Runnable at compile-time with a nightly compiler.
 //! - Made for `no_std` environments; `std` is used only for optional features[^1].
 //! - No external dependencies; speedy compilation and minimal disk space impact.
 //! - All `unsafe` code is well-documented and localized to internally marking and skipping preconditions; there is no usage of it relating to memory safety.
 //! - Heavily linted and fuzzed for safety and correctness.
 //! - Well documented and tested.
-//! - No panics; all errors are handled with [`Result`]s.
+//! - Fieldless enums can be derived to be stored as nits directly; see [`repr::NitRepr`].
+//! - Structs of differently-based fields can be packed into a single integer; see [`packed`].
+//! - No panics; all errors are handled with [`Result`]s and [`Option`]s.
+//!   The only exception is dividing a [`Nit`] by zero with the `/` operator, which panics like dividing an integer by zero does; [`Nit::checked_div`] doesn't.
 //!
 //! [^1]: The `std` feature may be enabled to enable implementations of [`std::error::Error`] for the error types in this crate,
 //!       or the nightly-only [`error-in-core`](#error-in-core) feature can be enabled to use the [`core::error::Error`] trait instead.
+//!       It also enables the `stream` module, for streaming nits to and from readers and writers.
 //!
 //! [`std::error::Error`]: https://doc.rust-lang.org/std/error/trait.Error.html
 //! [`core::error::Error`]: https://doc.rust-lang.org/nightly/core/error/trait.Error.html
@@ -62,7 +66,28 @@
 //! }
 //! ```
 //!
-//! A more complicated example can be found in [`tests/examples/permission-inheritance.rs`](./tests/examples/permission-inheritance.rs).
+//! Fields of differing bases can also be packed together into a single integer:
+//!
+//! ```
+//! use nit::{Bit, Nit, Trit};
+//!
+//! #[nit::packed(u16)]
+//! #[derive(Debug)]
+//! struct Cell {
+//!     state: Trit,
+//!     owner: Nit<5>,
+//!     flag: Bit,
+//!     level: Nit<7>,
+//! }
+//!
+//! let mut cell = Cell::new(Trit::TWO, Nit::new(4).unwrap(), Bit::ONE, Nit::new(6).unwrap());
+//! assert_eq!(cell.owner(), Nit::new(4).unwrap());
+//! assert_eq!(cell.set_level(Nit::new(3).unwrap()), Nit::new(6).unwrap());
+//! assert_eq!(cell.bits(), 2 + 4 * 3 + 1 * 3 * 5 + 3 * 3 * 5 * 2);
+//! assert_eq!(Cell::MAX_VALUE, 3 * 5 * 2 * 7 - 1);
+//! ```
+//!
+//! A more complicated example can be found in [`examples/permission-inheritance.rs`](./examples/permission-inheritance.rs).
 //!
 
 
@@ -89,10 +114,12 @@
 #![cfg_attr(all(feature = "nightly", feature = "error-in-core", not(feature = "std")), feature(error_in_core))]
 #[cfg(feature = "alloc")] extern crate alloc;
 
-use nit_proc_macros::nit;
+pub use nit_proc_macros::{Nit, packed};
 
 /// The base of a number system.
 pub mod base;
+/// Division by invariant integers using multiplication, used to extract nits without a hardware division.
+pub mod reciprocal;
 /// Internal utility macros.
 mod internal_macros;
 /// Structs representing the placement of a nit.
@@ -110,25 +137,95 @@ pub mod places;
 pub mod supported;
 /// A trait that can be implemented to retrieve the nits in a number.
 pub mod data_container;
-/// Utility function and the potential errors that can occur for computing the maximum amount of nits that can be encoded with a number of bits.
+/// Iterators over the nits of a number.
+pub mod iter;
+/// Fixed-width unsigned integers of many 64-bit limbs, which hold more nits as a single number than any primitive integer type.
+pub mod wide;
+/// Utility functions and the potential errors that can occur for computing the maximum amount of nits that can be encoded with a number of bits, and for planning how to pack them.
 pub mod max_nits;
+/// A fixed-size array of nits, spanning many words.
+pub mod array;
+/// A growable list of nits, spanning many words.
+#[cfg(feature = "alloc")]
+pub mod vec;
+/// A number type where each place has its own base.
+pub mod mixed_radix;
+/// Balanced digits of odd bases, which fall in the range of `-(BASE / 2)..=(BASE / 2)`, and the containers which can be read as them.
+pub mod balanced;
+/// Modular arithmetic on nits, which form the ring of integers modulo `BASE`, or a finite field when `BASE` is prime.
+pub mod arithmetic;
+/// Multi-valued logic on nits, treating each digit as a truth value from false (zero) to true (`BASE - 1`).
+pub mod logic;
+/// Transcoding sequences of nits from one base to another, losslessly.
+pub mod transcode;
+/// Formatting the nits of a value as text, and parsing them back.
+pub mod display;
+/// Encoding bytes as text in any base, with block sizes chosen to pack the most bits into each symbol.
+pub mod codec;
+/// Writing and reading groups of nits as a bitstream over byte buffers, without allocation.
+pub mod bitstream;
+/// Streaming nits to and from [`std::io`] readers and writers, packed into fixed-size words.
+#[cfg(feature = "std")]
+pub mod stream;
+/// A self-describing binary container for persisting packed nits, with a header describing how to read them back.
+pub mod container;
+/// A trait for types which can be represented as a single nit, such as enums deriving [`Nit`](derive@Nit).
+pub mod repr;
 /// Common relevant exports that can be imported with a wildcard.
 pub mod prelude;
 
 
 use internal_macros::{define_empty_error, deriving_const};
-use supported::{BaseMaximum, FitsMaximumBits};
+use supported::{BaseMaximum, BASE_MAXIMUM_BYTES};
 
 define_empty_error!(NitCreationError, "The value is not within the range of 0..BASE.", #[doc = "An error indicating that the value is not within the range of 0..BASE."]);
 deriving_const!((PartialEq) for {
 	/// A base-`BASE` digit of an integer, which falls in the range of `0..BASE`.
-	// TODO: doc better
+	///
+	/// The digit is stored within `BYTES` bytes, one by default, which holds the digits of any base up to 256.
+	/// The digits of greater bases need more of them, such as `Nit<1000, 2>`; a digit which doesn't fit fails to compile.
+	/// Methods which take or return digits take the width alongside the base, such as `value.get_nit::<1000, 2>(0)`.
+	///
+	/// # Example
+	/// ```
+	/// use nit::Nit;
+	/// assert_eq!(size_of::<Nit<3>>(), 1);
+	/// assert_eq!(size_of::<[Nit<3>; 8]>(), 8);
+	/// assert_eq!(Nit::<1000, 2>::new(999).unwrap().get_value(), 999);
+	/// ```
+	///
+	/// ```compile_fail
+	/// use nit::Nit;
+	/// let value = Nit::<1000>::new(999); // The digits of base-1000 don't fit within a byte.
+	/// ```
 	#[repr(transparent)]
-	#[derive(Debug, Clone, Copy, Eq, Hash, PartialOrd, Ord)]
-	pub struct Nit<const BASE: BaseMaximum>(FitsMaximumBits);
+	#[derive(Clone, Copy, Eq, Hash, PartialOrd, Ord)]
+	// The bytes are big-endian, so that comparing them compares the digits.
+	pub struct Nit<const BASE: BaseMaximum, const BYTES: usize = 1>([u8; BYTES]);
 });
 
-impl<const BASE: BaseMaximum> Nit<BASE> {
+impl<const BASE: BaseMaximum, const BYTES: usize> Nit<BASE, BYTES> {
+	/// Fails to compile if a digit of the base doesn't fit within `BYTES` bytes, or if they are wider than a [`BaseMaximum`].
+	const FITS: () = assert!(
+		BYTES > 0 && BYTES <= BASE_MAXIMUM_BYTES && BASE as u64 <= 1 << (8 * BYTES),
+		"The digits of the base don't fit within the bytes of the nit; a wider nit, such as `Nit<BASE, 2>`, is needed."
+	);
+
+	/// Returns a [`Nit`] storing the given value, which must be within the range of `0..BASE`.
+	const fn from_value(mut value: BaseMaximum) -> Self {
+		let () = Self::FITS;
+		let mut bytes = [0; BYTES];
+		let mut i = BYTES;
+		while i > 0 {
+			i -= 1;
+			#[allow(clippy::cast_possible_truncation)]
+			let byte = value as u8;
+			bytes[i] = byte;
+			value >>= 8;
+		}
+		Self(bytes)
+	}
+
 	/// Converts the [`Nit`] into the underlying value.
 	///
 	/// # Example
@@ -138,8 +235,8 @@ impl<const BASE: BaseMaximum> Nit<BASE> {
 	/// assert_eq!(value.into_value(), 6);
 	/// ```
 	#[must_use]
-	pub const fn into_value(self) -> FitsMaximumBits {
-		self.0
+	pub const fn into_value(self) -> BaseMaximum {
+		self.get_value()
 	}
 
 	/// Returns the underlying value; the digit in the relevant base.
@@ -152,8 +249,14 @@ impl<const BASE: BaseMaximum> Nit<BASE> {
 	/// assert_eq!(value.get_value(), 6);
 	/// ```
 	#[must_use]
-	pub const fn get_value(&self) -> FitsMaximumBits {
-		self.0
+	pub const fn get_value(&self) -> BaseMaximum {
+		let mut value: BaseMaximum = 0;
+		let mut i = 0;
+		while i < BYTES {
+			value = value << 8 | self.0[i] as BaseMaximum;
+			i += 1;
+		}
+		value
 	}
 
 	/// Returns a new [`Nit`] with the given value, without checking if it is valid.
@@ -171,8 +274,8 @@ impl<const BASE: BaseMaximum> Nit<BASE> {
 	/// assert_eq!(value.get_value(), 8); // ;c
 	/// ```
 	#[must_use]
-	pub const unsafe fn new_unchecked(value: FitsMaximumBits) -> Self {
-		Self(value)
+	pub const unsafe fn new_unchecked(value: BaseMaximum) -> Self {
+		Self::from_value(value)
 	}
 
 	/// Creates a new [`Nit`] with the given value.
@@ -191,19 +294,37 @@ impl<const BASE: BaseMaximum> Nit<BASE> {
 	/// assert!(value.is_ok()); // 5 is storable in one digit were it in base-8.
 	/// let value = Nit::<5>::new(8);
 	/// assert!(value.is_err()); // 8 is not storable in one digit were it in base-5.
+	/// let value = Nit::<1000, 2>::new(999);
+	/// assert!(value.is_ok()); // Bases aren't limited to what fits within a byte, given enough of them.
 	/// ```
-	pub const fn new(value: FitsMaximumBits) -> Result<Self, NitCreationError> {
+	pub const fn new(value: BaseMaximum) -> Result<Self, NitCreationError> {
 		if value < BASE {
-			Ok(Self(value))
+			Ok(Self::from_value(value))
 		} else {
 			Err(NitCreationError)
 		}
 	}
+
+	/// Returns the same digit, stored within `TO` bytes rather than `BYTES`.
+	/// This fails to compile if the digits of the base don't fit within `TO` bytes.
+	///
+	/// # Example
+	/// ```
+	/// use nit::Nit;
+	/// let value = Nit::<200>::new(199).unwrap();
+	/// assert_eq!(value.resize::<4>().get_value(), 199);
+	/// assert_eq!(size_of_val(&value.resize::<4>()), 4);
+	/// assert_eq!(Nit::<200, 2>::new(199).unwrap().resize(), value);
+	/// ```
+	#[must_use]
+	pub const fn resize<const TO: usize>(self) -> Nit<BASE, TO> {
+		Nit::from_value(self.get_value())
+	}
 }
-impl<const BASE: BaseMaximum> TryFrom<FitsMaximumBits> for Nit<BASE> {
+impl<const BASE: BaseMaximum, const BYTES: usize> TryFrom<BaseMaximum> for Nit<BASE, BYTES> {
 	type Error = NitCreationError;
 
-	/// Attempts to create and return a new [`Nit`] from a [`FitsMaximumBits`] integer primitive.
+	/// Attempts to create and return a new [`Nit`] from a [`BaseMaximum`] integer primitive.
 	///
 	/// # Errors
 	/// - If the value is not within the range of `0..BASE`.
@@ -216,42 +337,69 @@ impl<const BASE: BaseMaximum> TryFrom<FitsMaximumBits> for Nit<BASE> {
 	/// assert!(Nit::<3>::try_from(2).is_ok());
 	/// assert!(Nit::<3>::try_from(3).is_err());
 	/// ```
-	fn try_from(value: FitsMaximumBits) -> Result<Self, Self::Error> {
+	fn try_from(value: BaseMaximum) -> Result<Self, Self::Error> {
 		Self::new(value)
 	}
 }
+impl<const BASE: BaseMaximum, const BYTES: usize> core::fmt::Debug for Nit<BASE, BYTES> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.debug_tuple("Nit").field(&self.get_value()).finish()
+	}
+}
 
 /// A binary [`Nit`] (having a base of `2`).
 pub type Bit = Nit<2>;
 impl Bit {
 	/// A bit of with a value of zero.
-	pub const ZERO: Self = Self(0);
+	pub const ZERO: Self = Self([0]);
 	/// A bit of with a value of one.
-	pub const ONE: Self = Self(1);
+	pub const ONE: Self = Self([1]);
 }
 /// A tertiary [`Nit`] (having a base of `3`).
 pub type Trit = Nit<3>;
 impl Trit {
 	/// A trit of with a value of zero.
-	pub const ZERO: Self = Self(0);
+	pub const ZERO: Self = Self([0]);
 	/// A trit of with a value of one.
-	pub const ONE: Self = Self(1);
+	pub const ONE: Self = Self([1]);
 	/// A trit of with a value of two.
-	pub const TWO: Self = Self(2);
+	pub const TWO: Self = Self([2]);
 }
 
-/// Generates implementations of `From<Nit<BASE>>` for each primitive integer type provided; as all of them are greater than or equal to the maximum of [`FitsMaximumBits`].
+/// Generates implementations of `From<Nit<BASE, BYTES>>` for each primitive integer type provided, for the widths of nits whose digits always fit within it.
+/// The narrower types can instead be attempted to be converted into, with [`TryFrom`].
 macro_rules! impl_numeric_unit_value_wrappers_conversions {
-	(($($into: ty),*)) => {
+	($(($into: ty, $($bytes: literal),*)),*) => {
 		$(
-			impl<const BASE: FitsMaximumBits> From<Nit<BASE>> for $into {
-				fn from(value: Nit<BASE>) -> Self {
-					#[allow(clippy::cast_lossless)]
-					let cast = value.0 as Self;
-					cast
+			$(
+				impl<const BASE: BaseMaximum> From<Nit<BASE, $bytes>> for $into {
+					fn from(value: Nit<BASE, $bytes>) -> Self {
+						#[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+						let cast = value.get_value() as Self;
+						cast
+					}
+				}
+			)*
+		)*
+	};
+}
+impl_numeric_unit_value_wrappers_conversions!((u8, 1), (u16, 1, 2), (u32, 1, 2, 3, 4), (u64, 1, 2, 3, 4), (u128, 1, 2, 3, 4));
+
+/// Generates implementations of `TryFrom<Nit<BASE, BYTES>>` for each primitive integer type provided, for the widths of nits whose digits may not fit within it.
+macro_rules! impl_numeric_unit_value_wrappers_fallible_conversions {
+	($(($into: ty, $($bytes: literal),*)),*) => {
+		$(
+			$(
+				impl<const BASE: BaseMaximum> TryFrom<Nit<BASE, $bytes>> for $into {
+					type Error = core::num::TryFromIntError;
+
+					/// Attempts to convert the value of the digit, which fails if it's greater than the maximum of the type.
+					fn try_from(value: Nit<BASE, $bytes>) -> Result<Self, Self::Error> {
+						Self::try_from(value.get_value())
+					}
 				}
-			}
+			)*
 		)*
 	};
 }
-impl_numeric_unit_value_wrappers_conversions!((u8, u16, u32, u64, u128));
+impl_numeric_unit_value_wrappers_fallible_conversions!((u8, 2, 3, 4), (u16, 3, 4));
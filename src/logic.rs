@@ -0,0 +1,383 @@
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not};
+use crate::{Nit, Trit};
+use crate::array::NitArray;
+use crate::data_container::NitDataContainer;
+use crate::places::PlacesIndex;
+use crate::supported::{BaseMaximum, FitsMaximumBits, BASE_MAXIMUM_BYTES};
+
+impl<const BASE: BaseMaximum, const BYTES: usize> Nit<BASE, BYTES> {
+	/// The largest digit, `BASE - 1`; the truth value of complete truth.
+	const TOP: BaseMaximum = BASE - 1;
+	/// The middle digit, `(BASE - 1) / 2`, rounding down; the truth value of [`Trit::UNKNOWN`] for ternary.
+	const MIDDLE: BaseMaximum = (BASE - 1) / 2;
+
+	/// Returns the conjunction of both truth values; the lesser of both digits.
+	///
+	/// # Example
+	/// ```
+	/// use nit::Trit;
+	/// assert_eq!(Trit::TRUE.and(Trit::UNKNOWN), Trit::UNKNOWN);
+	/// assert_eq!(Trit::FALSE & Trit::UNKNOWN, Trit::FALSE);
+	/// ```
+	#[must_use]
+	pub const fn and(self, rhs: Self) -> Self {
+		if self.get_value() <= rhs.get_value() { self } else { rhs }
+	}
+
+	/// Returns the disjunction of both truth values; the greater of both digits.
+	///
+	/// # Example
+	/// ```
+	/// use nit::Trit;
+	/// assert_eq!(Trit::FALSE.or(Trit::UNKNOWN), Trit::UNKNOWN);
+	/// assert_eq!(Trit::TRUE | Trit::UNKNOWN, Trit::TRUE);
+	/// ```
+	#[must_use]
+	pub const fn or(self, rhs: Self) -> Self {
+		if self.get_value() >= rhs.get_value() { self } else { rhs }
+	}
+
+	/// Returns the negation of the truth value; `BASE - 1 - self`.
+	///
+	/// # Example
+	/// ```
+	/// use nit::Trit;
+	/// assert_eq!(Trit::TRUE.not(), Trit::FALSE);
+	/// assert_eq!(!Trit::UNKNOWN, Trit::UNKNOWN);
+	/// ```
+	// This is also implemented through `Not`, but isn't usable in a const context there.
+	#[allow(clippy::should_implement_trait)]
+	#[must_use]
+	pub const fn not(self) -> Self {
+		Self::from_value(Self::TOP - self.get_value())
+	}
+
+	/// Returns Kleene's implication of the truth values; `!self | rhs`.
+	///
+	/// # Example
+	/// ```
+	/// use nit::Trit;
+	/// assert_eq!(Trit::FALSE.implies(Trit::UNKNOWN), Trit::TRUE);
+	/// assert_eq!(Trit::UNKNOWN.implies(Trit::UNKNOWN), Trit::UNKNOWN);
+	/// ```
+	#[must_use]
+	pub const fn implies(self, rhs: Self) -> Self {
+		self.not().or(rhs)
+	}
+
+	/// Returns Łukasiewicz's implication of the truth values; `min(BASE - 1, BASE - 1 - self + rhs)`.
+	///
+	/// This differs from [Kleene's implication](Self::implies) in that anything implies itself, including the intermediate truth values.
+	///
+	/// # Example
+	/// ```
+	/// use nit::Trit;
+	/// assert_eq!(Trit::UNKNOWN.lukasiewicz_implies(Trit::UNKNOWN), Trit::TRUE);
+	/// assert_eq!(Trit::TRUE.lukasiewicz_implies(Trit::UNKNOWN), Trit::UNKNOWN);
+	/// ```
+	#[must_use]
+	pub const fn lukasiewicz_implies(self, rhs: Self) -> Self {
+		if self.get_value() <= rhs.get_value() {
+			Self::from_value(Self::TOP)
+		} else {
+			// The difference is positive and no greater than `BASE - 1`.
+			Self::from_value(Self::TOP - (self.get_value() - rhs.get_value()))
+		}
+	}
+
+	/// Returns the consensus of the truth values; what both agree upon.
+	///
+	/// If both digits are on the same side of the middle digit, `(BASE - 1) / 2`, this is the one closest to it; otherwise, it is the middle digit.
+	/// For ternary, this is the value of both trits if they are equal, and [`Trit::UNKNOWN`] otherwise.
+	///
+	/// # Example
+	/// ```
+	/// use nit::Trit;
+	/// assert_eq!(Trit::TRUE.consensus(Trit::TRUE), Trit::TRUE);
+	/// assert_eq!(Trit::TRUE.consensus(Trit::FALSE), Trit::UNKNOWN);
+	/// assert_eq!(Trit::FALSE.consensus(Trit::UNKNOWN), Trit::UNKNOWN);
+	/// ```
+	#[must_use]
+	pub const fn consensus(self, rhs: Self) -> Self {
+		if self.get_value() >= Self::MIDDLE && rhs.get_value() >= Self::MIDDLE {
+			self.and(rhs)
+		} else if self.get_value() <= Self::MIDDLE && rhs.get_value() <= Self::MIDDLE {
+			self.or(rhs)
+		} else {
+			Self::from_value(Self::MIDDLE)
+		}
+	}
+}
+
+impl<const BASE: BaseMaximum, const BYTES: usize> BitAnd for Nit<BASE, BYTES> {
+	type Output = Self;
+
+	/// See [`Nit::and`].
+	fn bitand(self, rhs: Self) -> Self {
+		self.and(rhs)
+	}
+}
+impl<const BASE: BaseMaximum, const BYTES: usize> BitAndAssign for Nit<BASE, BYTES> {
+	/// See [`Nit::and`].
+	fn bitand_assign(&mut self, rhs: Self) {
+		*self = self.and(rhs);
+	}
+}
+impl<const BASE: BaseMaximum, const BYTES: usize> BitOr for Nit<BASE, BYTES> {
+	type Output = Self;
+
+	/// See [`Nit::or`].
+	fn bitor(self, rhs: Self) -> Self {
+		self.or(rhs)
+	}
+}
+impl<const BASE: BaseMaximum, const BYTES: usize> BitOrAssign for Nit<BASE, BYTES> {
+	/// See [`Nit::or`].
+	fn bitor_assign(&mut self, rhs: Self) {
+		*self = self.or(rhs);
+	}
+}
+impl<const BASE: BaseMaximum, const BYTES: usize> Not for Nit<BASE, BYTES> {
+	type Output = Self;
+
+	/// See [`Nit::not`].
+	fn not(self) -> Self {
+		Self::not(self)
+	}
+}
+
+impl Trit {
+	/// The truth value of falsity, represented by zero.
+	pub const FALSE: Self = Self([0]);
+	/// The truth value of being unknown, represented by one.
+	pub const UNKNOWN: Self = Self([1]);
+	/// The truth value of truth, represented by two.
+	pub const TRUE: Self = Self([2]);
+}
+
+/// Digit-wise multi-valued logic across every base-`BASE` digit of a packed integer.
+///
+/// Each operator applies the operator of the same name on [`Nit`] to the digits at each place within the nit capacity;
+/// anything beyond the nit capacity is discarded from the result.
+/// [`NitArray`]s have the same operators as inherent methods.
+///
+/// # Example
+/// ```
+/// use nit::logic::NitwiseLogic;
+/// use nit::prelude::*;
+/// use nit::Trit;
+///
+/// let a = u8::from_nits(&[Trit::TRUE, Trit::UNKNOWN, Trit::FALSE]).unwrap();
+/// let b = u8::from_nits(&[Trit::UNKNOWN, Trit::UNKNOWN, Trit::TRUE]).unwrap();
+/// assert_eq!(a.nitwise_and::<3>(b), u8::from_nits(&[Trit::UNKNOWN, Trit::UNKNOWN, Trit::FALSE]).unwrap());
+/// assert_eq!(a.nitwise_or::<3>(b).get_nit::<3, 1>(2), Some(Trit::TRUE));
+/// assert_eq!(a.nitwise_consensus::<3>(b).get_nit::<3, 1>(0), Some(Trit::UNKNOWN));
+/// assert!(a.nitwise_and::<3>(b).dominates::<3>(&b));
+/// assert!(!a.dominates::<3>(&b));
+/// ```
+pub trait NitwiseLogic: Sized {
+	/// Combines the digits at each place of both values with the given function.
+	#[must_use]
+	fn zip_nits<const BASE: BaseMaximum, const BYTES: usize, F: FnMut(Nit<BASE, BYTES>, Nit<BASE, BYTES>) -> Nit<BASE, BYTES>>(&self, rhs: &Self, f: F) -> Self;
+
+	/// Returns whether the given predicate holds for the digits at every place of both values.
+	#[must_use]
+	fn all_nits<const BASE: BaseMaximum, const BYTES: usize, F: FnMut(Nit<BASE, BYTES>, Nit<BASE, BYTES>) -> bool>(&self, rhs: &Self, f: F) -> bool;
+
+	/// Returns the [conjunction](Nit::and) at each place.
+	#[must_use]
+	fn nitwise_and<const BASE: BaseMaximum>(self, rhs: Self) -> Self {
+		self.zip_nits::<BASE, BASE_MAXIMUM_BYTES, _>(&rhs, Nit::and)
+	}
+
+	/// Returns the [disjunction](Nit::or) at each place.
+	#[must_use]
+	fn nitwise_or<const BASE: BaseMaximum>(self, rhs: Self) -> Self {
+		self.zip_nits::<BASE, BASE_MAXIMUM_BYTES, _>(&rhs, Nit::or)
+	}
+
+	/// Returns the [negation](Nit::not) at each place.
+	#[must_use]
+	fn nitwise_not<const BASE: BaseMaximum>(self) -> Self {
+		self.zip_nits::<BASE, BASE_MAXIMUM_BYTES, _>(&self, |a, _| a.not())
+	}
+
+	/// Returns [Kleene's implication](Nit::implies) at each place.
+	#[must_use]
+	fn nitwise_implies<const BASE: BaseMaximum>(self, rhs: Self) -> Self {
+		self.zip_nits::<BASE, BASE_MAXIMUM_BYTES, _>(&rhs, Nit::implies)
+	}
+
+	/// Returns [Łukasiewicz's implication](Nit::lukasiewicz_implies) at each place.
+	#[must_use]
+	fn nitwise_lukasiewicz_implies<const BASE: BaseMaximum>(self, rhs: Self) -> Self {
+		self.zip_nits::<BASE, BASE_MAXIMUM_BYTES, _>(&rhs, Nit::lukasiewicz_implies)
+	}
+
+	/// Returns the [consensus](Nit::consensus) at each place.
+	#[must_use]
+	fn nitwise_consensus<const BASE: BaseMaximum>(self, rhs: Self) -> Self {
+		self.zip_nits::<BASE, BASE_MAXIMUM_BYTES, _>(&rhs, Nit::consensus)
+	}
+
+	/// Returns whether every digit is less than or equal to the digit at the same place of `rhs`.
+	///
+	/// For truth values, this means that `rhs` is at least as true as `self` at each place.
+	#[must_use]
+	fn dominates<const BASE: BaseMaximum>(&self, rhs: &Self) -> bool {
+		self.all_nits::<BASE, BASE_MAXIMUM_BYTES, _>(rhs, |a, b| a <= b)
+	}
+}
+
+/// Generates an implementation of [`NitwiseLogic`] for each primitive integer type provided.
+macro_rules! impl_nitwise_logic {
+	($($type: ty),*) => {
+		$(
+			impl NitwiseLogic for $type {
+				fn zip_nits<const BASE: BaseMaximum, const BYTES: usize, F: FnMut(Nit<BASE, BYTES>, Nit<BASE, BYTES>) -> Nit<BASE, BYTES>>(&self, rhs: &Self, mut f: F) -> Self {
+					#[allow(clippy::cast_possible_truncation)]
+					let places = PlacesIndex::<{ <$type>::BITS as FitsMaximumBits }, BASE>::all();
+					places.map(|n| f(self.get_nit_indexed(n), rhs.get_nit_indexed(n))).collect()
+				}
+
+				fn all_nits<const BASE: BaseMaximum, const BYTES: usize, F: FnMut(Nit<BASE, BYTES>, Nit<BASE, BYTES>) -> bool>(&self, rhs: &Self, mut f: F) -> bool {
+					#[allow(clippy::cast_possible_truncation)]
+					let mut places = PlacesIndex::<{ <$type>::BITS as FitsMaximumBits }, BASE>::all();
+					places.all(|n| f(self.get_nit_indexed(n), rhs.get_nit_indexed(n)))
+				}
+			}
+		)*
+	};
+}
+impl_nitwise_logic!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl<const BASE: BaseMaximum, const N: usize, const WORDS: usize, const BYTES: usize> NitArray<BASE, N, WORDS, BYTES> {
+	/// Combines the digits at each index of both arrays with the given function.
+	#[must_use]
+	pub fn zip_nits<F: FnMut(Nit<BASE, BYTES>, Nit<BASE, BYTES>) -> Nit<BASE, BYTES>>(&self, rhs: &Self, mut f: F) -> Self {
+		let mut result = Self::new();
+		for i in 0..N {
+			if let (Some(a), Some(b)) = (self.get(i), rhs.get(i)) {
+				// The index is within the length of the array, as it is for both operands.
+				let _ = result.set(i, f(a, b));
+			}
+		}
+		result
+	}
+
+	/// Returns whether the given predicate holds for the digits at every index of both arrays.
+	#[must_use]
+	pub fn all_nits<F: FnMut(Nit<BASE, BYTES>, Nit<BASE, BYTES>) -> bool>(&self, rhs: &Self, mut f: F) -> bool {
+		(0..N).all(|i| match (self.get(i), rhs.get(i)) {
+			(Some(a), Some(b)) => f(a, b),
+			_ => true,
+		})
+	}
+
+	/// Returns the [conjunction](Nit::and) at each index.
+	#[must_use]
+	pub fn nitwise_and(&self, rhs: &Self) -> Self {
+		self.zip_nits(rhs, Nit::and)
+	}
+
+	/// Returns the [disjunction](Nit::or) at each index.
+	#[must_use]
+	pub fn nitwise_or(&self, rhs: &Self) -> Self {
+		self.zip_nits(rhs, Nit::or)
+	}
+
+	/// Returns the [negation](Nit::not) at each index.
+	#[must_use]
+	pub fn nitwise_not(&self) -> Self {
+		self.zip_nits(self, |a, _| a.not())
+	}
+
+	/// Returns [Kleene's implication](Nit::implies) at each index.
+	#[must_use]
+	pub fn nitwise_implies(&self, rhs: &Self) -> Self {
+		self.zip_nits(rhs, Nit::implies)
+	}
+
+	/// Returns [Łukasiewicz's implication](Nit::lukasiewicz_implies) at each index.
+	#[must_use]
+	pub fn nitwise_lukasiewicz_implies(&self, rhs: &Self) -> Self {
+		self.zip_nits(rhs, Nit::lukasiewicz_implies)
+	}
+
+	/// Returns the [consensus](Nit::consensus) at each index.
+	#[must_use]
+	pub fn nitwise_consensus(&self, rhs: &Self) -> Self {
+		self.zip_nits(rhs, Nit::consensus)
+	}
+
+	/// Returns whether every digit is less than or equal to the digit at the same index of `rhs`.
+	#[must_use]
+	pub fn dominates(&self, rhs: &Self) -> bool {
+		self.all_nits(rhs, |a, b| a <= b)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::missing_docs_in_private_items)]
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+	use crate::array::words_required;
+
+	const TRITS: [Trit; 3] = [Trit::FALSE, Trit::UNKNOWN, Trit::TRUE];
+
+	#[test]
+	fn kleene_truth_tables() {
+		// Indexed by the left operand, then the right operand.
+		let and = [[0, 0, 0], [0, 1, 1], [0, 1, 2]];
+		let or = [[0, 1, 2], [1, 1, 2], [2, 2, 2]];
+		let implies = [[2, 2, 2], [1, 1, 2], [0, 1, 2]];
+		let lukasiewicz = [[2, 2, 2], [1, 2, 2], [0, 1, 2]];
+		let consensus = [[0, 1, 1], [1, 1, 1], [1, 1, 2]];
+		for (i, a) in TRITS.into_iter().enumerate() {
+			assert_eq!(a.not().get_value(), 2 - a.get_value());
+			for (j, b) in TRITS.into_iter().enumerate() {
+				assert_eq!(a.and(b).get_value(), and[i][j]);
+				assert_eq!(a.or(b).get_value(), or[i][j]);
+				assert_eq!(a.implies(b).get_value(), implies[i][j]);
+				assert_eq!(a.lukasiewicz_implies(b).get_value(), lukasiewicz[i][j]);
+				assert_eq!(a.consensus(b).get_value(), consensus[i][j]);
+			}
+		}
+	}
+
+	#[test]
+	fn arrays() {
+		type Trits = NitArray<3, 50, { words_required::<3>(50) }>;
+		let mut a = Trits::new();
+		let mut b = Trits::new();
+		for i in 0..50 {
+			a.set(i, TRITS[i % 3]).unwrap();
+			b.set(i, TRITS[(i + 1) % 3]).unwrap();
+		}
+		let not = a.nitwise_not();
+		for i in 0..50 {
+			assert_eq!(not.get(i), Some(TRITS[2 - i % 3]));
+			assert_eq!(a.nitwise_or(&b).get(i), Some(TRITS[i % 3] | TRITS[(i + 1) % 3]));
+		}
+		assert!(a.nitwise_and(&b).dominates(&b));
+		assert!(!a.dominates(&b));
+		assert!(Trits::new().dominates(&a));
+		// The places past the length within the last word stay zero.
+		assert_eq!(Trits::from_words(*not.as_words()), Some(not));
+	}
+
+	#[test]
+	fn large_bases() {
+		type Thousand = Nit<1000, 2>;
+		let a = u64::from_nits(&[0, 999, 500, 1, 998, 250].map(|value| Thousand::new(value).unwrap())).unwrap();
+		let b = u64::from_nits(&[999, 0, 499, 2, 998, 750].map(|value| Thousand::new(value).unwrap())).unwrap();
+		assert_eq!(a.zip_nits::<1000, 2, _>(&b, Thousand::or), 750_998_002_500_999_999);
+		assert_eq!(a.nitwise_or::<1000>(b), 750_998_002_500_999_999);
+		assert_eq!(a.nitwise_and::<1000>(b), 250_998_001_499_000_000);
+		assert_eq!(a.nitwise_not::<1000>(), 749_001_998_499_000_999);
+		assert!(a.all_nits::<1000, 2, _>(&a.nitwise_or::<1000>(b), |x: Thousand, y| x <= y));
+		assert!(a.nitwise_and::<1000>(b).dominates::<1000>(&b));
+		assert!(!a.dominates::<1000>(&b));
+	}
+}
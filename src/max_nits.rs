@@ -1,5 +1,5 @@
 use crate::internal_macros::{deriving_const, impl_error};
-use crate::supported::{BaseMaximum, FitsMaximumBits, FitsMaximumBitsAsType, MAXIMUM_SUPPORTED_BITS};
+use crate::supported::{BaseMaximum, FitsMaximumBits, FitsMaximumBitsAsType, MAXIMUM_NATIVE_BITS, MAXIMUM_SUPPORTED_BITS};
 #[cfg(all(test, not(tarpaulin), not(debug_assertions)))] use no_panic::no_panic;
 
 // TODO: These errors should be more clarifying.
@@ -11,6 +11,9 @@ deriving_const!((PartialEq) for {
 		/// No effective data is representable by this, and it can cause computational errors, so it is not supported.
 		BaseTooSmall,
 		/// The base are greater than what is currently supported.
+		///
+		/// This is no longer returned, as any base is supported by the bits that can hold one of its digits; see [`Self::BaseExceedsMaxBitValues`].
+		#[deprecated(note = "Bases are no longer limited, other than by `BaseExceedsMaxBitValues`; this is never returned.")]
 		BaseTooLarge,
 		/// The bits are zero.
 		/// No effective data is representable by this, and it can cause computational errors, so it is not supported.
@@ -19,6 +22,8 @@ deriving_const!((PartialEq) for {
 		BitsTooLarge,
 		/// The amount of bits can't store enough values to represent at least one base digit.
 		BaseExceedsMaxBitValues,
+		/// The amount of bits can't store enough values to represent every combination of the places' digits.
+		PlacesExceedMaxBitValues,
 	}
 });
 impl MaxNitComputationFailure {
@@ -26,12 +31,14 @@ impl MaxNitComputationFailure {
 	#[must_use]
 	#[cfg(not(tarpaulin_include))]
 	pub const fn get_str(&self) -> &str {
+		#[allow(deprecated)]
 		match self {
 			Self::BaseTooSmall => "The base is less than or equal to 1.",
 			Self::BaseTooLarge => "The base is greater than what is currently supported.",
 			Self::BitsTooSmall => "The bits are zero.",
 			Self::BitsTooLarge => "The bits are greater than what is currently supported.",
 			Self::BaseExceedsMaxBitValues => "The amount of bits can't store enough values to represent at least one base digit.",
+			Self::PlacesExceedMaxBitValues => "The amount of bits can't store enough values to represent every combination of the places' digits.",
 		}
 	}
 }
@@ -50,27 +57,564 @@ impl_error!(MaxNitComputationFailure);
 /// # Errors
 /// See: [`MaxNitComputationFailure`]
 /// - If the base is less than or equal to 1;
-/// - If the base is greater than what is currently supported;
+/// - If the base isn't less than `2^bits`;
 /// - If the bits are zero.
 /// - If the bits are greater than what is currently supported.
 // Since this is a compile-time function, there isn't any issue in using `u128`, which might otherwise have performance implications.
 #[cfg_attr(all(test, not(tarpaulin), not(debug_assertions), feature = "nightly"), no_panic)]
 pub const fn compute_max_nits_in_bits<const BASE: BaseMaximum, const BITS: FitsMaximumBits>() -> Result<FitsMaximumBits, MaxNitComputationFailure>  {
-	if BITS < 1 { return Err(MaxNitComputationFailure::BitsTooSmall) };
-	if BITS > MAXIMUM_SUPPORTED_BITS { return Err(MaxNitComputationFailure::BitsTooLarge) };
-	if BASE <= 1 { return Err(MaxNitComputationFailure::BaseTooSmall) };
-	if BASE == 2 { return Ok(BITS) };
-	if BASE > MAXIMUM_SUPPORTED_BITS { return Err(MaxNitComputationFailure::BaseTooLarge)}
+	// Evaluated at compile-time, as the powers of wide containers take a while to count.
+	const { compute_max_nits(BASE, BITS) }
+}
+
+/// Computes the maximum amount of base-`base` digits that can be stored in a number with `bits` bits.
+///
+/// See: [`compute_max_nits_in_bits`]
+#[allow(clippy::unnecessary_semicolon)]
+const fn compute_max_nits(base: BaseMaximum, bits: FitsMaximumBits) -> Result<FitsMaximumBits, MaxNitComputationFailure> {
+	if bits < 1 { return Err(MaxNitComputationFailure::BitsTooSmall) };
+	if bits > MAXIMUM_SUPPORTED_BITS { return Err(MaxNitComputationFailure::BitsTooLarge) };
+	if base <= 1 { return Err(MaxNitComputationFailure::BaseTooSmall) };
+	if base == 2 { return Ok(bits) };
+	if bits > MAXIMUM_NATIVE_BITS { return Ok(compute_max_wide_nits(base, bits)) }
 	#[allow(clippy::cast_lossless)]
-	let max = if BITS == MAXIMUM_SUPPORTED_BITS { FitsMaximumBitsAsType::MAX } else {
-		(1_u128.wrapping_shl(BITS as u32)) - 1
+	let max = if bits == MAXIMUM_NATIVE_BITS { FitsMaximumBitsAsType::MAX } else {
+		(1_u128.wrapping_shl(bits as u32)) - 1
 	};
-	if max < BASE as u128 - 1 {
+	// A base of exactly `2^bits` would be given a capacity of zero, so it is rejected along with the bases that exceed it.
+	if max < base as u128 {
 		return Err(MaxNitComputationFailure::BaseExceedsMaxBitValues)
 	}
 	#[allow(clippy::cast_possible_truncation)]
-	let log = max.ilog(BASE as FitsMaximumBitsAsType);
+	let log = max.ilog(base as FitsMaximumBitsAsType);
 	#[allow(clippy::cast_possible_truncation)]
 	let log = log as FitsMaximumBits;
 	Ok(log)
 }
+
+/// Counts the base-`base` digits that can be stored in a number with more bits than any native integer type; the largest `k` where `base^k < 2^bits`.
+///
+/// The powers are computed on as many limbs as the widest container has, until one no longer fits within the bits.
+const fn compute_max_wide_nits(base: BaseMaximum, bits: FitsMaximumBits) -> FitsMaximumBits {
+	let mut power = [0_u64; MAXIMUM_SUPPORTED_BITS as usize / 64];
+	power[0] = 1;
+	let mut len = 1;
+	let mut digits = 0;
+	loop {
+		let mut carry = 0;
+		let mut i = 0;
+		while i < len {
+			#[allow(clippy::cast_lossless)]
+			let product = power[i] as u128 * base as u128 + carry as u128;
+			#[allow(clippy::cast_possible_truncation)]
+			let (low, high) = (product as u64, (product >> 64) as u64);
+			(power[i], carry) = (low, high);
+			i += 1;
+		}
+		if carry != 0 {
+			if len == power.len() { return digits }
+			power[len] = carry;
+			len += 1;
+		}
+		#[allow(clippy::cast_possible_truncation)]
+		let used = (len as u32 - 1) * 64 + (u64::BITS - power[len - 1].leading_zeros());
+		if used > bits as u32 { return digits }
+		digits += 1;
+	}
+}
+
+/// Computes the largest value representable by a mixed-radix number, where the `i`th place has a base of `bases[i]`, checking that it can be stored in a number with `BITS` bits.
+///
+/// This is the product of the bases, minus one:
+///
+/// $${max} = \left(\prod_{i=0}^{k} b\_i\right) - 1$$
+///
+/// # Errors
+/// See: [`MaxNitComputationFailure`]
+/// - If any base is less than or equal to 1;
+/// - If the bits are zero.
+/// - If the bits are greater than those of a [`FitsMaximumBitsAsType`].
+/// - If the largest value can't be stored within the bits.
+///
+/// # Example
+/// ```
+/// use nit::max_nits::{compute_mixed_radix_max_value, MaxNitComputationFailure};
+/// // A trit, a quint, a bit and a septit; 3 * 5 * 2 * 7 = 210 values.
+/// assert_eq!(compute_mixed_radix_max_value::<8>(&[3, 5, 2, 7]), Ok(209));
+/// assert_eq!(compute_mixed_radix_max_value::<7>(&[3, 5, 2, 7]), Err(MaxNitComputationFailure::PlacesExceedMaxBitValues));
+/// ```
+#[cfg_attr(all(test, not(tarpaulin), not(debug_assertions), feature = "nightly"), no_panic)]
+#[allow(clippy::unnecessary_semicolon)]
+pub const fn compute_mixed_radix_max_value<const BITS: FitsMaximumBits>(bases: &[BaseMaximum]) -> Result<FitsMaximumBitsAsType, MaxNitComputationFailure> {
+	if BITS < 1 { return Err(MaxNitComputationFailure::BitsTooSmall) };
+	if BITS > MAXIMUM_NATIVE_BITS { return Err(MaxNitComputationFailure::BitsTooLarge) };
+	#[allow(clippy::cast_lossless)]
+	let bits_max = if BITS == MAXIMUM_NATIVE_BITS { FitsMaximumBitsAsType::MAX } else {
+		(1_u128.wrapping_shl(BITS as u32)) - 1
+	};
+	let mut max: FitsMaximumBitsAsType = 0;
+	let mut i = 0;
+	while i < bases.len() {
+		let base = bases[i];
+		if base <= 1 { return Err(MaxNitComputationFailure::BaseTooSmall) };
+		// Appending a place as the most significant digit: `(max + 1) * base - 1`, rearranged to avoid overflowing on the upper bound.
+		#[allow(clippy::cast_lossless)]
+		let base = base as FitsMaximumBitsAsType;
+		max = match max.checked_mul(base) {
+			Some(product) => match product.checked_add(base - 1) {
+				Some(sum) => sum,
+				None => return Err(MaxNitComputationFailure::PlacesExceedMaxBitValues),
+			},
+			None => return Err(MaxNitComputationFailure::PlacesExceedMaxBitValues),
+		};
+		if max > bits_max { return Err(MaxNitComputationFailure::PlacesExceedMaxBitValues) };
+		i += 1;
+	}
+	Ok(max)
+}
+
+/// Bounds a power of a base from one side, as `mantissa * 2^exponent`, with the most significant bit of the mantissa set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PowerBound {
+	/// The leading bits of the power.
+	mantissa: u64,
+	/// The power of two that the mantissa is scaled by.
+	exponent: i64,
+}
+
+impl PowerBound {
+	/// The bound of one, which is exact.
+	const ONE: Self = Self { mantissa: 1 << 63, exponent: -63 };
+
+	/// Returns the bound of a base, which is exact.
+	#[allow(clippy::cast_lossless)]
+	const fn of(base: BaseMaximum) -> Self {
+		let log = base.ilog2();
+		Self { mantissa: (base as u64) << (63 - log), exponent: log as i64 - 63 }
+	}
+
+	/// Multiplies two bounds, rounding the product down if bounding from below, or up if bounding from above.
+	#[allow(clippy::cast_lossless, clippy::cast_possible_truncation)]
+	const fn mul(self, rhs: Self, up: bool) -> Self {
+		// Both mantissas are at least `2^63`, so the product is at least `2^126`, and the shift leaves 64 significant bits.
+		let product = self.mantissa as u128 * rhs.mantissa as u128;
+		let shift = if product >> 127 == 1 { 64 } else { 63 };
+		let mut mantissa = (product >> shift) as u64;
+		let mut exponent = self.exponent + rhs.exponent + shift as i64;
+		if up && product & ((1 << shift) - 1) != 0 {
+			if let Some(next) = mantissa.checked_add(1) {
+				mantissa = next;
+			} else {
+				exponent += 1;
+				mantissa = 1 << 63;
+			}
+		}
+		Self { mantissa, exponent }
+	}
+
+	/// Returns $\left\lfloor\log\_2 x\right\rfloor$ of the bound.
+	const fn floor_log2(self) -> i64 {
+		self.exponent + 63
+	}
+
+	/// Returns the bounds of `base.pow(n)` from below and from above, by squaring and multiplying.
+	const fn pow(base: BaseMaximum, n: u64) -> (Self, Self) {
+		let (mut low, mut high) = (Self::ONE, Self::ONE);
+		let (mut base_low, mut base_high) = (Self::of(base), Self::of(base));
+		let mut exponent = n;
+		while exponent > 0 {
+			if exponent & 1 == 1 {
+				low = low.mul(base_low, false);
+				high = high.mul(base_high, true);
+			}
+			exponent >>= 1;
+			if exponent > 0 {
+				base_low = base_low.mul(base_low, false);
+				base_high = base_high.mul(base_high, true);
+			}
+		}
+		(low, high)
+	}
+
+	/// Returns whether the bound is no less than another.
+	const fn at_least(self, rhs: Self) -> bool {
+		self.exponent > rhs.exponent || (self.exponent == rhs.exponent && self.mantissa >= rhs.mantissa)
+	}
+}
+
+/// Computes the fewest bits that can hold any `n` base-`BASE` digits together, when they're packed as a single number.
+///
+/// $${bits} = \left\lceil n \log\_2 {base} \right\rceil$$
+///
+/// This is exact where the base is a power of two. Otherwise, it's computed from bounds on $base^n$ which are within a relative error of about $n \cdot 2^{-63}$;
+/// were $base^n$ ever that close to a power of two, the bits would be overestimated by one, but never underestimated.
+///
+/// This is [`compute_nits_required`] into base 2.
+///
+/// # Errors
+/// See: [`MaxNitComputationFailure`]
+/// - If the base is less than or equal to 1;
+/// - If the bits wouldn't fit within a `u64`, or if there are more than $2^{61} / (\lfloor\log\_2 {base}\rfloor + 1)$ digits of a base other than a power of two, whose power is then too large to be bounded.
+///
+/// # Example
+/// ```
+/// use nit::max_nits::compute_bits_required;
+/// assert_eq!(compute_bits_required::<3>(5), Ok(8));
+/// assert_eq!(compute_bits_required::<3>(1000), Ok(1585));
+/// assert_eq!(compute_bits_required::<10>(1_000_000), Ok(3_321_929));
+/// assert_eq!(compute_bits_required::<16>(7), Ok(28));
+/// ```
+pub const fn compute_bits_required<const BASE: BaseMaximum>(n: u64) -> Result<u64, MaxNitComputationFailure> {
+	compute_nits_required::<BASE, 2>(n)
+}
+
+/// Computes the fewest base-`TO` digits that can hold any `n` base-`FROM` digits, when each of them are a single number.
+///
+/// $${nits} = \left\lceil n \log\_{to} {from} \right\rceil$$
+///
+/// This is exact where both bases are powers of a common base, such as 4 and 8.
+/// Otherwise, it's computed from bounds on the powers of both bases, as [`compute_bits_required`] is;
+/// were $from^n$ ever that close to a power of `TO`, the digits would be overestimated by one, but never underestimated.
+///
+/// # Errors
+/// See: [`MaxNitComputationFailure`]
+/// - If either base is less than or equal to 1;
+/// - If the base-`TO` digits wouldn't fit within a `u64`, or if there are more than $2^{61} / (\lfloor\log\_2 {from}\rfloor + 1)$ base-`FROM` digits
+///   where the bases aren't powers of a common base, whose power is then too large to be bounded.
+///
+/// # Example
+/// ```
+/// use nit::max_nits::compute_nits_required;
+/// assert_eq!(compute_nits_required::<3, 2>(5), Ok(8));
+/// assert_eq!(compute_nits_required::<3, 2>(1000), Ok(1585));
+/// // `9.pow(3)` is `27.pow(2)`.
+/// assert_eq!(compute_nits_required::<9, 27>(3), Ok(2));
+/// assert_eq!(compute_nits_required::<10, 256>(1_000_000), Ok(415_242));
+/// ```
+#[allow(clippy::cast_lossless, clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub const fn compute_nits_required<const FROM: BaseMaximum, const TO: BaseMaximum>(n: u64) -> Result<u64, MaxNitComputationFailure> {
+	if FROM <= 1 || TO <= 1 { return Err(MaxNitComputationFailure::BaseTooSmall) }
+	if n == 0 { return Ok(0) }
+	if let Some(root) = common_root(FROM, TO) {
+		// `FROM.pow(n)` is `root.pow(n * from)`, which is no greater than `root.pow(m * to)` exactly where `m * to` is at least `n * from`.
+		let (from, to) = (FROM.ilog(root) as u128, TO.ilog(root) as u128);
+		let nits = (n as u128 * from).div_ceil(to);
+		return if nits <= u64::MAX as u128 { Ok(nits as u64) } else { Err(MaxNitComputationFailure::BitsTooLarge) }
+	}
+	// Each digit needs no more than `FROM.ilog2() + 1` bits, and squaring the powers of either base up to the `n`th power of `FROM` at most doubles that,
+	// so this keeps the exponents of their bounds well within an `i64`.
+	if n > (i64::MAX as u64 >> 2) / (FROM.ilog2() as u64 + 1) { return Err(MaxNitComputationFailure::BitsTooLarge) }
+	let (_, bound) = PowerBound::pow(FROM, n);
+	// `TO.pow(high)` is at least `2.pow(high * TO.ilog2())`, which is greater than the bound, even from below.
+	let (mut low, mut high) = (0, bound.floor_log2() as u64 / TO.ilog2() as u64 + 1);
+	while high - low > 1 {
+		let middle = low + (high - low) / 2;
+		if PowerBound::pow(TO, middle).0.at_least(bound) { high = middle } else { low = middle }
+	}
+	Ok(high)
+}
+
+/// Returns the base which both bases are powers of, if there is one, in the same way as Euclid's algorithm finds the greatest common divisor of their logarithms.
+const fn common_root(a: BaseMaximum, b: BaseMaximum) -> Option<BaseMaximum> {
+	let (mut a, mut b) = (a, b);
+	while a != b {
+		if a > b { (a, b) = (b, a) }
+		if !b.is_multiple_of(a) { return None }
+		b /= a;
+	}
+	Some(a)
+}
+
+/// The bit widths of the native unsigned integers, from [`u8`] to [`u128`].
+pub const NATIVE_WORD_BITS: [FitsMaximumBits; 5] = [8, 16, 32, 64, 128];
+
+/// A layout of base-`base` digits packed into words of a bit width, as the streams of this crate pack them.
+///
+/// # Example
+/// ```
+/// use nit::max_nits::plan_native_packings;
+/// let [u8, _, _, u64, _] = plan_native_packings::<3>().unwrap();
+/// // Five trits within a `u8` leave 13 of its 256 states unused.
+/// assert_eq!((u8.nits_per_word, u8.wasted_states), (5, 13));
+/// assert_eq!((u64.nits_per_word, u64.wasted_states), (40, (1 << 64) - 3_u128.pow(40)));
+/// // Both spend 1.6 bits on each trit, but the `u64`s round a hundred trits up to more bits.
+/// assert_eq!(u8.bits_per_nit(), u64.bits_per_nit());
+/// assert!((u8.efficiency() - 0.9906).abs() < 0.0001);
+/// assert_eq!(u8.bits_for(100), 160);
+/// assert_eq!(u64.bits_for(100), 192);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PackingPlan {
+	/// The base of the digits.
+	pub base: BaseMaximum,
+	/// The bit width of each word.
+	pub word_bits: FitsMaximumBits,
+	/// The amount of digits within each word, the most whose states fit within those of the word, $base^{nits} \le 2^{bits}$.
+	///
+	/// This is one more than [`compute_max_nits_in_bits`] where a power of the base is exactly $2^{bits}$, as every state of the word is then the value of its digits.
+	pub nits_per_word: FitsMaximumBits,
+	/// The amount of states of each word which aren't the value of any of its digits, $2^{bits} - base^{nits}$.
+	pub wasted_states: FitsMaximumBitsAsType,
+}
+
+impl PackingPlan {
+	/// Returns the amount of words needed to hold `n` digits.
+	#[must_use]
+	pub const fn words_for(&self, n: u64) -> u64 {
+		n.div_ceil(self.nits_per_word as u64)
+	}
+
+	/// Returns the amount of bits needed to hold `n` digits, which is a whole amount of words.
+	#[must_use]
+	pub const fn bits_for(&self, n: u64) -> u64 {
+		self.words_for(n) * self.word_bits as u64
+	}
+
+	/// Returns the bits spent on each digit.
+	#[must_use]
+	pub const fn bits_per_nit(&self) -> f64 {
+		self.word_bits as f64 / self.nits_per_word as f64
+	}
+
+	/// Returns the ratio of the information within each word to its bits, from zero to one.
+	#[must_use]
+	pub const fn efficiency(&self) -> f64 {
+		log2(self.base) / self.bits_per_nit()
+	}
+}
+
+/// Plans the packing of base-`BASE` digits into words of any bit width.
+///
+/// # Errors
+/// See: [`MaxNitComputationFailure`]
+/// - See: [`compute_max_nits_in_bits`]
+/// - If a word can't hold a single digit.
+/// - If the words are wider than [`MAXIMUM_NATIVE_BITS`](crate::supported::MAXIMUM_NATIVE_BITS).
+///
+/// # Example
+/// ```
+/// use nit::max_nits::{plan_packing, MaxNitComputationFailure};
+/// let plan = plan_packing::<3>(27).unwrap();
+/// assert_eq!((plan.nits_per_word, plan.wasted_states), (17, (1 << 27) - 3_u128.pow(17)));
+/// // Every state of the word is the value of its digits, as `4^4 = 2^8`.
+/// let plan = plan_packing::<4>(8).unwrap();
+/// assert_eq!((plan.nits_per_word, plan.wasted_states, plan.efficiency()), (4, 0, 1.0));
+/// assert_eq!(plan_packing::<16>(64).unwrap().nits_per_word, 16);
+/// assert_eq!(plan_packing::<3>(1), Err(MaxNitComputationFailure::BaseExceedsMaxBitValues));
+/// ```
+pub const fn plan_packing<const BASE: BaseMaximum>(word_bits: FitsMaximumBits) -> Result<PackingPlan, MaxNitComputationFailure> {
+	// The wasted states of a wider word wouldn't fit.
+	if word_bits > MAXIMUM_NATIVE_BITS { return Err(MaxNitComputationFailure::BitsTooLarge) }
+	// A power of two base fills the words whose bits are a multiple of its own, where the nit capacity stops a digit short of `BASE^k = 2^bits`.
+	#[allow(clippy::cast_possible_truncation)]
+	let exact_bits = BASE.trailing_zeros() as FitsMaximumBits;
+	let nits_per_word = if BASE > 1 && BASE.is_power_of_two() && word_bits > 0 && word_bits.is_multiple_of(exact_bits) {
+		word_bits / exact_bits
+	} else {
+		match compute_max_nits(BASE, word_bits) {
+			Ok(0) => return Err(MaxNitComputationFailure::BaseExceedsMaxBitValues),
+			Ok(nits) => nits,
+			Err(err) => return Err(err),
+		}
+	};
+	#[allow(clippy::cast_lossless)]
+	let wasted_states = match (BASE as FitsMaximumBitsAsType).checked_pow(nits_per_word as u32) {
+		// Only a word of every bit, whose states are exactly those of its digits, has no wasted states while they don't fit.
+		None => 0,
+		Some(states) if word_bits == MAXIMUM_NATIVE_BITS => FitsMaximumBitsAsType::MAX - (states - 1),
+		Some(states) => (1 << word_bits) - states,
+	};
+	Ok(PackingPlan { base: BASE, word_bits, nits_per_word, wasted_states })
+}
+
+/// Plans the packing of base-`BASE` digits into each of the [native widths](NATIVE_WORD_BITS).
+///
+/// # Errors
+/// See: [`plan_packing`]
+pub const fn plan_native_packings<const BASE: BaseMaximum>() -> Result<[PackingPlan; 5], MaxNitComputationFailure> {
+	let mut plans = [PackingPlan { base: BASE, word_bits: 0, nits_per_word: 0, wasted_states: 0 }; 5];
+	let mut i = 0;
+	while i < NATIVE_WORD_BITS.len() {
+		plans[i] = match plan_packing::<BASE>(NATIVE_WORD_BITS[i]) {
+			Ok(plan) => plan,
+			Err(err) => return Err(err),
+		};
+		i += 1;
+	}
+	Ok(plans)
+}
+
+/// Chooses the packing of `n` base-`BASE` digits into words of one of the bit widths which needs the fewest bits, preferring the earlier widths.
+///
+/// Widths which can't hold a single digit are skipped.
+///
+/// # Errors
+/// See: [`plan_packing`]; the error of the last width is returned if none of them can be planned.
+///
+/// # Example
+/// ```
+/// use nit::max_nits::{choose_packing, NATIVE_WORD_BITS};
+/// // A hundred trits need 160 bits as `u8`s, but 192 as `u64`s.
+/// assert_eq!(choose_packing::<3>(100, &NATIVE_WORD_BITS).unwrap().word_bits, 8);
+/// // A hundred and sixty need 256 bits as either.
+/// assert_eq!(choose_packing::<3>(160, &[64, 8]).unwrap().word_bits, 64);
+/// // The fewest bits of all, spending 27 on each group of 17.
+/// assert_eq!(choose_packing::<3>(1000, &[8, 27, 64]).unwrap().word_bits, 27);
+/// ```
+pub const fn choose_packing<const BASE: BaseMaximum>(n: u64, word_bits: &[FitsMaximumBits]) -> Result<PackingPlan, MaxNitComputationFailure> {
+	let mut best: Result<PackingPlan, MaxNitComputationFailure> = Err(MaxNitComputationFailure::BitsTooSmall);
+	let mut i = 0;
+	while i < word_bits.len() {
+		match (plan_packing::<BASE>(word_bits[i]), best) {
+			(Ok(plan), Ok(current)) if plan.bits_for(n) >= current.bits_for(n) => {},
+			(Ok(plan), _) => best = Ok(plan),
+			(Err(err), Err(_)) => best = Err(err),
+			(Err(_), Ok(_)) => {},
+		}
+		i += 1;
+	}
+	best
+}
+
+/// Returns $\log\_2 {base}$, without the standard library.
+#[allow(clippy::cast_lossless)] // `f64::from` isn't usable in a `const` context.
+pub(crate) const fn log2(base: BaseMaximum) -> f64 {
+	let integer = base.ilog2();
+	// The mantissa is within `1..2`; each squaring doubles its logarithm, so whether it reaches two is the next bit of the fraction.
+	let mut mantissa = base as f64 / (1_u32 << integer) as f64;
+	let mut fraction = 0.0;
+	let mut bit = 0.5;
+	let mut i = 1;
+	while i < f64::MANTISSA_DIGITS {
+		mantissa *= mantissa;
+		if mantissa >= 2.0 {
+			mantissa /= 2.0;
+			fraction += bit;
+		}
+		bit /= 2.0;
+		i += 1;
+	}
+	integer as f64 + fraction
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::missing_docs_in_private_items)]
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+
+	fn check_bits_required<const BASE: BaseMaximum>() {
+		// Where the power fits within a `u128`, the bits are those of its largest value.
+		let capacity = compute_max_nits_in_bits::<BASE, 128>().unwrap();
+		for n in 1..=capacity {
+			let max = FitsMaximumBitsAsType::from(BASE).checked_pow(u32::from(n)).map_or(FitsMaximumBitsAsType::MAX, |power| power - 1);
+			assert_eq!(compute_bits_required::<BASE>(u64::from(n)), Ok(u64::from(max.ilog2() + 1)), "{n} digits in base {BASE}");
+		}
+		// Past it, the bits of `n` digits are between those of its parts.
+		for n in [1000, 12_345, 1 << 20, 1 << 40] {
+			let bits = compute_bits_required::<BASE>(n).unwrap();
+			let (a, b) = (compute_bits_required::<BASE>(n / 2).unwrap(), compute_bits_required::<BASE>(n - n / 2).unwrap());
+			assert!(a + b - 1 <= bits && bits <= a + b, "{n} digits in base {BASE}");
+		}
+	}
+
+	#[test]
+	fn bits_required() {
+		check_bits_required::<2>();
+		check_bits_required::<3>();
+		check_bits_required::<4>();
+		check_bits_required::<10>();
+		check_bits_required::<85>();
+		check_bits_required::<127>();
+		assert_eq!(compute_bits_required::<3>(0), Ok(0));
+		assert_eq!(compute_bits_required::<2>(u64::MAX >> 3), Ok(u64::MAX >> 3));
+		assert_eq!(compute_bits_required::<3>(u64::MAX), Err(MaxNitComputationFailure::BitsTooLarge));
+		assert_eq!(compute_bits_required::<4>(u64::MAX >> 1), Ok(u64::MAX - 1));
+		assert_eq!(compute_bits_required::<4>(u64::MAX), Err(MaxNitComputationFailure::BitsTooLarge));
+		assert_eq!(compute_bits_required::<1>(1), Err(MaxNitComputationFailure::BaseTooSmall));
+		assert!((log2(3) - 1.584_962_500_721_156).abs() < 1e-12);
+	}
+
+	fn check_nits_required<const FROM: BaseMaximum, const TO: BaseMaximum>() {
+		// Where the power fits within a `u128`, the digits are those of its largest value.
+		let capacity = compute_max_nits_in_bits::<FROM, 128>().unwrap();
+		for n in 1..=capacity {
+			let max = FitsMaximumBitsAsType::from(FROM).checked_pow(u32::from(n)).map_or(FitsMaximumBitsAsType::MAX, |power| power - 1);
+			let expected = max.checked_ilog(FitsMaximumBitsAsType::from(TO)).map_or(1, |log| log + 1);
+			assert_eq!(compute_nits_required::<FROM, TO>(u64::from(n)), Ok(u64::from(expected)), "{n} digits from base {FROM} to {TO}");
+		}
+		// Past it, the digits of `n` digits are between those of its parts.
+		for n in [1000, 12_345, 1 << 20, 1 << 40] {
+			let nits = compute_nits_required::<FROM, TO>(n).unwrap();
+			let (a, b) = (compute_nits_required::<FROM, TO>(n / 2).unwrap(), compute_nits_required::<FROM, TO>(n - n / 2).unwrap());
+			assert!(a + b - 1 <= nits && nits <= a + b, "{n} digits from base {FROM} to {TO}");
+		}
+	}
+
+	#[test]
+	fn nits_required() {
+		check_nits_required::<3, 2>();
+		check_nits_required::<2, 3>();
+		check_nits_required::<10, 7>();
+		check_nits_required::<128, 3>();
+		check_nits_required::<3, 128>();
+		check_nits_required::<4, 8>();
+		check_nits_required::<9, 27>();
+		check_nits_required::<5, 5>();
+		check_nits_required::<60000, 3>();
+		assert_eq!(compute_nits_required::<4, 8>(3 << 40), Ok(2 << 40));
+		assert_eq!(compute_nits_required::<3, 2>(1 << 40), compute_bits_required::<3>(1 << 40));
+		assert_eq!(compute_nits_required::<3, 2>(0), Ok(0));
+		assert_eq!(compute_nits_required::<3, 2>(u64::MAX), Err(MaxNitComputationFailure::BitsTooLarge));
+		assert_eq!(compute_nits_required::<60000, 2>(u64::MAX >> 6), Err(MaxNitComputationFailure::BitsTooLarge));
+		assert_eq!(compute_nits_required::<3, 1>(1), Err(MaxNitComputationFailure::BaseTooSmall));
+		assert_eq!(compute_nits_required::<0, 3>(1), Err(MaxNitComputationFailure::BaseTooSmall));
+	}
+
+	#[test]
+	fn wide_bits_required() {
+		check_bits_required::<60000>();
+		check_bits_required::<4_294_967_295>();
+		assert_eq!(compute_bits_required::<60000>(1000), Ok(15_873));
+		// Just short of 32 bits a digit, which the bounds are close enough to tell apart from a power of two.
+		assert_eq!(compute_bits_required::<4_294_967_295>(12_345), Ok(395_040));
+		assert_eq!(compute_bits_required::<60000>(1 << 56), Ok(1_143_746_762_818_555_704));
+		assert_eq!(compute_bits_required::<60000>(u64::MAX >> 3), Err(MaxNitComputationFailure::BitsTooLarge));
+		assert_eq!(compute_bits_required::<4_294_967_295>(u64::MAX >> 5), Err(MaxNitComputationFailure::BitsTooLarge));
+	}
+
+	#[test]
+	fn wide_capacities() {
+		assert_eq!(compute_max_nits_in_bits::<3, 129>(), Ok(81));
+		assert_eq!(compute_max_nits_in_bits::<3, 256>(), Ok(161));
+		assert_eq!(compute_max_nits_in_bits::<3, 512>(), Ok(323));
+		assert_eq!(compute_max_nits_in_bits::<10, 256>(), Ok(77));
+		assert_eq!(compute_max_nits_in_bits::<2, 1024>(), Ok(1024));
+		assert_eq!(compute_max_nits_in_bits::<1000, 1024>(), Ok(102));
+		assert_eq!(compute_max_nits_in_bits::<60000, 1024>(), Ok(64));
+		assert_eq!(compute_max_nits_in_bits::<3, 1025>(), Err(MaxNitComputationFailure::BitsTooLarge));
+	}
+
+	#[test]
+	fn packings() {
+		let plans = plan_native_packings::<2>().unwrap();
+		assert!(plans.iter().all(|plan| plan.wasted_states == 0 && plan.nits_per_word == plan.word_bits));
+		let plans = plan_native_packings::<10>().unwrap();
+		assert_eq!(plans.map(|plan| plan.nits_per_word), [2, 4, 9, 19, 38]);
+		assert_eq!(plans[4].wasted_states, u128::MAX - (10_u128.pow(38) - 1));
+		assert_eq!(plan_packing::<3>(0), Err(MaxNitComputationFailure::BitsTooSmall));
+		assert_eq!(plan_packing::<3>(129), Err(MaxNitComputationFailure::BitsTooLarge));
+		assert_eq!(choose_packing::<3>(10, &[]), Err(MaxNitComputationFailure::BitsTooSmall));
+		assert_eq!(choose_packing::<3>(10, &[1, 8]).unwrap().word_bits, 8);
+		assert_eq!(choose_packing::<3>(10, &[8, 1]).unwrap().word_bits, 8);
+		assert_eq!(choose_packing::<3>(10, &[1]), Err(MaxNitComputationFailure::BaseExceedsMaxBitValues));
+		// Where a power of the base is exactly `2^bits`, every state of the word is used.
+		let plans = plan_native_packings::<16>().unwrap();
+		assert!(plans.iter().all(|plan| plan.wasted_states == 0 && plan.nits_per_word == plan.word_bits / 4));
+		assert_eq!(plan_packing::<4>(8).unwrap().nits_per_word, 4);
+		assert_eq!(plan_packing::<8>(8).unwrap().nits_per_word, 2);
+		assert_eq!(plan_packing::<256>(8).unwrap(), PackingPlan { base: 256, word_bits: 8, nits_per_word: 1, wasted_states: 0 });
+		assert_eq!(plan_packing::<256>(7), Err(MaxNitComputationFailure::BaseExceedsMaxBitValues));
+		assert_eq!(choose_packing::<4>(100, &[8, 64]).unwrap(), plan_packing::<4>(8).unwrap());
+	}
+}
@@ -0,0 +1,386 @@
+use core::marker::PhantomData;
+use crate::Nit;
+use crate::internal_macros::{deriving_const, impl_error};
+use crate::max_nits::compute_mixed_radix_max_value;
+use crate::supported::{BaseMaximum, FitsMaximumBits, FitsMaximumBitsAsType, MAXIMUM_NATIVE_BITS};
+
+/// The base of each place within a [`MixedRadix`] number.
+///
+/// This is implemented on a marker type, which then selects the layout of a [`MixedRadix`].
+///
+/// # Example
+/// ```
+/// use nit::mixed_radix::Radices;
+/// use nit::supported::BaseMaximum;
+///
+/// /// Seconds, minutes and hours.
+/// struct Clock;
+/// impl Radices for Clock {
+///     const BASES: &'static [BaseMaximum] = &[60, 60, 24];
+/// }
+/// ```
+pub trait Radices {
+	/// The base of each place, starting from the least significant digit (right-hand side).
+	const BASES: &'static [BaseMaximum];
+
+	/// The shifter of each place; the product of the bases of all lesser places, with the entries past the last place being zero.
+	///
+	/// A layout which can be stored within an integer has no more places than the integer has bits, as each base is at least two,
+	/// so every place of a storable layout has an entry.
+	#[allow(clippy::cast_lossless)]
+	const SHIFTERS: [FitsMaximumBitsAsType; MAXIMUM_NATIVE_BITS as usize] = {
+		let mut shifters = [0; MAXIMUM_NATIVE_BITS as usize];
+		let mut shifter: FitsMaximumBitsAsType = 1;
+		let mut i = 0;
+		while i < Self::BASES.len() && i < shifters.len() {
+			shifters[i] = shifter;
+			// The entries of layouts which can't be stored aren't used, so overflowing is of no concern.
+			shifter = shifter.wrapping_mul(Self::BASES[i] as FitsMaximumBitsAsType);
+			i += 1;
+		}
+		shifters
+	};
+}
+
+deriving_const!((PartialEq) for {
+	/// An error that occurred when attempting to access a place of a [`MixedRadix`] number.
+	#[derive(Debug, Eq, Clone, Copy, Hash)]
+	pub enum MixedRadixAccessError {
+		/// The index goes beyond the amount of places.
+		OutOfBounds,
+		/// The base of the provided or requested [`Nit`] isn't the base of the place.
+		BaseMismatch,
+		/// The digit isn't within the range of `0..BASE` for the base of the place.
+		DigitTooLarge,
+	}
+});
+impl MixedRadixAccessError {
+	/// Returns the error message as a string.
+	#[must_use]
+	#[cfg(not(tarpaulin_include))]
+	pub const fn get_str(&self) -> &str {
+		match self {
+			Self::OutOfBounds => "The index goes beyond the amount of places.",
+			Self::BaseMismatch => "The base of the nit isn't the base of the place.",
+			Self::DigitTooLarge => "The digit isn't within the range of 0..BASE for the base of the place.",
+		}
+	}
+}
+#[cfg(not(tarpaulin_include))]
+impl core::fmt::Display for MixedRadixAccessError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "{}", self.get_str())
+	}
+}
+impl_error!(MixedRadixAccessError);
+
+/// A number where each place has its own base, as given by [`Radices::BASES`].
+///
+/// The `i`th place is extracted the same way as a regular digit, except the shifter is the product of the bases of all lesser places rather than `BASE.pow(i)`:
+///
+/// $$d\_i = \left\lfloor\frac{n}{\prod_{j=0}^{i-1} b\_j}\right\rfloor\bmod b\_i$$
+///
+/// Using a layout whose values can't all be stored within `T` fails to compile.
+///
+/// # Example
+/// ```
+/// use nit::mixed_radix::{MixedRadix, Radices};
+/// use nit::supported::BaseMaximum;
+///
+/// struct Clock;
+/// impl Radices for Clock {
+///     const BASES: &'static [BaseMaximum] = &[60, 60, 24];
+/// }
+///
+/// let mut time = MixedRadix::<u32, Clock>::new();
+/// assert_eq!(time.set(0, 30), Ok(0)); // seconds
+/// assert_eq!(time.set(1, 15), Ok(0)); // minutes
+/// assert_eq!(time.set(2, 12), Ok(0)); // hours
+/// assert_eq!(time.get_value(), 30 + 15 * 60 + 12 * 60 * 60);
+/// assert_eq!(time.get(1), Some(15));
+/// assert_eq!(MixedRadix::<u32, Clock>::MAX, 24 * 60 * 60 - 1);
+/// ```
+#[repr(transparent)]
+pub struct MixedRadix<T, R: Radices>(T, PhantomData<R>);
+
+impl<T: Clone, R: Radices> Clone for MixedRadix<T, R> {
+	fn clone(&self) -> Self {
+		Self(self.0.clone(), PhantomData)
+	}
+}
+impl<T: Copy, R: Radices> Copy for MixedRadix<T, R> {}
+impl<T: PartialEq, R: Radices> PartialEq for MixedRadix<T, R> {
+	fn eq(&self, other: &Self) -> bool {
+		self.0 == other.0
+	}
+}
+impl<T: Eq, R: Radices> Eq for MixedRadix<T, R> {}
+impl<T: core::hash::Hash, R: Radices> core::hash::Hash for MixedRadix<T, R> {
+	fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+		self.0.hash(state);
+	}
+}
+impl<T: core::fmt::Debug, R: Radices> core::fmt::Debug for MixedRadix<T, R> {
+	#[cfg(not(tarpaulin_include))]
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.debug_tuple("MixedRadix").field(&self.0).finish()
+	}
+}
+
+/// Generates the implementation of [`MixedRadix`] for each primitive integer type provided.
+macro_rules! impl_mixed_radix {
+	($($type: ty),*) => {
+		$(
+			impl<R: Radices> MixedRadix<$type, R> {
+				/// The largest value representable by the places; the product of their bases, minus one.
+				///
+				/// This is evaluated at compile-time, failing if the layout can't be stored within the type.
+				#[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+				pub const MAX: $type = match compute_mixed_radix_max_value::<{ <$type>::BITS as FitsMaximumBits }>(R::BASES) {
+					Ok(max) => max as $type,
+					Err(err) => panic!("{}", err.get_str()),
+				};
+
+				/// Creates a new number with every place set to zero.
+				#[must_use]
+				pub const fn new() -> Self {
+					let _ = Self::MAX;
+					Self(0, PhantomData)
+				}
+
+				/// Creates a number from its underlying value, if it is no greater than [`Self::MAX`].
+				#[must_use]
+				pub const fn from_value(value: $type) -> Option<Self> {
+					if value <= Self::MAX {
+						Some(Self(value, PhantomData))
+					} else {
+						None
+					}
+				}
+
+				/// Returns the underlying value.
+				#[must_use]
+				pub const fn get_value(&self) -> $type {
+					self.0
+				}
+
+				/// Returns the amount of places.
+				#[must_use]
+				pub const fn places() -> usize {
+					R::BASES.len()
+				}
+
+				/// Returns the shifter of the `i`th place; the product of the bases of all lesser places.
+				/// Returns [`None`] if the index goes beyond the amount of places.
+				#[must_use]
+				pub const fn get_place_shifter(i: usize) -> Option<$type> {
+					// Ensures the layout is storable, and so that each place has an entry within the table.
+					let _ = Self::MAX;
+					if i >= R::BASES.len() {
+						return None;
+					}
+					// The product of the lesser bases is no greater than `MAX`, so this can't truncate.
+					#[allow(clippy::cast_possible_truncation)]
+					let shifter = R::SHIFTERS[i] as $type;
+					Some(shifter)
+				}
+
+				/// Returns the digit at the `i`th place, falling in the range of `0..R::BASES[i]`.
+				/// Returns [`None`] if the index goes beyond the amount of places.
+				#[must_use]
+				pub const fn get(&self, i: usize) -> Option<BaseMaximum> {
+					let Some(shifter) = Self::get_place_shifter(i) else { return None };
+					#[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+					let base = R::BASES[i] as $type;
+					#[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+					let digit = ((self.0 / shifter) % base) as BaseMaximum;
+					Some(digit)
+				}
+
+				/// Sets the digit at the `i`th place, returning the previous digit at that place.
+				///
+				/// # Errors
+				/// - [`MixedRadixAccessError::OutOfBounds`]: If the index goes beyond the amount of places.
+				/// - [`MixedRadixAccessError::DigitTooLarge`]: If the digit isn't within the range of `0..R::BASES[i]`.
+				pub const fn set(&mut self, i: usize, digit: BaseMaximum) -> Result<BaseMaximum, MixedRadixAccessError> {
+					let Some(shifter) = Self::get_place_shifter(i) else { return Err(MixedRadixAccessError::OutOfBounds) };
+					if digit >= R::BASES[i] {
+						return Err(MixedRadixAccessError::DigitTooLarge);
+					}
+					#[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+					let base = R::BASES[i] as $type;
+					let previous = (self.0 / shifter) % base;
+					// Removing the previous digit first ensures that the value stays within `0..=MAX` throughout.
+					#[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+					let replacement = digit as $type;
+					self.0 = self.0 - previous * shifter + replacement * shifter;
+					#[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+					let previous = previous as BaseMaximum;
+					Ok(previous)
+				}
+
+				/// Returns the digit at the `i`th place as a [`Nit`] of the place's base.
+				///
+				/// # Errors
+				/// - [`MixedRadixAccessError::OutOfBounds`]: If the index goes beyond the amount of places.
+				/// - [`MixedRadixAccessError::BaseMismatch`]: If `BASE` isn't the base of the place.
+				///
+				/// # Example
+				/// ```
+				/// use nit::mixed_radix::{MixedRadix, MixedRadixAccessError, Radices};
+				/// use nit::supported::BaseMaximum;
+				/// use nit::{Bit, Nit, Trit};
+				///
+				/// struct Layout;
+				/// impl Radices for Layout {
+				///     const BASES: &'static [BaseMaximum] = &[3, 5, 2];
+				/// }
+				///
+				/// let mut value = MixedRadix::<u8, Layout>::new();
+				/// assert_eq!(value.set_nit(2, Bit::ONE), Ok(Bit::ZERO));
+				/// assert_eq!(value.get_nit::<2, 1>(2), Ok(Bit::ONE));
+				/// assert_eq!(value.get_nit::<3, 1>(1), Err(MixedRadixAccessError::BaseMismatch));
+				/// assert_eq!(value.set_nit(3, Trit::ONE), Err(MixedRadixAccessError::OutOfBounds));
+				/// ```
+				pub const fn get_nit<const BASE: BaseMaximum, const BYTES: usize>(&self, i: usize) -> Result<Nit<BASE, BYTES>, MixedRadixAccessError> {
+					if i >= R::BASES.len() {
+						return Err(MixedRadixAccessError::OutOfBounds);
+					}
+					if R::BASES[i] != BASE {
+						return Err(MixedRadixAccessError::BaseMismatch);
+					}
+					match self.get(i) {
+						// SAFETY: The digit of the place is within `0..R::BASES[i]`, which was just checked to be `BASE`.
+						Some(digit) => Ok(unsafe { Nit::new_unchecked(digit) }),
+						None => Err(MixedRadixAccessError::OutOfBounds),
+					}
+				}
+
+				/// Sets the digit at the `i`th place to a [`Nit`] of the place's base, returning the previous digit at that place.
+				///
+				/// # Errors
+				/// - [`MixedRadixAccessError::OutOfBounds`]: If the index goes beyond the amount of places.
+				/// - [`MixedRadixAccessError::BaseMismatch`]: If `BASE` isn't the base of the place.
+				pub const fn set_nit<const BASE: BaseMaximum, const BYTES: usize>(&mut self, i: usize, value: Nit<BASE, BYTES>) -> Result<Nit<BASE, BYTES>, MixedRadixAccessError> {
+					if i >= R::BASES.len() {
+						return Err(MixedRadixAccessError::OutOfBounds);
+					}
+					if R::BASES[i] != BASE {
+						return Err(MixedRadixAccessError::BaseMismatch);
+					}
+					match self.set(i, value.get_value()) {
+						// SAFETY: The digit of the place is within `0..R::BASES[i]`, which was just checked to be `BASE`.
+						Ok(previous) => Ok(unsafe { Nit::new_unchecked(previous) }),
+						Err(err) => Err(err),
+					}
+				}
+			}
+
+			impl<R: Radices> Default for MixedRadix<$type, R> {
+				fn default() -> Self {
+					Self::new()
+				}
+			}
+		)*
+	};
+}
+impl_mixed_radix!(u8, u16, u32, u64, u128);
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::missing_docs_in_private_items)]
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+
+	struct Small;
+	impl Radices for Small {
+		const BASES: &'static [BaseMaximum] = &[3, 5, 2, 7];
+	}
+
+	struct Clock;
+	impl Radices for Clock {
+		const BASES: &'static [BaseMaximum] = &[60, 60, 18];
+	}
+
+	struct Metric;
+	impl Radices for Metric {
+		const BASES: &'static [BaseMaximum] = &[1000, 1000, 10];
+	}
+
+	struct Bits;
+	impl Radices for Bits {
+		const BASES: &'static [BaseMaximum] = &[2; 128];
+	}
+
+	macro_rules! check_round_trips {
+		($type: ty, $radices: ty) => {{
+			let mut shifter: $type = 1;
+			for (i, &base) in <$radices>::BASES.iter().enumerate() {
+				assert_eq!(MixedRadix::<$type, $radices>::get_place_shifter(i), Some(shifter));
+				#[allow(clippy::cast_possible_truncation)]
+				let base = base as $type;
+				shifter = shifter.wrapping_mul(base);
+			}
+			assert_eq!(MixedRadix::<$type, $radices>::get_place_shifter(<$radices>::BASES.len()), None);
+
+			for value in 0..=MixedRadix::<$type, $radices>::MAX {
+				let number = MixedRadix::<$type, $radices>::from_value(value).unwrap();
+				let mut rebuilt = MixedRadix::<$type, $radices>::new();
+				for i in 0..<$radices>::BASES.len() {
+					let digit = number.get(i).unwrap();
+					assert!(digit < <$radices>::BASES[i]);
+					assert_eq!(rebuilt.set(i, digit), Ok(0));
+				}
+				assert_eq!(rebuilt, number);
+			}
+		}};
+	}
+
+	#[test]
+	fn round_trips() {
+		check_round_trips!(u8, Small);
+		check_round_trips!(u16, Small);
+		check_round_trips!(u16, Clock);
+	}
+
+	#[test]
+	fn set_every_digit() {
+		let mut number = MixedRadix::<u16, Clock>::from_value(12_345).unwrap();
+		for (i, &base) in Clock::BASES.iter().enumerate() {
+			for digit in 0..base {
+				let previous = number.get(i).unwrap();
+				assert_eq!(number.set(i, digit), Ok(previous));
+				assert_eq!(number.get(i), Some(digit));
+			}
+			assert_eq!(number.set(i, base), Err(MixedRadixAccessError::DigitTooLarge));
+		}
+		assert_eq!(number.set(Clock::BASES.len(), 0), Err(MixedRadixAccessError::OutOfBounds));
+		assert_eq!(number.get(Clock::BASES.len()), None);
+	}
+
+	#[test]
+	fn wide_places() {
+		let mut number = MixedRadix::<u32, Metric>::from_value(7_654_321).unwrap();
+		assert_eq!(number.get_nit::<1000, 2>(0), Ok(Nit::new(321).unwrap()));
+		assert_eq!(number.get_nit::<1000, 4>(1), Ok(Nit::new(654).unwrap()));
+		assert_eq!(number.get_nit::<10, 1>(2), Ok(Nit::new(7).unwrap()));
+		assert_eq!(number.set_nit(1, Nit::<1000, 2>::new(999).unwrap()), Ok(Nit::new(654).unwrap()));
+		assert_eq!(number.get_value(), 7_999_321);
+		assert_eq!(number.get_nit::<1000, 2>(2), Err(MixedRadixAccessError::BaseMismatch));
+		assert_eq!(number.set_nit(3, Nit::<1000, 2>::new(0).unwrap()), Err(MixedRadixAccessError::OutOfBounds));
+	}
+
+	#[test]
+	fn every_place() {
+		assert_eq!(MixedRadix::<u128, Bits>::MAX, u128::MAX);
+		let mut number = MixedRadix::<u128, Bits>::new();
+		for i in 0..128 {
+			assert_eq!(MixedRadix::<u128, Bits>::get_place_shifter(i), Some(1 << i));
+			assert_eq!(number.set(i, 1), Ok(0));
+			assert_eq!(number.get_value(), u128::MAX >> (127 - i));
+		}
+		for i in 0..128 {
+			assert_eq!(number.get(i), Some(1));
+		}
+	}
+}
@@ -1,3 +1,4 @@
+use core::iter::FusedIterator;
 use crate::internal_macros::{deriving_const, impl_error};
 use crate::supported::{BaseMaximum, FitsMaximumBits};
 use crate::max_nits::{compute_max_nits_in_bits, MaxNitComputationFailure};
@@ -67,10 +68,13 @@ impl_error!(PlacesIndexCreationError);
 /// for n in 0..8 {
 ///     let idx = U8BitIndex::new(n).unwrap();
 ///     assert_eq!(
-///         VALUE.get_nit_indexed(idx).get_value(),
-///         (VALUE >> n) & 1
+///         VALUE.get_nit_indexed::<2, 1>(idx).get_value(),
+///         u32::from((VALUE >> n) & 1)
 ///     )
 /// }
+/// // A `u64` holds six base-1000 digits, as `1000^7` exceeds it.
+/// assert!(PlacesIndex::<64, 1000>::new(5).is_ok());
+/// assert!(PlacesIndex::<64, 1000>::new(6).is_err());
 /// ```
 // TODO: rename to DigitPosition or something
 #[repr(transparent)]
@@ -172,4 +176,65 @@ impl<const TYPE_BIT_WIDTH: FitsMaximumBits, const BASE: BaseMaximum> PlacesIndex
 	pub const fn into(self) -> FitsMaximumBits {
 		self.0
 	}
+
+	/// Returns an iterator over every valid [`PlacesIndex`] for the base and bit width, in ascending order.
+	/// The iterator is empty if the base or bit width is erroneous.
+	///
+	/// # Example
+	/// ```
+	/// use nit::places::PlacesIndex;
+	/// assert_eq!(PlacesIndex::<8, 3>::all().map(|index| index.get()).collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+	/// assert_eq!(PlacesIndex::<8, 3>::all().next_back(), PlacesIndex::new(4).ok());
+	/// assert_eq!(PlacesIndex::<1, 3>::all().len(), 0);
+	/// ```
+	#[must_use]
+	pub const fn all() -> PlacesIndexIter<TYPE_BIT_WIDTH, BASE> {
+		let back = match compute_max_nits_in_bits::<BASE, TYPE_BIT_WIDTH>() {
+			Ok(max) => max,
+			Err(_) => 0,
+		};
+		PlacesIndexIter { front: 0, back }
+	}
+}
+
+/// An iterator over every valid [`PlacesIndex`] for a base and bit width.
+///
+/// # See Also
+/// - [`PlacesIndex::all`]
+#[derive(Debug, Clone)]
+pub struct PlacesIndexIter<const TYPE_BIT_WIDTH: FitsMaximumBits, const BASE: BaseMaximum> {
+	/// The next index yielded from the front.
+	front: FitsMaximumBits,
+	/// One past the next index yielded from the back.
+	back: FitsMaximumBits,
+}
+impl<const TYPE_BIT_WIDTH: FitsMaximumBits, const BASE: BaseMaximum> Iterator for PlacesIndexIter<TYPE_BIT_WIDTH, BASE> {
+	type Item = PlacesIndex<TYPE_BIT_WIDTH, BASE>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.front >= self.back {
+			return None;
+		}
+		// SAFETY: The index is less than the computed nit capacity for this base and bit width.
+		let index = unsafe { PlacesIndex::new_unchecked(self.front) };
+		self.front += 1;
+		Some(index)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = usize::from(self.back - self.front);
+		(len, Some(len))
+	}
+}
+impl<const TYPE_BIT_WIDTH: FitsMaximumBits, const BASE: BaseMaximum> DoubleEndedIterator for PlacesIndexIter<TYPE_BIT_WIDTH, BASE> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.front >= self.back {
+			return None;
+		}
+		self.back -= 1;
+		// SAFETY: The index is less than the computed nit capacity for this base and bit width.
+		Some(unsafe { PlacesIndex::new_unchecked(self.back) })
+	}
 }
+impl<const TYPE_BIT_WIDTH: FitsMaximumBits, const BASE: BaseMaximum> ExactSizeIterator for PlacesIndexIter<TYPE_BIT_WIDTH, BASE> {}
+impl<const TYPE_BIT_WIDTH: FitsMaximumBits, const BASE: BaseMaximum> FusedIterator for PlacesIndexIter<TYPE_BIT_WIDTH, BASE> {}
@@ -1,4 +1,11 @@
 pub use crate::data_container::NitDataContainer;
 pub use crate::places::{PlacesIndex, PlacesIndexCreationError};
 pub use crate::max_nits::MaxNitComputationFailure;
+pub use crate::array::{NitArray, words_required};
+pub use crate::mixed_radix::{MixedRadix, MixedRadixAccessError, Radices};
+pub use crate::balanced::{BalancedNit, BalancedNitAccessError, BalancedNitDataContainer, BalancedTrit};
+pub use crate::arithmetic::NitwiseArithmetic;
+pub use crate::logic::NitwiseLogic;
+pub use crate::display::{DisplayNits, ParseNitsError};
+pub use crate::repr::NitRepr;
 pub use crate::{Nit, NitCreationError};
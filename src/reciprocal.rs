@@ -0,0 +1,235 @@
+/// A precomputed reciprocal of a divisor, used to divide by it without a hardware division.
+///
+/// This uses the method from Granlund & Montgomery's [*Division by Invariant Integers using Multiplication*](https://gmplib.org/~tege/divcnst-pldi94.pdf) (figure 4.1),
+/// which is exact for every dividend and any non-zero divisor of the type:
+///
+/// $$\left\lfloor\frac{n}{d}\right\rfloor = \left(t + \left(n - t\right) \gg s\_1\right) \gg s\_2, \quad t = \left\lfloor\frac{m \cdot n}{2^N}\right\rfloor$$
+///
+/// where $l = \lceil\log\_2 d\rceil$, $m = \left\lfloor\frac{2^N \left(2^l - d\right)}{d}\right\rfloor + 1$, $s\_1 = \min(l, 1)$ and $s\_2 = \max(l - 1, 0)$.
+///
+/// The reciprocals of every power of a base are tabulated within [`Powers::RECIPROCALS`](crate::base::Powers::RECIPROCALS),
+/// which are what digit extraction uses instead of dividing by the [`PlacesShifter`](crate::places::PlacesShifter).
+///
+/// # Example
+/// ```
+/// use nit::reciprocal::Reciprocal;
+/// let seven = Reciprocal::<u64>::new(7).unwrap();
+/// assert_eq!(seven.divide(u64::MAX), u64::MAX / 7);
+/// assert_eq!(seven.remainder(u64::MAX), u64::MAX % 7);
+/// assert!(Reciprocal::<u64>::new(0).is_none());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Reciprocal<T> {
+	/// The divisor this is the reciprocal of.
+	divisor: T,
+	/// The low bits of the multiplier; the implicit top bit is accounted for by the first shift.
+	multiplier: T,
+	/// The shift applied to the difference between the dividend and the high product; $s\_1$.
+	first_shift: u32,
+	/// The shift applied to the final sum; $s\_2$.
+	second_shift: u32,
+}
+
+/// Generates the implementation of [`Reciprocal`] for each primitive integer type provided, along with the function used to compute the high half of a product.
+macro_rules! impl_reciprocal {
+	($(($type: ty, $mul_high: expr)),*) => {
+		$(
+			impl Reciprocal<$type> {
+				/// Computes the reciprocal of a divisor.
+				/// Returns [`None`] if the divisor is zero.
+				#[must_use]
+				pub const fn new(divisor: $type) -> Option<Self> {
+					if divisor == 0 {
+						return None;
+					}
+					// `ceil(log2(divisor))`, which is the amount of bits in `divisor - 1`.
+					let log = <$type>::BITS - (divisor - 1).leading_zeros();
+					// `2^l - d` is less than `d`; it is computed modulo `2^N`, as `2^l` doesn't fit within the type when `l = N`.
+					let power = match (1 as $type).checked_shl(log) {
+						Some(power) => power,
+						None => 0,
+					};
+					let mut remainder = power.wrapping_sub(divisor);
+					// Long division of `(2^l - d) * 2^N` by `d`, one bit at a time, as the numerator doesn't fit within the type.
+					let mut quotient: $type = 0;
+					let mut i = 0;
+					while i < <$type>::BITS {
+						let carry = remainder >> (<$type>::BITS - 1);
+						remainder <<= 1;
+						quotient <<= 1;
+						// With the carry, the true remainder is at least `2^N`, which is greater than the divisor.
+						if carry == 1 || remainder >= divisor {
+							remainder = remainder.wrapping_sub(divisor);
+							quotient |= 1;
+						}
+						i += 1;
+					}
+					Some(Self {
+						divisor,
+						// As `2^l - d` is less than `d`, the quotient is less than `2^N - 1`, so this can't overflow.
+						multiplier: quotient + 1,
+						first_shift: if log < 1 { log } else { 1 },
+						second_shift: log.saturating_sub(1),
+					})
+				}
+
+				/// Returns the divisor this is the reciprocal of.
+				#[must_use]
+				pub const fn divisor(&self) -> $type {
+					self.divisor
+				}
+
+				/// Returns `n / divisor`.
+				#[must_use]
+				#[inline]
+				pub const fn divide(&self, n: $type) -> $type {
+					let high = $mul_high(self.multiplier, n);
+					// The high product is no greater than the dividend, and the sum is no greater than the dividend either, so neither can overflow.
+					(high + ((n - high) >> self.first_shift)) >> self.second_shift
+				}
+
+				/// Returns `n % divisor`.
+				#[must_use]
+				#[inline]
+				pub const fn remainder(&self, n: $type) -> $type {
+					// The quotient times the divisor is no greater than the dividend.
+					n - self.divide(n) * self.divisor
+				}
+			}
+		)*
+	};
+}
+
+/// The high half of the full product of two integers, for each primitive integer type.
+mod mul_high {
+	/// Returns the high half of the full product of two [`u8`]s.
+	#[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+	pub(super) const fn u8(a: u8, b: u8) -> u8 { ((a as u16 * b as u16) >> 8) as u8 }
+	/// Returns the high half of the full product of two [`u16`]s.
+	#[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+	pub(super) const fn u16(a: u16, b: u16) -> u16 { ((a as u32 * b as u32) >> 16) as u16 }
+	/// Returns the high half of the full product of two [`u32`]s.
+	#[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+	pub(super) const fn u32(a: u32, b: u32) -> u32 { ((a as u64 * b as u64) >> 32) as u32 }
+	/// Returns the high half of the full product of two [`u64`]s.
+	#[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+	pub(super) const fn u64(a: u64, b: u64) -> u64 { ((a as u128 * b as u128) >> 64) as u64 }
+	/// Returns the high half of the full product of two [`u128`]s.
+	pub(super) const fn u128(a: u128, b: u128) -> u128 {
+		/// A mask for the low half of a [`u128`].
+		const LOW: u128 = u64::MAX as u128;
+		let (a_low, a_high) = (a & LOW, a >> 64);
+		let (b_low, b_high) = (b & LOW, b >> 64);
+		// Each partial product fits within a `u128`, as its factors each fit within a `u64`.
+		let low_low = a_low * b_low;
+		let low_high = a_low * b_high;
+		let high_low = a_high * b_low;
+		let high_high = a_high * b_high;
+		// The sum of the middle terms' low halves and the carry out of the low product fits within 66 bits.
+		let middle = (low_low >> 64) + (low_high & LOW) + (high_low & LOW);
+		high_high + (low_high >> 64) + (high_low >> 64) + (middle >> 64)
+	}
+}
+impl_reciprocal!(
+	(u8, mul_high::u8),
+	(u16, mul_high::u16),
+	(u32, mul_high::u32),
+	(u64, mul_high::u64),
+	(u128, mul_high::u128)
+);
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::missing_docs_in_private_items)]
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+	use crate::Nit;
+	use crate::base::Base;
+	use crate::data_container::NitDataContainer;
+	use crate::places::PlacesIndex;
+	use crate::supported::{BaseMaximum, FitsMaximumBits};
+
+	#[test]
+	fn exhaustive_u8() {
+		for divisor in 1..=u8::MAX {
+			let reciprocal = Reciprocal::<u8>::new(divisor).unwrap();
+			for n in 0..=u8::MAX {
+				assert_eq!(reciprocal.divide(n), n / divisor, "{n} / {divisor}");
+				assert_eq!(reciprocal.remainder(n), n % divisor, "{n} % {divisor}");
+			}
+		}
+	}
+
+	macro_rules! make_sampled_division_tests {
+		($(($name: ident, $type: ty)),*) => {
+			$(
+				#[test]
+				#[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+				fn $name() {
+					let mut samples = vec![0, 1, 2, 3, <$type>::MAX, <$type>::MAX - 1, <$type>::MAX / 2, <$type>::MAX / 2 + 1];
+					// A cheap generator with good enough coverage of every bit position.
+					let mut state: $type = 0x9E37_79B9_u32 as $type;
+					for _ in 0..500 {
+						state = state.wrapping_mul(0x5851_F42D_u32 as $type).wrapping_add(0x1405_7B7E_u32 as $type) ^ (state >> 3);
+						samples.push(state);
+						samples.push(state >> (state % <$type>::BITS as $type));
+					}
+					for &divisor in &samples {
+						let Some(reciprocal) = Reciprocal::<$type>::new(divisor) else { continue };
+						for &n in &samples {
+							assert_eq!(reciprocal.divide(n), n / divisor, "{n} / {divisor}");
+						}
+					}
+				}
+			)*
+		};
+	}
+
+	make_sampled_division_tests!(
+		(sampled_u16, u16),
+		(sampled_u32, u32),
+		(sampled_u64, u64),
+		(sampled_u128, u128)
+	);
+
+	macro_rules! make_reference_extraction_tests {
+		($(($name: ident, $type: ty)),*) => {
+			$(
+				#[test]
+				fn $name() {
+					/// The reference implementation of digit extraction, dividing by the shifter and base.
+					#[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+					fn reference<const BASE: BaseMaximum>(value: $type, n: PlacesIndex<{ <$type>::BITS as FitsMaximumBits }, BASE>) -> Nit<BASE> {
+						let shifter = <$type>::get_places_shifter(n).get();
+						Nit::new(((value / shifter) % BASE as $type) as BaseMaximum).unwrap()
+					}
+					fn check<const BASE: BaseMaximum>(value: $type) {
+						for n in PlacesIndex::all() {
+							let mut copy = value;
+							assert_eq!(value.get_nit_indexed::<BASE, 1>(n), reference(value, n));
+							let replacement = Nit::<BASE>::new(BASE - 1).unwrap();
+							assert_eq!(copy.set_nit_indexed(n, replacement), reference(value, n));
+							assert_eq!(copy.get_nit_indexed::<BASE, 1>(n), reference(copy, n));
+						}
+					}
+					for value in [0, 1, <$type>::MAX / 5 * 2, <$type>::MAX, <$type>::MAX / 3, <$type>::MAX / 10 * 7] {
+						check::<2>(value);
+						check::<3>(value);
+						check::<10>(value);
+						check::<36>(value);
+						check::<127>(value);
+						check::<128>(value);
+					}
+				}
+			)*
+		};
+	}
+
+	make_reference_extraction_tests!(
+		(reference_extraction_u8, u8),
+		(reference_extraction_u16, u16),
+		(reference_extraction_u32, u32),
+		(reference_extraction_u64, u64),
+		(reference_extraction_u128, u128)
+	);
+}
@@ -0,0 +1,86 @@
+use crate::Nit;
+use crate::data_container::NitDataContainer;
+use crate::places::{PlacesIndex, PlacesIndexCreationError};
+use crate::supported::{BaseMaximum, FitsMaximumBits};
+
+/// A type which can be represented as a single base-[`BASE`](NitRepr::BASE) digit.
+///
+/// This is implemented for every [`Nit`], and can be derived for fieldless enums with [`derive@crate::Nit`],
+/// in which case the base is the amount of variants and each variant is represented by its declaration index.
+///
+/// # Example
+/// ```
+/// use nit::prelude::*;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq, Nit)]
+/// enum PermissionGrant { Unset, Allow, Deny }
+///
+/// let mut value = 0u8;
+/// assert_eq!(value.set_as(1, PermissionGrant::Deny), Ok(PermissionGrant::Unset));
+/// assert_eq!(value.get_as::<PermissionGrant>(1), Some(PermissionGrant::Deny));
+/// assert_eq!(value.get_nit::<3, 1>(1), Some(Nit::from(PermissionGrant::Deny)));
+/// ```
+pub trait NitRepr: Sized + Copy {
+	/// The base of the digit which represents this type.
+	const BASE: BaseMaximum;
+
+	/// Returns the digit representing this value, falling in the range of `0..BASE`.
+	#[must_use]
+	fn into_digit(self) -> BaseMaximum;
+
+	/// Returns the value represented by the given digit, without checking if it is valid.
+	///
+	/// # Safety
+	/// - The digit must be within the range of `0..BASE`.
+	#[must_use]
+	unsafe fn from_digit_unchecked(digit: BaseMaximum) -> Self;
+
+	/// Returns the value represented by the given digit, if it falls within the range of `0..BASE`.
+	#[must_use]
+	fn from_digit(digit: BaseMaximum) -> Option<Self> {
+		if digit < Self::BASE {
+			// SAFETY: The digit was just checked to be within the range of `0..BASE`.
+			Some(unsafe { Self::from_digit_unchecked(digit) })
+		} else {
+			None
+		}
+	}
+
+	/// Returns the value represented by the base-`BASE` digit at the `n`th place of the container.
+	///
+	/// # See Also
+	/// - [`NitDataContainer::get_as`]
+	#[must_use]
+	fn get_from<const TYPE_BIT_WIDTH: FitsMaximumBits, C: NitDataContainer<TYPE_BIT_WIDTH> + ?Sized>(container: &C, n: FitsMaximumBits) -> Option<Self>;
+
+	/// Sets the base-`BASE` digit at the `n`th place of the container to the one representing this value.
+	/// Returns the value represented by the previous digit at that place.
+	///
+	/// # Errors
+	/// See: [`PlacesIndexCreationError`]
+	///
+	/// # See Also
+	/// - [`NitDataContainer::set_as`]
+	fn set_in<const TYPE_BIT_WIDTH: FitsMaximumBits, C: NitDataContainer<TYPE_BIT_WIDTH> + ?Sized>(self, container: &mut C, n: FitsMaximumBits) -> Result<Self, PlacesIndexCreationError>;
+}
+
+impl<const BASE: BaseMaximum, const BYTES: usize> NitRepr for Nit<BASE, BYTES> {
+	const BASE: BaseMaximum = BASE;
+
+	fn into_digit(self) -> BaseMaximum {
+		self.into_value()
+	}
+
+	unsafe fn from_digit_unchecked(digit: BaseMaximum) -> Self {
+		// SAFETY: The caller is responsible for ensuring that the digit is within the range of `0..BASE`.
+		unsafe { Self::new_unchecked(digit) }
+	}
+
+	fn get_from<const TYPE_BIT_WIDTH: FitsMaximumBits, C: NitDataContainer<TYPE_BIT_WIDTH> + ?Sized>(container: &C, n: FitsMaximumBits) -> Option<Self> {
+		PlacesIndex::new(n).ok().map(|n| container.get_nit_indexed(n))
+	}
+
+	fn set_in<const TYPE_BIT_WIDTH: FitsMaximumBits, C: NitDataContainer<TYPE_BIT_WIDTH> + ?Sized>(self, container: &mut C, n: FitsMaximumBits) -> Result<Self, PlacesIndexCreationError> {
+		PlacesIndex::new(n).map(|n| container.set_nit_indexed(n, self))
+	}
+}
@@ -0,0 +1,384 @@
+use core::iter::FusedIterator;
+use std::io::{self, ErrorKind, Read, Write};
+use crate::Nit;
+use crate::base::Powers;
+use crate::internal_macros::{deriving_const, impl_error};
+use crate::max_nits::compute_max_nits_in_bits;
+use crate::supported::{BaseMaximum, FitsMaximumBits, FitsMaximumBitsAsType};
+
+/// The most bytes within a word; any more, and the value of its digits may not fit within a [`u64`].
+const MAXIMUM_WORD_BYTES: usize = 8;
+
+/// The most bytes that a [`NitDecoder`] reads ahead, which is enough to tell whether the next word is the last.
+const LOOKAHEAD: usize = 2 * MAXIMUM_WORD_BYTES + 1;
+
+deriving_const!((PartialEq) for {
+	/// The reason a stream of nits couldn't be decoded, given as the inner error of an [`io::Error`].
+	#[derive(Debug, Clone, Copy, Eq, Hash)]
+	pub enum StreamError {
+		/// The stream ended within a word, or without a trailer.
+		Truncated,
+		/// A word holds a value which isn't the value of any of its digits, or the padding of the last word isn't zero.
+		InvalidWord,
+		/// The trailer holds more digits than a word.
+		InvalidTrailer,
+	}
+});
+impl StreamError {
+	/// Returns the error message as a string.
+	#[must_use]
+	#[cfg(not(tarpaulin_include))]
+	pub const fn get_str(&self) -> &str {
+		match self {
+			Self::Truncated => "The stream ended within a word, or without a trailer.",
+			Self::InvalidWord => "A word holds a value which isn't the value of any of its digits, or the padding of the last word isn't zero.",
+			Self::InvalidTrailer => "The trailer holds more digits than a word.",
+		}
+	}
+
+	/// Returns the [`ErrorKind`] that the error is reported as.
+	const fn kind(self) -> ErrorKind {
+		match self {
+			Self::Truncated => ErrorKind::UnexpectedEof,
+			Self::InvalidWord | Self::InvalidTrailer => ErrorKind::InvalidData,
+		}
+	}
+}
+#[cfg(not(tarpaulin_include))]
+impl core::fmt::Display for StreamError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "{}", self.get_str())
+	}
+}
+impl_error!(StreamError);
+impl From<StreamError> for io::Error {
+	fn from(err: StreamError) -> Self {
+		Self::new(err.kind(), err)
+	}
+}
+
+/// The layout of the words of a base, shared by [`NitEncoder`] and [`NitDecoder`].
+struct Word<const BASE: BaseMaximum>;
+
+impl<const BASE: BaseMaximum> Word<BASE> {
+	/// The amount of bytes within each word, and the amount of digits each word holds.
+	///
+	/// This is the amount of bytes, up to eight, which holds the most digits per byte, preferring fewer bytes.
+	/// This fails to evaluate at compile-time if the base is erroneous.
+	const SIZE: (usize, FitsMaximumBits) = {
+		if let Err(err) = compute_max_nits_in_bits::<BASE, 64>() {
+			panic!("{}", err.get_str());
+		}
+		// A single byte holds no digits of a base above 256, which any wider word holding a digit is better than.
+		let mut best = (1, Self::digits_for(1));
+		let mut bytes = 2;
+		while bytes <= MAXIMUM_WORD_BYTES {
+			let digits = Self::digits_for(bytes);
+			// Comparing `digits / bytes` against the best ratio, without dividing.
+			if digits as usize * best.0 > best.1 as usize * bytes {
+				best = (bytes, digits);
+			}
+			bytes += 1;
+		}
+		best
+	};
+
+	/// Returns the most digits that the given amount of bytes can hold, up to [`MAXIMUM_WORD_BYTES`].
+	#[allow(clippy::cast_lossless, clippy::cast_possible_truncation)]
+	const fn digits_for(bytes: usize) -> FitsMaximumBits {
+		// The digits fit so long as `BASE.pow(digits)` is no more than the amount of values of the bytes, which is a power of two that fits within a `u128`.
+		let values: FitsMaximumBitsAsType = 1 << (8 * bytes as u32);
+		values.ilog(BASE as FitsMaximumBitsAsType) as FitsMaximumBits
+	}
+}
+
+/// Writes nits to a byte stream, packed into fixed-size words.
+///
+/// Every word is the value of its digits as a big-endian integer, with the first digit being the least significant (see [`WORD`](Self::WORD)).
+/// Once [`finish`](Self::finish)ed, a trailing partial word is padded with zeroes, and followed by a trailer of a single byte
+/// holding the amount of digits within the last word, or zero if it's whole; the stream can then be read back with a [`NitDecoder`].
+///
+/// Words are written to the writer as soon as they're whole, so it may be worth wrapping it within a [`std::io::BufWriter`].
+/// Dropping the encoder without finishing it loses the trailing partial word and the trailer.
+///
+/// # Example
+/// ```
+/// use nit::stream::{NitDecoder, NitEncoder};
+/// use nit::Trit;
+///
+/// let trits = [Trit::TWO, Trit::ONE, Trit::ZERO, Trit::TWO, Trit::ONE, Trit::ONE, Trit::TWO];
+/// // Five trits are packed into each byte.
+/// assert_eq!(NitEncoder::<Vec<u8>, 3>::WORD, (1, 5));
+///
+/// let mut encoder = NitEncoder::<_, 3>::new(Vec::new());
+/// encoder.write_nits(trits).unwrap();
+/// let bytes = encoder.finish().unwrap();
+/// // Two words, and the trailer for the two trits within the last.
+/// assert_eq!(bytes, [2 + 1 * 3 + 2 * 27 + 1 * 81, 1 + 2 * 3, 2]);
+///
+/// let decoder = NitDecoder::<_, 3>::new(bytes.as_slice());
+/// assert_eq!(decoder.collect::<Result<Vec<_>, _>>().unwrap(), trits);
+/// ```
+#[derive(Debug)]
+pub struct NitEncoder<W: Write, const BASE: BaseMaximum, const BYTES: usize = 1> {
+	/// The stream the words are written to.
+	writer: W,
+	/// The value of the digits of the word being written.
+	word: u64,
+	/// The amount of digits within the word being written.
+	count: FitsMaximumBits,
+}
+
+impl<W: Write, const BASE: BaseMaximum, const BYTES: usize> NitEncoder<W, BASE, BYTES> {
+	/// The amount of bytes within each word, and the amount of digits each word holds.
+	///
+	/// This is the amount of bytes, up to eight, which holds the most digits per byte, preferring fewer bytes.
+	/// This fails to evaluate at compile-time if the base is erroneous.
+	pub const WORD: (usize, FitsMaximumBits) = Word::<BASE>::SIZE;
+
+	/// Creates an encoder writing to the stream.
+	pub const fn new(writer: W) -> Self {
+		Self { writer, word: 0, count: 0 }
+	}
+
+	/// Returns a reference to the underlying stream.
+	pub const fn get_ref(&self) -> &W {
+		&self.writer
+	}
+
+	/// Writes a nit, writing its word once it's whole.
+	///
+	/// # Errors
+	/// Any error from writing to the stream; the nit isn't written.
+	pub fn write_nit(&mut self, nit: Nit<BASE, BYTES>) -> io::Result<()> {
+		let (_, digits) = Self::WORD;
+		let word = self.word + u64::from(nit.get_value()) * Powers::<u64, BASE>::TABLE[usize::from(self.count)];
+		if self.count + 1 == digits {
+			self.write_word(word)?;
+			(self.word, self.count) = (0, 0);
+		} else {
+			(self.word, self.count) = (word, self.count + 1);
+		}
+		Ok(())
+	}
+
+	/// Writes every nit in order.
+	///
+	/// # Errors
+	/// Any error from writing to the stream; the nits before the one which failed will have been written.
+	pub fn write_nits(&mut self, nits: impl IntoIterator<Item = Nit<BASE, BYTES>>) -> io::Result<()> {
+		nits.into_iter().try_for_each(|nit| self.write_nit(nit))
+	}
+
+	/// Writes the trailing partial word and the trailer, flushes the stream, and returns it.
+	///
+	/// # Errors
+	/// Any error from writing to or flushing the stream.
+	pub fn finish(mut self) -> io::Result<W> {
+		if self.count != 0 {
+			self.write_word(self.word)?;
+		}
+		// A word holds at most 64 digits, so the count fits within the byte.
+		#[allow(clippy::cast_possible_truncation)]
+		let count = self.count as u8;
+		self.writer.write_all(&[count])?;
+		self.writer.flush()?;
+		Ok(self.writer)
+	}
+
+	/// Writes the bytes of a word.
+	fn write_word(&mut self, word: u64) -> io::Result<()> {
+		let (bytes, _) = Self::WORD;
+		self.writer.write_all(&word.to_be_bytes()[MAXIMUM_WORD_BYTES - bytes..])
+	}
+}
+
+/// Reads nits from a byte stream written by a [`NitEncoder`] of the same base.
+///
+/// This is an iterator over the nits, which ends after the first error; see [`StreamError`] for the errors particular to the format.
+/// At most two words and the trailer are read ahead, so only the bytes of the last word are needed to know where the stream ends.
+#[derive(Debug)]
+pub struct NitDecoder<R: Read, const BASE: BaseMaximum, const BYTES: usize = 1> {
+	/// The stream the words are read from.
+	reader: R,
+	/// The bytes read ahead of the word being read.
+	buffer: [u8; LOOKAHEAD],
+	/// The amount of bytes within the buffer.
+	buffered: usize,
+	/// The value of the word being read, divided by `BASE` for each digit yielded from it.
+	word: u64,
+	/// The amount of digits left to yield from the word being read.
+	pending: FitsMaximumBits,
+	/// Whether the last word has been read, or an error has occurred.
+	done: bool,
+}
+
+impl<R: Read, const BASE: BaseMaximum, const BYTES: usize> NitDecoder<R, BASE, BYTES> {
+	/// The amount of bytes within each word, and the amount of digits each word holds.
+	///
+	/// See: [`NitEncoder::WORD`]
+	pub const WORD: (usize, FitsMaximumBits) = Word::<BASE>::SIZE;
+
+	/// Creates a decoder reading from the stream.
+	pub const fn new(reader: R) -> Self {
+		Self { reader, buffer: [0; LOOKAHEAD], buffered: 0, word: 0, pending: 0, done: false }
+	}
+
+	/// Returns a reference to the underlying stream.
+	pub const fn get_ref(&self) -> &R {
+		&self.reader
+	}
+
+	/// Reads the next word, returning [`None`] if the stream has ended.
+	fn read_word(&mut self) -> io::Result<Option<()>> {
+		let (bytes, digits) = Self::WORD;
+		let wanted = 2 * bytes + 1;
+		while self.buffered < wanted {
+			match self.reader.read(&mut self.buffer[self.buffered..wanted]) {
+				Ok(0) => break,
+				Ok(read) => self.buffered += read,
+				Err(err) if err.kind() == ErrorKind::Interrupted => {},
+				Err(err) => return Err(err),
+			}
+		}
+		// With another word after it, this one is whole; otherwise, it's either the last word followed by the trailer, or there's only the trailer.
+		let len = if self.buffered == wanted {
+			digits
+		} else if self.buffered == bytes + 1 {
+			self.done = true;
+			match self.buffer[bytes] {
+				0 => digits,
+				trailer if FitsMaximumBits::from(trailer) < digits => FitsMaximumBits::from(trailer),
+				_ => return Err(StreamError::InvalidTrailer.into()),
+			}
+		} else if self.buffered == 1 {
+			self.done = true;
+			return if self.buffer[0] == 0 { Ok(None) } else { Err(StreamError::InvalidTrailer.into()) };
+		} else {
+			return Err(StreamError::Truncated.into());
+		};
+		let mut word = [0; MAXIMUM_WORD_BYTES];
+		word[MAXIMUM_WORD_BYTES - bytes..].copy_from_slice(&self.buffer[..bytes]);
+		let word = u64::from_be_bytes(word);
+		// The padding of the last word must be zero, so the value is less than `BASE.pow(len)`, which wraps to zero for a base of two.
+		if word > Powers::<u64, BASE>::power(len).wrapping_sub(1) {
+			return Err(StreamError::InvalidWord.into());
+		}
+		self.buffer.copy_within(bytes..self.buffered, 0);
+		self.buffered -= bytes;
+		(self.word, self.pending) = (word, len);
+		Ok(Some(()))
+	}
+}
+
+impl<R: Read, const BASE: BaseMaximum, const BYTES: usize> Iterator for NitDecoder<R, BASE, BYTES> {
+	type Item = io::Result<Nit<BASE, BYTES>>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.pending == 0 {
+			if self.done {
+				return None;
+			}
+			match self.read_word() {
+				Ok(Some(())) => {},
+				Ok(None) => return None,
+				Err(err) => {
+					self.done = true;
+					return Some(Err(err));
+				},
+			}
+		}
+		let reciprocal = Powers::<u64, BASE>::BASE_RECIPROCAL;
+		let quotient = reciprocal.divide(self.word);
+		let digit = self.word - quotient * reciprocal.divisor();
+		(self.word, self.pending) = (quotient, self.pending - 1);
+		// SAFETY: The value will be always within the range of `0..BASE` because it is the remainder of a division by `BASE`.
+		unsafe { #[allow(clippy::cast_possible_truncation, clippy::cast_lossless)] let digit = digit as BaseMaximum; Some(Ok(Nit::new_unchecked(digit))) }
+	}
+}
+
+impl<R: Read, const BASE: BaseMaximum, const BYTES: usize> FusedIterator for NitDecoder<R, BASE, BYTES> {}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::missing_docs_in_private_items)]
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+
+	/// A reader which yields a single byte at a time, to exercise reading ahead across short reads.
+	struct Trickle<'a>(&'a [u8]);
+
+	impl Read for Trickle<'_> {
+		fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+			match (self.0.split_first(), buf.first_mut()) {
+				(Some((&byte, rest)), Some(slot)) => {
+					*slot = byte;
+					self.0 = rest;
+					Ok(1)
+				},
+				_ => Ok(0),
+			}
+		}
+	}
+
+	fn check_round_trips<const BASE: BaseMaximum, const BYTES: usize>() {
+		let mut state = 0x2545_F491_u32;
+		let original: Vec<Nit<BASE, BYTES>> = (0..300).map(|_| {
+			state ^= state << 13;
+			state ^= state >> 17;
+			state ^= state << 5;
+			Nit::new(state % BASE).unwrap()
+		}).collect();
+		let (bytes, digits) = NitEncoder::<Vec<u8>, BASE, BYTES>::WORD;
+		for len in [0, 1, usize::from(digits) - 1, usize::from(digits), usize::from(digits) + 1, 299, 300] {
+			let mut encoder = NitEncoder::<_, BASE, BYTES>::new(Vec::new());
+			encoder.write_nits(original[..len].iter().copied()).unwrap();
+			let stream = encoder.finish().unwrap();
+			assert_eq!(stream.len(), len.div_ceil(usize::from(digits)) * bytes + 1);
+			let decoded = NitDecoder::<_, BASE, BYTES>::new(stream.as_slice()).collect::<io::Result<Vec<_>>>().unwrap();
+			assert_eq!(decoded, &original[..len], "{len} digits in base {BASE}");
+			let decoded = NitDecoder::<_, BASE, BYTES>::new(Trickle(&stream)).collect::<io::Result<Vec<_>>>().unwrap();
+			assert_eq!(decoded, &original[..len], "{len} digits in base {BASE}, read a byte at a time");
+		}
+	}
+
+	#[test]
+	fn round_trips() {
+		check_round_trips::<2, 1>();
+		check_round_trips::<3, 1>();
+		check_round_trips::<10, 1>();
+		check_round_trips::<85, 1>();
+		check_round_trips::<128, 1>();
+		check_round_trips::<1000, 2>();
+		check_round_trips::<60000, 2>();
+		check_round_trips::<100_000, 4>();
+	}
+
+	#[test]
+	fn words() {
+		assert_eq!(NitEncoder::<Vec<u8>, 2>::WORD, (1, 8));
+		assert_eq!(NitEncoder::<Vec<u8>, 3>::WORD, (1, 5));
+		assert_eq!(NitEncoder::<Vec<u8>, 10>::WORD, (5, 12));
+		assert_eq!(NitEncoder::<Vec<u8>, 85>::WORD, (5, 6));
+		assert_eq!(NitEncoder::<Vec<u8>, 1000, 2>::WORD, (5, 4));
+		assert_eq!(NitEncoder::<Vec<u8>, 60000, 2>::WORD, (2, 1));
+	}
+
+	#[test]
+	fn errors() {
+		let error = |stream: &[u8]| {
+			NitDecoder::<_, 3>::new(stream).find_map(Result::err).map(|err| (err.kind(), err.into_inner().unwrap().downcast::<StreamError>().unwrap()))
+		};
+		assert_eq!(error(&[]), Some((ErrorKind::UnexpectedEof, Box::new(StreamError::Truncated))));
+		assert_eq!(error(&[1]), Some((ErrorKind::InvalidData, Box::new(StreamError::InvalidTrailer))));
+		assert_eq!(error(&[0, 5]), Some((ErrorKind::InvalidData, Box::new(StreamError::InvalidTrailer))));
+		// 243 isn't the value of any five trits, and 9 isn't the value of two.
+		assert_eq!(error(&[243, 0]), Some((ErrorKind::InvalidData, Box::new(StreamError::InvalidWord))));
+		assert_eq!(error(&[9, 2]), Some((ErrorKind::InvalidData, Box::new(StreamError::InvalidWord))));
+		assert_eq!(error(&[8, 2]), None);
+		assert_eq!(NitDecoder::<_, 10>::new([0; 7].as_slice()).find_map(Result::err).map(|err| err.kind()), Some(ErrorKind::UnexpectedEof));
+		let mut decoder = NitDecoder::<_, 3>::new([0, 7].as_slice());
+		assert!(decoder.next().unwrap().is_err());
+		assert!(decoder.next().is_none());
+	}
+}
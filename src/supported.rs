@@ -1,26 +1,40 @@
-/// The smallest type that can contain the maximum amount of bits supported for an integer type in this library.
+/// The smallest type that can contain the maximum amount of bits supported for a container in this library.
 ///
-/// Currently, the exact limit is 128 bits (as seen in [`MAXIMUM_SUPPORTED_BITS`]).
-/// This leaves a potential future expansion to 256 bits, but this isn't a current native integer size, and likely wouldn't be performant, so there isn't anything done for bases above 128.
+/// Currently, the exact limit is 1024 bits (as seen in [`MAXIMUM_SUPPORTED_BITS`]), which is the width of the widest [`WideUint`](crate::wide::WideUint).
 ///
 /// # See Also
 /// - [`MAXIMUM_SUPPORTED_BITS`]
-/// - [`FitsMaximumBitsAsType`]
-pub type FitsMaximumBits = u8;
-/// The maximum amount of bits supported for an integer type in this library.
-/// This also determines the highest base, as anything further would require an equal or more amount of bits to represent.
+/// - [`MAXIMUM_NATIVE_BITS`]
+pub type FitsMaximumBits = u16;
+/// The maximum amount of bits supported for a container in this library.
 ///
 /// # See Also
 /// - [`FitsMaximumBits`]
-/// - [`FitsMaximumBitsAsType`]
-pub const MAXIMUM_SUPPORTED_BITS: FitsMaximumBits = 128;
-/// The native integer type utilizing the maximum amount of bits supported by this library.
+/// - [`MAXIMUM_NATIVE_BITS`]
+pub const MAXIMUM_SUPPORTED_BITS: FitsMaximumBits = 1024;
+/// The maximum amount of bits of a native integer type; those of [`FitsMaximumBitsAsType`].
+///
+/// Computations which produce a value of the bits, rather than an amount of digits, are limited to this.
 ///
 /// # See Also
 /// - [`FitsMaximumBits`]
 /// - [`MAXIMUM_SUPPORTED_BITS`]
+pub const MAXIMUM_NATIVE_BITS: FitsMaximumBits = 128;
+/// The native integer type utilizing the maximum amount of bits of any native integer type.
+///
+/// # See Also
+/// - [`FitsMaximumBits`]
+/// - [`MAXIMUM_NATIVE_BITS`]
 pub type FitsMaximumBitsAsType = u128;
 
-/// The maximum supported base for a number.
-pub type BaseMaximum = FitsMaximumBits;
+/// The type of a base, and of the value of a digit, which falls in the range of `0..BASE`.
+///
+/// This is wider than [`FitsMaximumBits`], so that digits of bases such as 1000 or 60000 can be packed several to a word.
+/// A base is only supported by an integer type if at least one of its digits fits within it; see [`compute_max_nits_in_bits`](crate::max_nits::compute_max_nits_in_bits).
+pub type BaseMaximum = u32;
+
+/// The signed counterpart of [`BaseMaximum`], used for the values of balanced digits, which fall in the range of `-(BASE / 2)..=(BASE / 2)`.
+pub type BaseMaximumSigned = i32;
 
+/// The amount of bytes of a [`BaseMaximum`], within which a [`Nit`](crate::Nit) holds the digits of any base.
+pub const BASE_MAXIMUM_BYTES: usize = core::mem::size_of::<BaseMaximum>();
@@ -0,0 +1,485 @@
+use core::iter::FusedIterator;
+use crate::Nit;
+use crate::internal_macros::{deriving_const, impl_error};
+use crate::base::Powers;
+use crate::max_nits::{compute_max_nits_in_bits, compute_nits_required};
+use crate::supported::{BaseMaximum, FitsMaximumBitsAsType};
+
+deriving_const!((PartialEq) for {
+	/// The reason transcoding failed.
+	#[derive(Debug, Clone, Copy, Eq, Hash)]
+	pub enum TranscodeError {
+		/// The output doesn't have room for every transcoded digit.
+		OutputTooShort,
+		/// The amount of encoded digits isn't the amount which the requested amount of decoded digits is encoded as.
+		LengthMismatch,
+		/// The encoded digits hold a value which no original digits are encoded as.
+		Overflow,
+	}
+});
+impl TranscodeError {
+	/// Returns the error message as a string.
+	#[must_use]
+	#[cfg(not(tarpaulin_include))]
+	pub const fn get_str(&self) -> &str {
+		match self {
+			Self::OutputTooShort => "The output doesn't have room for every transcoded digit.",
+			Self::LengthMismatch => "The amount of encoded digits isn't the amount which the requested amount of decoded digits is encoded as.",
+			Self::Overflow => "The encoded digits hold a value which no original digits are encoded as.",
+		}
+	}
+}
+#[cfg(not(tarpaulin_include))]
+impl core::fmt::Display for TranscodeError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "{}", self.get_str())
+	}
+}
+impl_error!(TranscodeError);
+
+/// Converts sequences of base-`FROM` digits into base-`TO` digits and back, both starting from the least significant digit (right-hand side).
+///
+/// - If either base is a power of the other, the digits are transcoded in groups: each group is a single digit of the larger base, and the digits of the smaller base it's made of.
+///   For example, every two trits are grouped into one base-9 digit, and every base-9 digit is split into two trits.
+///   A trailing partial group of the smaller base is transcoded as a single digit of the larger base.
+/// - Otherwise, the whole sequence is treated as one number, and transcoded as the fewest base-`TO` digits which can hold any value of as many base-`FROM` digits
+///   (see [`compute_nits_required`]), by multiplying the digits written so far by a power of `FROM`, and adding the next few base-`FROM` digits to them.
+///   This takes time proportional to the product of both amounts of digits.
+///
+/// The amount of encoded digits only depends on the amount of original digits (see [`encoded_len`](Self::encoded_len)).
+/// The amount of original digits is needed to decode them, which is what makes round-trips lossless;
+/// a round-trip through a different number of digits could otherwise gain or lose leading zeroes.
+///
+/// Note that `Transcoder<FROM, TO>` decoding isn't the same as `Transcoder<TO, FROM>` encoding unless either base is a power of the other, as the amounts of digits differ.
+///
+/// # Example
+/// ```
+/// use nit::transcode::Transcoder;
+/// use nit::{Bit, Nit, Trit};
+///
+/// let trits = [Trit::TWO, Trit::ONE, Trit::ZERO, Trit::TWO, Trit::ONE];
+/// // Grouped two at a time, with the last one on its own.
+/// let nonits: Vec<Nit<9>> = Transcoder::<3, 9>::encode(trits).collect();
+/// assert_eq!(nonits, [Nit::new(2 + 1 * 3).unwrap(), Nit::new(0 + 2 * 3).unwrap(), Nit::new(1).unwrap()]);
+///
+/// // Five trits hold up to 242, which needs eight bits.
+/// assert_eq!(Transcoder::<3, 2>::encoded_len(5), 8);
+/// let mut bits = [Bit::ZERO; 8];
+/// assert_eq!(Transcoder::<3, 2>::encode_into(&trits, &mut bits), Ok(8));
+/// // 2 + 1 * 3 + 0 * 9 + 2 * 27 + 1 * 81 is 140.
+/// assert_eq!(bits.map(|bit| bit.get_value()), [0, 0, 1, 1, 0, 0, 0, 1]);
+/// let mut decoded = [Trit::ZERO; 5];
+/// assert_eq!(Transcoder::<3, 2>::decode_into(&bits, &mut decoded), Ok(()));
+/// assert_eq!(decoded, trits);
+///
+/// // A thousand trits are a single number of 1585 bits, rather than 25 numbers of 64 bits.
+/// assert_eq!(Transcoder::<3, 2>::encoded_len(1000), 1585);
+///
+/// // The digits of bases above 256 are stored within more bytes; every three decimal digits are one base-1000 digit.
+/// let decimal = [7, 0, 5, 1].map(|digit| Nit::<10>::new(digit).unwrap());
+/// let thousands: Vec<Nit<1000, 2>> = Transcoder::<10, 1000>::encode(decimal).collect();
+/// assert_eq!(thousands, [Nit::new(507).unwrap(), Nit::new(1).unwrap()]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Transcoder<const FROM: BaseMaximum, const TO: BaseMaximum>;
+
+impl<const FROM: BaseMaximum, const TO: BaseMaximum> Transcoder<FROM, TO> {
+	/// The amount of base-`FROM` digits within each group, and the amount of base-`TO` digits each group is encoded as, if either base is a power of the other.
+	/// Otherwise, this is [`None`], as the digits are transcoded as one number.
+	///
+	/// This fails to evaluate at compile-time if either base is erroneous.
+	pub const BLOCK: Option<(usize, usize)> = {
+		if let Err(err) = compute_max_nits_in_bits::<FROM, 64>() {
+			panic!("{}", err.get_str());
+		}
+		if let Err(err) = compute_max_nits_in_bits::<TO, 64>() {
+			panic!("{}", err.get_str());
+		}
+		#[allow(clippy::cast_lossless)]
+		let (from, to) = (FROM as FitsMaximumBitsAsType, TO as FitsMaximumBitsAsType);
+		if let Some(exponent) = exact_log(to, from) {
+			Some((exponent, 1))
+		} else if let Some(exponent) = exact_log(from, to) {
+			Some((1, exponent))
+		} else {
+			None
+		}
+	};
+
+	/// Evaluated at compile-time without the `alloc` feature, failing if neither base is a power of the other,
+	/// as none of the digits of one number can be yielded until all of them are read.
+	#[cfg(not(feature = "alloc"))]
+	const ASSERT_GROUPED: () = assert!(Self::BLOCK.is_some(), "Encoding an iterator as one number requires the `alloc` feature.");
+
+	/// Returns the amount of base-`TO` digits that `n` base-`FROM` digits are encoded as.
+	///
+	/// This saturates at [`usize::MAX`] where it would be too many to ever be held.
+	///
+	/// # Example
+	/// ```
+	/// use nit::transcode::Transcoder;
+	/// assert_eq!(Transcoder::<2, 8>::encoded_len(7), 3);
+	/// assert_eq!(Transcoder::<8, 2>::encoded_len(7), 21);
+	/// assert_eq!(Transcoder::<10, 3>::encoded_len(0), 0);
+	/// // Four trits hold up to 80, which needs two base-10 digits, but 400 trits need 191.
+	/// assert_eq!(Transcoder::<3, 10>::encoded_len(4), 2);
+	/// assert_eq!(Transcoder::<3, 10>::encoded_len(400), 191);
+	/// ```
+	#[must_use]
+	#[allow(clippy::cast_possible_truncation)]
+	pub const fn encoded_len(n: usize) -> usize {
+		match Self::BLOCK {
+			Some((size, encoded)) => n / size * encoded + if n.is_multiple_of(size) { 0 } else { 1 },
+			// The bases are valid, so this can only fail for amounts of digits that couldn't be held either.
+			None => match compute_nits_required::<FROM, TO>(n as u64) {
+				Ok(len) if len <= usize::MAX as u64 => len as usize,
+				_ => usize::MAX,
+			},
+		}
+	}
+
+	/// Returns an iterator over the base-`TO` digits that the base-`FROM` digits are encoded as.
+	///
+	/// If either base is a power of the other, the digits are read a group at a time, so the source is only required to be finite.
+	/// Otherwise, every digit is read before the first is yielded, which requires the `alloc` feature; this fails to compile without it.
+	#[must_use]
+	pub fn encode<I: IntoIterator<Item = Nit<FROM, FROM_BYTES>>, const FROM_BYTES: usize, const TO_BYTES: usize>(nits: I) -> Encode<I::IntoIter, FROM, TO, TO_BYTES> {
+		#[cfg(not(feature = "alloc"))]
+		let () = Self::ASSERT_GROUPED;
+		Encode::new(nits.into_iter())
+	}
+
+	/// Encodes the base-`FROM` digits as base-`TO` digits into `out`, returning the amount written, which is [`encoded_len`](Self::encoded_len) of the input.
+	/// Digits in `out` beyond the amount written are left unchanged.
+	///
+	/// # Errors
+	/// - [`TranscodeError::OutputTooShort`]: If `out` is shorter than the amount of encoded digits; nothing is written.
+	pub fn encode_into<const FROM_BYTES: usize, const TO_BYTES: usize>(nits: &[Nit<FROM, FROM_BYTES>], out: &mut [Nit<TO, TO_BYTES>]) -> Result<usize, TranscodeError> {
+		let len = Self::encoded_len(nits.len());
+		let Some(out) = out.get_mut(..len) else { return Err(TranscodeError::OutputTooShort) };
+		if Self::BLOCK.is_none() {
+			// The encoded digits can hold any value of the original digits, so this can't overflow.
+			return convert(nits, out).map(|()| len);
+		}
+		for (slot, nit) in out.iter_mut().zip(Encode::<_, FROM, TO, TO_BYTES>::new(nits.iter().copied())) {
+			*slot = nit;
+		}
+		Ok(len)
+	}
+
+	/// Decodes base-`TO` digits back into the `out.len()` base-`FROM` digits they were encoded from.
+	///
+	/// # Errors
+	/// - [`TranscodeError::LengthMismatch`]: If the amount of encoded digits isn't [`encoded_len`](Self::encoded_len) of `out.len()`; nothing is written.
+	/// - [`TranscodeError::Overflow`]: If the encoded digits hold a value which can't have been encoded:
+	///   a trailing digit of the larger base beyond the partial group it was encoded from, or a number with more than `out.len()` base-`FROM` digits.
+	///   Some of `out` will have been written.
+	///
+	/// # Example
+	/// ```
+	/// use nit::transcode::{Transcoder, TranscodeError};
+	/// use nit::{Bit, Trit};
+	/// let mut trits = [Trit::ZERO; 5];
+	/// // 255 doesn't fit within five trits.
+	/// assert_eq!(Transcoder::<3, 2>::decode_into(&[Bit::ONE; 8], &mut trits), Err(TranscodeError::Overflow));
+	/// assert_eq!(Transcoder::<3, 2>::decode_into(&[Bit::ONE; 7], &mut trits), Err(TranscodeError::LengthMismatch));
+	/// ```
+	pub fn decode_into<const FROM_BYTES: usize, const TO_BYTES: usize>(nits: &[Nit<TO, TO_BYTES>], out: &mut [Nit<FROM, FROM_BYTES>]) -> Result<(), TranscodeError> {
+		if nits.len() != Self::encoded_len(out.len()) {
+			return Err(TranscodeError::LengthMismatch);
+		}
+		let Some((size, _)) = Self::BLOCK else { return convert(nits, out) };
+		let mut encoded = nits;
+		#[allow(clippy::cast_lossless)]
+		let (from, to) = (FROM as FitsMaximumBitsAsType, TO as FitsMaximumBitsAsType);
+		for chunk in out.chunks_mut(size) {
+			let (group, rest) = encoded.split_at(Self::encoded_len(chunk.len()));
+			encoded = rest;
+			// A group of the smaller base is a single digit of the larger one, so this can't overflow.
+			let mut value = group.iter().rev().fold(0, |value, nit| value * to + FitsMaximumBitsAsType::from(nit.get_value()));
+			#[allow(clippy::cast_possible_truncation)]
+			if value >= from.pow(chunk.len() as u32) {
+				return Err(TranscodeError::Overflow);
+			}
+			for slot in chunk {
+				// SAFETY: The value will be always within the range of `0..FROM` because of the modulo operation.
+				*slot = unsafe { #[allow(clippy::cast_possible_truncation)] let digit = (value % from) as BaseMaximum; Nit::new_unchecked(digit) };
+				value /= from;
+			}
+		}
+		Ok(())
+	}
+}
+
+/// Returns `k` if `value` is `base.pow(k)` for some `k` greater than zero.
+const fn exact_log(value: FitsMaximumBitsAsType, base: FitsMaximumBitsAsType) -> Option<usize> {
+	let mut power = base;
+	let mut exponent = 1;
+	while power < value {
+		power *= base;
+		exponent += 1;
+	}
+	if power == value { Some(exponent) } else { None }
+}
+
+/// Writes the number which the base-`A` digits are into `out` as base-`B` digits, both starting from the least significant digit.
+///
+/// The base-`A` digits are read from the most significant, as many at a time as their power times `B` fits within a `u64`,
+/// and each time, the places written so far are multiplied by that power, and the digits read are added.
+///
+/// # Errors
+/// - [`TranscodeError::Overflow`]: If the number doesn't fit within `out`; some of it will have been written.
+fn convert<const A: BaseMaximum, const A_BYTES: usize, const B: BaseMaximum, const B_BYTES: usize>(nits: &[Nit<A, A_BYTES>], out: &mut [Nit<B, B_BYTES>]) -> Result<(), TranscodeError> {
+	// SAFETY: Zero is within the range of `0..B`.
+	out.fill(unsafe { Nit::new_unchecked(0) });
+	let (from, to) = (u64::from(A), u64::from(B));
+	// Both bases are at least two, and less than `2^32`, so at least one digit is read at a time.
+	let group = (u64::MAX / to).ilog(from) as usize;
+	let reciprocal = Powers::<u64, B>::BASE_RECIPROCAL;
+	// The amount of the least significant places of `out` which the number read so far spans.
+	let mut len = 0;
+	for chunk in nits.rchunks(group) {
+		let (mut carry, scale) = chunk.iter().rev().fold((0, 1), |(value, scale), nit| (value * from + u64::from(nit.get_value()), scale * from));
+		for slot in &mut out[..len] {
+			// The carry is less than the scale, so this is less than `B` times the scale, which fits within a `u64`.
+			let value = u64::from(slot.get_value()) * scale + carry;
+			carry = reciprocal.divide(value);
+			// SAFETY: The value will be always within the range of `0..B`, as it's the remainder of a division by `B`.
+			*slot = unsafe { #[allow(clippy::cast_possible_truncation)] let digit = (value - carry * to) as BaseMaximum; Nit::new_unchecked(digit) };
+		}
+		while carry > 0 {
+			let Some(slot) = out.get_mut(len) else { return Err(TranscodeError::Overflow) };
+			let quotient = reciprocal.divide(carry);
+			// SAFETY: The value will be always within the range of `0..B`, as it's the remainder of a division by `B`.
+			*slot = unsafe { #[allow(clippy::cast_possible_truncation)] let digit = (carry - quotient * to) as BaseMaximum; Nit::new_unchecked(digit) };
+			(carry, len) = (quotient, len + 1);
+		}
+	}
+	Ok(())
+}
+
+/// An iterator over the base-`TO` digits that base-`FROM` digits are encoded as, each stored within `TO_BYTES` bytes; see [`Transcoder::encode`].
+#[derive(Debug, Clone)]
+pub struct Encode<I, const FROM: BaseMaximum, const TO: BaseMaximum, const TO_BYTES: usize = 1> {
+	/// The base-`FROM` digits which haven't been read yet.
+	source: I,
+	/// The value of the group being encoded, divided by `TO` for each digit yielded from it.
+	block: FitsMaximumBitsAsType,
+	/// The amount of digits left to yield from the group.
+	pending: usize,
+	/// Whether the source has run out of digits.
+	exhausted: bool,
+	/// The digits of the number which every base-`FROM` digit is, once they have all been read, if neither base is a power of the other.
+	#[cfg(feature = "alloc")]
+	number: Option<alloc::vec::IntoIter<Nit<TO, TO_BYTES>>>,
+}
+
+impl<I: Iterator<Item = Nit<FROM, FROM_BYTES>>, const FROM: BaseMaximum, const TO: BaseMaximum, const FROM_BYTES: usize, const TO_BYTES: usize> Encode<I, FROM, TO, TO_BYTES> {
+	/// Returns an iterator over the digits that the source is encoded as, without asserting that it can be.
+	const fn new(source: I) -> Self {
+		Self {
+			source,
+			block: 0,
+			pending: 0,
+			exhausted: false,
+			#[cfg(feature = "alloc")]
+			number: None,
+		}
+	}
+
+	/// Reads every digit of the source, and returns an iterator over the digits of the number they are.
+	#[cfg(feature = "alloc")]
+	fn read_number(&mut self) -> alloc::vec::IntoIter<Nit<TO, TO_BYTES>> {
+		let nits: alloc::vec::Vec<Nit<FROM, FROM_BYTES>> = self.source.by_ref().collect();
+		// SAFETY: Zero is within the range of `0..TO`.
+		let mut out = alloc::vec![unsafe { Nit::new_unchecked(0) }; Transcoder::<FROM, TO>::encoded_len(nits.len())];
+		// The encoded digits can hold any value of the original digits, so this can't overflow.
+		let _ = convert(&nits, &mut out);
+		self.exhausted = true;
+		out.into_iter()
+	}
+}
+
+impl<I: Iterator<Item = Nit<FROM, FROM_BYTES>>, const FROM: BaseMaximum, const TO: BaseMaximum, const FROM_BYTES: usize, const TO_BYTES: usize> Iterator for Encode<I, FROM, TO, TO_BYTES> {
+	type Item = Nit<TO, TO_BYTES>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let Some((size, _)) = Transcoder::<FROM, TO>::BLOCK else {
+			#[cfg(feature = "alloc")]
+			{
+				if self.number.is_none() {
+					self.number = Some(self.read_number());
+				}
+				return self.number.as_mut().and_then(Iterator::next);
+			}
+			// Without the `alloc` feature, this is never constructed for such bases, as `Transcoder::encode` asserts.
+			#[cfg(not(feature = "alloc"))]
+			return None;
+		};
+		#[allow(clippy::cast_lossless)]
+		let (from, to) = (FROM as FitsMaximumBitsAsType, TO as FitsMaximumBitsAsType);
+		if self.pending == 0 {
+			if self.exhausted {
+				return None;
+			}
+			let (mut value, mut power, mut read) = (0, 1, 0);
+			while read < size {
+				let Some(nit) = self.source.next() else {
+					self.exhausted = true;
+					break;
+				};
+				value += FitsMaximumBitsAsType::from(nit.get_value()) * power;
+				power *= from;
+				read += 1;
+			}
+			if read == 0 {
+				return None;
+			}
+			self.block = value;
+			self.pending = Transcoder::<FROM, TO>::encoded_len(read);
+		}
+		let digit = self.block % to;
+		self.block /= to;
+		self.pending -= 1;
+		// SAFETY: The value will be always within the range of `0..TO` because of the modulo operation.
+		unsafe { #[allow(clippy::cast_possible_truncation, clippy::cast_lossless)] let digit = digit as BaseMaximum; Some(Nit::new_unchecked(digit)) }
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		#[cfg(feature = "alloc")]
+		if let Some(number) = &self.number {
+			return number.size_hint();
+		}
+		if self.exhausted {
+			return (self.pending, Some(self.pending));
+		}
+		// Any group being encoded is full, so the remaining digits start a new one, or are all of the number.
+		let (lower, upper) = self.source.size_hint();
+		(
+			self.pending + Transcoder::<FROM, TO>::encoded_len(lower),
+			upper.map(|upper| self.pending + Transcoder::<FROM, TO>::encoded_len(upper)),
+		)
+	}
+}
+
+impl<I: Iterator<Item = Nit<FROM, FROM_BYTES>>, const FROM: BaseMaximum, const TO: BaseMaximum, const FROM_BYTES: usize, const TO_BYTES: usize> FusedIterator for Encode<I, FROM, TO, TO_BYTES> {}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::missing_docs_in_private_items)]
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+
+	/// Pseudo-random digits, with good enough coverage of every value.
+	fn digits<const BASE: BaseMaximum, const BYTES: usize>(n: usize, seed: u64) -> Vec<Nit<BASE, BYTES>> {
+		let mut state = seed;
+		(0..n).map(|_| {
+			state = state.wrapping_mul(0x5851_F42D_4C95_7F2D).wrapping_add(0x1405_7B7E_F767_814F);
+			#[allow(clippy::cast_possible_truncation)]
+			Nit::new(((state >> 33) % u64::from(BASE)) as BaseMaximum).unwrap()
+		}).collect()
+	}
+
+	fn check_round_trips<const FROM: BaseMaximum, const FROM_BYTES: usize, const TO: BaseMaximum, const TO_BYTES: usize>() {
+		for n in 0..150 {
+			let original = digits::<FROM, FROM_BYTES>(n, n as u64);
+			let mut encoded = vec![Nit::<TO, TO_BYTES>::new(0).unwrap(); Transcoder::<FROM, TO>::encoded_len(n)];
+			assert_eq!(Transcoder::<FROM, TO>::encode_into(&original, &mut encoded), Ok(encoded.len()));
+			#[cfg(feature = "alloc")]
+			{
+				let iterated = Transcoder::<FROM, TO>::encode(original.iter().copied());
+				assert_eq!(iterated.size_hint(), (encoded.len(), Some(encoded.len())));
+				assert_eq!(iterated.collect::<Vec<_>>(), encoded, "{n} digits from base {FROM} to {TO}");
+			}
+			let mut decoded = vec![Nit::new(0).unwrap(); n];
+			assert_eq!(Transcoder::<FROM, TO>::decode_into(&encoded, &mut decoded), Ok(()));
+			assert_eq!(decoded, original, "{n} digits from base {FROM} to {TO}");
+		}
+	}
+
+	#[test]
+	fn round_trips() {
+		check_round_trips::<3, 1, 9, 1>();
+		check_round_trips::<9, 1, 3, 1>();
+		check_round_trips::<3, 1, 2, 1>();
+		check_round_trips::<2, 1, 3, 1>();
+		check_round_trips::<2, 1, 8, 1>();
+		check_round_trips::<8, 1, 2, 1>();
+		check_round_trips::<10, 1, 7, 1>();
+		check_round_trips::<128, 1, 3, 1>();
+		check_round_trips::<3, 1, 128, 1>();
+		check_round_trips::<4, 1, 8, 1>();
+		check_round_trips::<9, 1, 27, 1>();
+		check_round_trips::<10, 1, 256, 1>();
+		check_round_trips::<5, 1, 5, 1>();
+		check_round_trips::<10, 1, 1000, 2>();
+		check_round_trips::<1000, 2, 10, 1>();
+		check_round_trips::<1000, 2, 60000, 2>();
+		check_round_trips::<60000, 2, 3, 1>();
+		check_round_trips::<1000, 2, 1000, 4>();
+	}
+
+	fn check_one_number<const FROM: BaseMaximum, const FROM_BYTES: usize, const TO: BaseMaximum, const TO_BYTES: usize>() {
+		// Where the number fits within a `u128`, its digits are those of its value.
+		let (from, to) = (u128::from(FROM), u128::from(TO));
+		for n in 0..=usize::from(compute_max_nits_in_bits::<FROM, 128>().unwrap()) {
+			let original = digits::<FROM, FROM_BYTES>(n, n as u64 + 1);
+			let len = Transcoder::<FROM, TO>::encoded_len(n);
+			let max = from.checked_pow(u32::try_from(n).unwrap()).map_or(u128::MAX, |power| power - 1);
+			assert_eq!(len, if n == 0 { 0 } else { max.ilog(to) as usize + 1 }, "{n} digits from base {FROM} to {TO}");
+			let mut value = original.iter().rev().fold(0, |value, nit| value * from + u128::from(nit.get_value()));
+			let mut encoded = vec![Nit::<TO, TO_BYTES>::new(0).unwrap(); len];
+			assert_eq!(Transcoder::<FROM, TO>::encode_into(&original, &mut encoded), Ok(len));
+			for nit in encoded {
+				assert_eq!(u128::from(nit.get_value()), value % to, "{n} digits from base {FROM} to {TO}");
+				value /= to;
+			}
+			assert_eq!(value, 0);
+		}
+	}
+
+	#[test]
+	fn one_number() {
+		check_one_number::<3, 1, 2, 1>();
+		check_one_number::<2, 1, 3, 1>();
+		check_one_number::<10, 1, 7, 1>();
+		check_one_number::<4, 1, 8, 1>();
+		check_one_number::<3, 1, 10, 1>();
+		check_one_number::<200, 1, 3, 1>();
+		check_one_number::<1000, 2, 7, 1>();
+		check_one_number::<3, 1, 60000, 2>();
+		// Forty trits fit within 64 bits, and one more within 65, rather than as a block and a partial block.
+		assert_eq!(Transcoder::<3, 2>::encoded_len(40), 64);
+		assert_eq!(Transcoder::<3, 2>::encoded_len(41), 65);
+		assert_eq!(Transcoder::<3, 2>::encoded_len(usize::MAX), usize::MAX);
+	}
+
+	#[test]
+	fn grouping() {
+		assert_eq!(Transcoder::<3, 27>::BLOCK, Some((3, 1)));
+		assert_eq!(Transcoder::<27, 3>::BLOCK, Some((1, 3)));
+		assert_eq!(Transcoder::<5, 5>::BLOCK, Some((1, 1)));
+		assert_eq!(Transcoder::<3, 2>::BLOCK, None);
+		assert_eq!(Transcoder::<4, 8>::BLOCK, None);
+		let trits = digits::<3, 1>(31, 7);
+		let grouped: Vec<Nit<27>> = Transcoder::<3, 27>::encode(trits.iter().copied()).collect();
+		for (i, nit) in grouped.iter().enumerate() {
+			let group = trits[i * 3..].iter().take(3).rev().fold(0, |value, trit| value * 3 + trit.get_value());
+			assert_eq!(nit.get_value(), group);
+		}
+		// Grouping and splitting are each other's inverse.
+		let split: Vec<Nit<3>> = Transcoder::<27, 3>::encode(grouped.iter().copied()).collect();
+		assert_eq!(split[..31], trits[..]);
+	}
+
+	#[test]
+	fn errors() {
+		let mut out = [Nit::<2>::new(0).unwrap(); 7];
+		assert_eq!(Transcoder::<3, 2>::encode_into(&[Nit::<3>::new(0).unwrap(); 5], &mut out), Err(TranscodeError::OutputTooShort));
+		let mut out = [Nit::<3>::new(0).unwrap(); 40];
+		assert_eq!(Transcoder::<3, 2>::decode_into(&[Nit::<2>::new(1).unwrap(); 64], &mut out), Err(TranscodeError::Overflow));
+		// A trailing base-9 digit holds more than the single trit it was encoded from.
+		let mut out = [Nit::<3>::new(0).unwrap(); 3];
+		assert_eq!(Transcoder::<3, 9>::decode_into(&[Nit::<9>::new(8).unwrap(), Nit::new(3).unwrap()], &mut out), Err(TranscodeError::Overflow));
+	}
+}
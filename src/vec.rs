@@ -0,0 +1,339 @@
+use alloc::vec::Vec;
+use crate::Nit;
+use crate::array::{NitArrayWord, WORD_BITS, words_required};
+use crate::base::Powers;
+use crate::data_container::NitDataContainer;
+use crate::max_nits::compute_max_nits_in_bits;
+use crate::places::{PlacesIndex, PlacesIndexCreationError};
+use crate::supported::{BaseMaximum, FitsMaximumBits};
+
+/// A growable list of base-`BASE` digits, densely packed into [`u64`](NitArrayWord)s.
+///
+/// The layout of the words is the same as a [`NitArray`](crate::array::NitArray)'s;
+/// every place past the length within the last word is always zero.
+/// The digits are read and written as [`Nit<BASE, BYTES>`](Nit)s, so bases above 256 need a wider `BYTES`.
+///
+/// # Example
+/// ```
+/// use nit::vec::NitVec;
+/// use nit::Trit;
+///
+/// let mut trits = NitVec::<3>::new();
+/// trits.extend([Trit::ONE, Trit::TWO, Trit::ZERO]);
+/// trits.push(Trit::TWO);
+/// assert_eq!(trits.len(), 4);
+/// assert_eq!(trits.as_words(), &[1 + 2 * 3 + 2 * 27]);
+/// assert_eq!(trits.remove(1), Ok(Trit::TWO));
+/// assert_eq!(trits.pop(), Some(Trit::TWO));
+/// assert_eq!(trits.get(0), Some(Trit::ONE));
+/// assert_eq!(trits.get(1), Some(Trit::ZERO));
+/// assert_eq!(trits.get(2), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NitVec<const BASE: BaseMaximum, const BYTES: usize = 1> {
+	/// The words holding the digits.
+	words: Vec<NitArrayWord>,
+	/// The amount of digits.
+	len: usize,
+}
+
+impl<const BASE: BaseMaximum, const BYTES: usize> NitVec<BASE, BYTES> {
+	/// The amount of digits stored within each word.
+	///
+	/// This is evaluated at compile-time, failing if the base can't be stored within a word.
+	pub const NITS_PER_WORD: usize = match compute_max_nits_in_bits::<BASE, WORD_BITS>() {
+		Ok(per_word) => per_word as usize,
+		Err(err) => panic!("{}", err.get_str()),
+	};
+
+	/// Creates a new, empty list without allocating.
+	#[must_use]
+	pub const fn new() -> Self {
+		let _ = Self::NITS_PER_WORD;
+		Self { words: Vec::new(), len: 0 }
+	}
+
+	/// Creates a new, empty list with enough space allocated for at least `capacity` digits.
+	#[must_use]
+	pub fn with_capacity(capacity: usize) -> Self {
+		let _ = Self::NITS_PER_WORD;
+		Self { words: Vec::with_capacity(words_required::<BASE>(capacity)), len: 0 }
+	}
+
+	/// Returns the amount of digits in the list.
+	#[must_use]
+	pub const fn len(&self) -> usize {
+		self.len
+	}
+
+	/// Returns whether the list holds no digits.
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// Returns the amount of digits the list can hold without reallocating.
+	#[must_use]
+	pub const fn capacity(&self) -> usize {
+		self.words.capacity() * Self::NITS_PER_WORD
+	}
+
+	/// Returns the underlying words.
+	#[must_use]
+	pub fn as_words(&self) -> &[NitArrayWord] {
+		&self.words
+	}
+
+	/// Splits the index of a digit into the index of its word and a [`PlacesIndex`] within that word, without checking it against the length.
+	const fn locate(i: usize) -> (usize, PlacesIndex<WORD_BITS, BASE>) {
+		let per_word = Self::NITS_PER_WORD;
+		#[allow(clippy::cast_possible_truncation)]
+		let place = (i % per_word) as FitsMaximumBits;
+		// SAFETY: The place is less than the amount of digits per word, which was computed for this base and bit width.
+		(i / per_word, unsafe { PlacesIndex::new_unchecked(place) })
+	}
+
+	/// Returns the most significant place within each word.
+	const fn top_place() -> FitsMaximumBits {
+		#[allow(clippy::cast_possible_truncation)]
+		let top = (Self::NITS_PER_WORD - 1) as FitsMaximumBits;
+		top
+	}
+
+	/// Returns the digit at the `i`th index.
+	/// Returns [`None`] if the index goes beyond the length of the list.
+	#[must_use]
+	pub fn get(&self, i: usize) -> Option<Nit<BASE, BYTES>> {
+		if i >= self.len {
+			return None;
+		}
+		let (word, place) = Self::locate(i);
+		Some(self.words[word].get_nit_indexed(place))
+	}
+
+	/// Sets the digit at the `i`th index, returning the previous digit at that index.
+	///
+	/// # Errors
+	/// - [`PlacesIndexCreationError::OutOfBounds`]: If the index goes beyond the length of the list.
+	pub fn set(&mut self, i: usize, value: Nit<BASE, BYTES>) -> Result<Nit<BASE, BYTES>, PlacesIndexCreationError> {
+		if i >= self.len {
+			return Err(PlacesIndexCreationError::OutOfBounds);
+		}
+		let (word, place) = Self::locate(i);
+		Ok(self.words[word].set_nit_indexed(place, value))
+	}
+
+	/// Appends a digit to the end of the list.
+	pub fn push(&mut self, value: Nit<BASE, BYTES>) {
+		let (word, place) = Self::locate(self.len);
+		if word == self.words.len() {
+			self.words.push(0);
+		}
+		self.words[word].set_nit_indexed(place, value);
+		self.len += 1;
+	}
+
+	/// Removes the last digit from the list and returns it, or [`None`] if it is empty.
+	pub fn pop(&mut self) -> Option<Nit<BASE, BYTES>> {
+		if self.len == 0 {
+			return None;
+		}
+		self.len -= 1;
+		let (word, place) = Self::locate(self.len);
+		// Zeroing the digit keeps the places past the length zeroed.
+		// SAFETY: Zero is within the range of `0..BASE`.
+		let value = self.words[word].set_nit_indexed(place, unsafe { Nit::new_unchecked(0) });
+		if place.get() == 0 {
+			self.words.pop();
+		}
+		Some(value)
+	}
+
+	/// Inserts a digit at the `i`th index, shifting all digits after it to the right.
+	///
+	/// Each word past the index is shifted by a whole place at once, carrying its top digit into the bottom of the next.
+	///
+	/// # Errors
+	/// - [`PlacesIndexCreationError::OutOfBounds`]: If the index is greater than the length of the list.
+	pub fn insert(&mut self, i: usize, value: Nit<BASE, BYTES>) -> Result<(), PlacesIndexCreationError> {
+		if i > self.len {
+			return Err(PlacesIndexCreationError::OutOfBounds);
+		}
+		if self.len.is_multiple_of(Self::NITS_PER_WORD) {
+			self.words.push(0);
+		}
+		let top = Self::top_place();
+		let base = NitArrayWord::from(BASE);
+		let (word, place) = Self::locate(i);
+		let place = place.get();
+		// Only the places at and above the index move within its own word.
+		let (high, low) = Powers::<NitArrayWord, BASE>::split_at_place(self.words[word], place);
+		let (mut carried, high) = Powers::<NitArrayWord, BASE>::split_at_place(high, top - place);
+		self.words[word] = low + (high * base + NitArrayWord::from(value.get_value())) * Powers::<NitArrayWord, BASE>::power(place);
+		for word in &mut self.words[word + 1..] {
+			let (next, rest) = Powers::<NitArrayWord, BASE>::split_at_place(*word, top);
+			*word = rest * base + carried;
+			carried = next;
+		}
+		self.len += 1;
+		Ok(())
+	}
+
+	/// Removes and returns the digit at the `i`th index, shifting all digits after it to the left.
+	///
+	/// Each word past the index is shifted by a whole place at once, carrying its bottom digit into the top of the previous.
+	///
+	/// # Errors
+	/// - [`PlacesIndexCreationError::OutOfBounds`]: If the index goes beyond the length of the list.
+	pub fn remove(&mut self, i: usize) -> Result<Nit<BASE, BYTES>, PlacesIndexCreationError> {
+		if i >= self.len {
+			return Err(PlacesIndexCreationError::OutOfBounds);
+		}
+		let top = Self::top_place();
+		let (word, place) = Self::locate(i);
+		let place = place.get();
+		let mut carried = 0;
+		for word in self.words[word + 1..].iter_mut().rev() {
+			let (rest, next) = Powers::<NitArrayWord, BASE>::split_at_place(*word, 1);
+			*word = rest + carried * Powers::<NitArrayWord, BASE>::power(top);
+			carried = next;
+		}
+		// Only the places above the index move within its own word.
+		let (high, low) = Powers::<NitArrayWord, BASE>::split_at_place(self.words[word], place);
+		let (high, removed) = Powers::<NitArrayWord, BASE>::split_at_place(high, 1);
+		self.words[word] = low + (high + carried * Powers::<NitArrayWord, BASE>::power(top - place)) * Powers::<NitArrayWord, BASE>::power(place);
+		self.len -= 1;
+		// The last word held only the digit which was shifted out of it.
+		if self.len.is_multiple_of(Self::NITS_PER_WORD) {
+			self.words.pop();
+		}
+		#[allow(clippy::cast_possible_truncation)]
+		let removed = removed as BaseMaximum;
+		// SAFETY: The digit was extracted from a place of the word, so it is within the range of `0..BASE`.
+		Ok(unsafe { Nit::new_unchecked(removed) })
+	}
+
+	/// Shortens the list to the first `len` digits, doing nothing if it is already no longer than that.
+	pub fn truncate(&mut self, len: usize) {
+		if len >= self.len {
+			return;
+		}
+		self.words.truncate(words_required::<BASE>(len));
+		self.len = len;
+		let (word, place) = Self::locate(len);
+		if let Some(word) = self.words.get_mut(word) {
+			// Zero every place at and above the new length within the last word.
+			let shifter = NitArrayWord::from(BASE).pow(u32::from(place.get()));
+			*word %= shifter;
+		}
+	}
+
+	/// Removes every digit from the list, keeping its allocation.
+	pub fn clear(&mut self) {
+		self.truncate(0);
+	}
+}
+
+impl<const BASE: BaseMaximum, const BYTES: usize> Default for NitVec<BASE, BYTES> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<const BASE: BaseMaximum, const BYTES: usize> Extend<Nit<BASE, BYTES>> for NitVec<BASE, BYTES> {
+	fn extend<I: IntoIterator<Item = Nit<BASE, BYTES>>>(&mut self, iter: I) {
+		let iter = iter.into_iter();
+		let additional = words_required::<BASE>(self.len + iter.size_hint().0).saturating_sub(self.words.len());
+		self.words.reserve(additional);
+		for value in iter {
+			self.push(value);
+		}
+	}
+}
+
+impl<const BASE: BaseMaximum, const BYTES: usize> FromIterator<Nit<BASE, BYTES>> for NitVec<BASE, BYTES> {
+	fn from_iter<I: IntoIterator<Item = Nit<BASE, BYTES>>>(iter: I) -> Self {
+		let mut vec = Self::new();
+		vec.extend(iter);
+		vec
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::missing_docs_in_private_items)]
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+	use crate::Trit;
+
+	fn trit(value: u32) -> Trit {
+		Trit::new(value).unwrap()
+	}
+
+	#[test]
+	fn crosses_words() {
+		let mut vec = NitVec::<3>::with_capacity(100);
+		assert!(vec.capacity() >= 100);
+		for i in 0..100 {
+			vec.push(trit(i % 3));
+		}
+		assert_eq!(vec.as_words().len(), 3);
+		vec.insert(0, trit(2)).unwrap();
+		assert_eq!(vec.len(), 101);
+		for (i, expected) in (0..100).map(|i| i % 3).enumerate() {
+			assert_eq!(vec.get(i + 1), Some(trit(expected)));
+		}
+		assert_eq!(vec.remove(0), Ok(trit(2)));
+		assert_eq!(vec, (0..100).map(|i| trit(i % 3)).collect());
+		assert_eq!(vec.insert(101, trit(0)), Err(PlacesIndexCreationError::OutOfBounds));
+		assert_eq!(vec.remove(100), Err(PlacesIndexCreationError::OutOfBounds));
+	}
+
+	fn check_against_vec<const BASE: BaseMaximum, const BYTES: usize>() {
+		let digits = |len: u32| (0..len).map(|i| (i * 7 + 3) % BASE).collect::<Vec<_>>();
+		let nit = |digit: u32| Nit::<BASE, BYTES>::new(digit).unwrap();
+		let len = u32::try_from(NitVec::<BASE, BYTES>::NITS_PER_WORD * 2 + 3).unwrap();
+		for i in 0..=len as usize {
+			let mut expected = digits(len);
+			let mut vec: NitVec<BASE, BYTES> = expected.iter().map(|&digit| nit(digit)).collect();
+			expected.insert(i, BASE - 1);
+			vec.insert(i, nit(BASE - 1)).unwrap();
+			assert_eq!(vec, expected.iter().map(|&digit| nit(digit)).collect());
+			assert_eq!(vec.remove(i), Ok(nit(BASE - 1)));
+			assert_eq!(vec, digits(len).into_iter().map(nit).collect());
+		}
+		// Removing from the front until empty keeps every place past the length zeroed.
+		let mut expected = digits(len);
+		let mut vec: NitVec<BASE, BYTES> = expected.iter().map(|&digit| nit(digit)).collect();
+		while !expected.is_empty() {
+			assert_eq!(vec.remove(0), Ok(nit(expected.remove(0))));
+			assert_eq!(vec, expected.iter().map(|&digit| nit(digit)).collect());
+		}
+		assert!(vec.as_words().is_empty());
+	}
+
+	#[test]
+	fn shifts_whole_words() {
+		check_against_vec::<2, 1>();
+		check_against_vec::<3, 1>();
+		check_against_vec::<10, 1>();
+		check_against_vec::<256, 1>();
+		check_against_vec::<1000, 2>();
+	}
+
+	#[test]
+	fn truncation_zeroes_the_remainder() {
+		let mut vec: NitVec<3> = (0..45).map(|_| trit(2)).collect();
+		vec.truncate(42);
+		assert_eq!(vec.len(), 42);
+		assert_eq!(vec.as_words(), &[3_u64.pow(40) - 1, 8]);
+		vec.truncate(40);
+		assert_eq!(vec.as_words(), &[3_u64.pow(40) - 1]);
+		while vec.pop().is_some() {}
+		assert!(vec.as_words().is_empty());
+		vec.extend([trit(1)]);
+		vec.clear();
+		assert!(vec.is_empty());
+		assert_eq!(vec, NitVec::new());
+	}
+}
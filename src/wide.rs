@@ -0,0 +1,621 @@
+use core::cmp::Ordering;
+use crate::Nit;
+use crate::data_container::NitDataContainer;
+use crate::internal_macros::const_impl;
+use crate::max_nits::compute_max_nits_in_bits;
+use crate::places::{PlacesIndex, PlacesIndexCreationError};
+use crate::supported::{BaseMaximum, FitsMaximumBits};
+
+/// An unsigned integer of `LIMBS` 64-bit limbs, the least significant first.
+///
+/// Packing nits into one wide number rather than into independent words wastes fewer states:
+/// a `u64` holds 40 trits, so four of them hold 160, whereas a [`U256`] holds 161, and a [`U512`] holds 323 rather than 320.
+///
+/// [`NitDataContainer`] is implemented for every width from one to sixteen limbs (64 to 1024 bits).
+/// The digits are extracted by dividing each limb in turn by the largest power of the base which fits within a limb, from the most significant limb down.
+///
+/// # Example
+/// ```
+/// use nit::prelude::*;
+/// use nit::wide::U256;
+/// use nit::Trit;
+///
+/// let mut value = U256::ZERO;
+/// assert_eq!(value.set_nit(160, Trit::TWO), Ok(Trit::ZERO));
+/// assert_eq!(value.set_nit(161, Trit::TWO), Err(PlacesIndexCreationError::OutOfBounds));
+/// assert_eq!(value.iter_nits::<3, 1>().len(), 161);
+/// assert_eq!(value.iter_nits::<3, 1>().last(), Some(Trit::TWO));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WideUint<const LIMBS: usize>([u64; LIMBS]);
+
+/// A 256-bit unsigned integer.
+pub type U256 = WideUint<4>;
+/// A 512-bit unsigned integer.
+pub type U512 = WideUint<8>;
+
+impl<const LIMBS: usize> WideUint<LIMBS> {
+	/// The amount of bits.
+	#[allow(clippy::cast_possible_truncation)]
+	pub const BITS: u32 = LIMBS as u32 * u64::BITS;
+	/// The value of zero.
+	pub const ZERO: Self = Self([0; LIMBS]);
+	/// The value of one.
+	pub const ONE: Self = Self::from_u64(1);
+	/// The largest value, with every bit set.
+	pub const MAX: Self = Self([u64::MAX; LIMBS]);
+
+	/// Creates a value from its limbs, the least significant first.
+	#[must_use]
+	pub const fn from_limbs(limbs: [u64; LIMBS]) -> Self {
+		Self(limbs)
+	}
+
+	/// Returns the limbs, the least significant first.
+	#[must_use]
+	pub const fn into_limbs(self) -> [u64; LIMBS] {
+		self.0
+	}
+
+	/// Returns a reference to the limbs, the least significant first.
+	#[must_use]
+	pub const fn as_limbs(&self) -> &[u64; LIMBS] {
+		&self.0
+	}
+
+	/// Creates a value from a single limb, truncating it to nothing if there are no limbs.
+	#[must_use]
+	pub const fn from_u64(value: u64) -> Self {
+		let mut limbs = [0; LIMBS];
+		if LIMBS > 0 {
+			limbs[0] = value;
+		}
+		Self(limbs)
+	}
+
+	/// Returns whether the value is zero.
+	#[must_use]
+	pub const fn is_zero(&self) -> bool {
+		let mut i = 0;
+		while i < LIMBS {
+			if self.0[i] != 0 {
+				return false;
+			}
+			i += 1;
+		}
+		true
+	}
+
+	/// Returns `self + rhs`, along with whether it overflowed.
+	#[must_use]
+	pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+		let mut limbs = [0; LIMBS];
+		let mut carry = false;
+		let mut i = 0;
+		while i < LIMBS {
+			let (sum, first) = self.0[i].overflowing_add(rhs.0[i]);
+			let (sum, second) = sum.overflowing_add(carry as u64);
+			limbs[i] = sum;
+			carry = first || second;
+			i += 1;
+		}
+		(Self(limbs), carry)
+	}
+
+	/// Returns `self + rhs`, wrapping around at the bounds of the type.
+	#[must_use]
+	pub const fn wrapping_add(self, rhs: Self) -> Self {
+		self.overflowing_add(rhs).0
+	}
+
+	/// Returns `self + rhs`, or [`None`] if it overflows.
+	#[must_use]
+	pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+		match self.overflowing_add(rhs) {
+			(sum, false) => Some(sum),
+			(_, true) => None,
+		}
+	}
+
+	/// Returns `self - rhs`, along with whether it overflowed.
+	#[must_use]
+	pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+		let mut limbs = [0; LIMBS];
+		let mut borrow = false;
+		let mut i = 0;
+		while i < LIMBS {
+			let (difference, first) = self.0[i].overflowing_sub(rhs.0[i]);
+			let (difference, second) = difference.overflowing_sub(borrow as u64);
+			limbs[i] = difference;
+			borrow = first || second;
+			i += 1;
+		}
+		(Self(limbs), borrow)
+	}
+
+	/// Returns `self - rhs`, wrapping around at the bounds of the type.
+	#[must_use]
+	pub const fn wrapping_sub(self, rhs: Self) -> Self {
+		self.overflowing_sub(rhs).0
+	}
+
+	/// Returns `self - rhs`, or [`None`] if it overflows.
+	#[must_use]
+	pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+		match self.overflowing_sub(rhs) {
+			(difference, false) => Some(difference),
+			(_, true) => None,
+		}
+	}
+
+	/// Returns `self * rhs`, wrapping around at the bounds of the type, along with the limb which was carried out of the top.
+	#[must_use]
+	#[allow(clippy::cast_possible_truncation)]
+	pub const fn carrying_mul_small(self, rhs: u64) -> (Self, u64) {
+		let mut limbs = [0; LIMBS];
+		let mut carry = 0;
+		let mut i = 0;
+		while i < LIMBS {
+			// The product is at most `(2^64 - 1)^2 + (2^64 - 1)`, which fits within a `u128`.
+			let product = self.0[i] as u128 * rhs as u128 + carry as u128;
+			limbs[i] = product as u64;
+			carry = (product >> u64::BITS) as u64;
+			i += 1;
+		}
+		(Self(limbs), carry)
+	}
+
+	/// Returns `self * rhs`, wrapping around at the bounds of the type.
+	#[must_use]
+	pub const fn wrapping_mul_small(self, rhs: u64) -> Self {
+		self.carrying_mul_small(rhs).0
+	}
+
+	/// Returns `self * rhs`, or [`None`] if it overflows.
+	#[must_use]
+	pub const fn checked_mul_small(self, rhs: u64) -> Option<Self> {
+		match self.carrying_mul_small(rhs) {
+			(product, 0) => Some(product),
+			_ => None,
+		}
+	}
+
+	/// Returns `(self / divisor, self % divisor)`, dividing from the most significant limb down, or [`None`] if `divisor` is zero.
+	///
+	/// # Example
+	/// ```
+	/// use nit::wide::U256;
+	/// let value = U256::from_limbs([5, 0, 0, 1]);
+	/// assert_eq!(value.div_rem_small(1 << 32), Some((U256::from_limbs([0, 0, 1 << 32, 0]), 5)));
+	/// assert_eq!(value.div_rem_small(0), None);
+	/// ```
+	#[must_use]
+	pub const fn div_rem_small(self, divisor: u64) -> Option<(Self, u64)> {
+		if divisor == 0 {
+			return None;
+		}
+		Some(self.div_rem_nonzero(divisor))
+	}
+
+	/// Returns `(self / divisor, self % divisor)`, for a divisor which is known to be non-zero.
+	#[allow(clippy::cast_possible_truncation)]
+	pub(crate) const fn div_rem_nonzero(self, divisor: u64) -> (Self, u64) {
+		debug_assert!(divisor != 0, "The divisor is zero.");
+		let mut limbs = [0; LIMBS];
+		let mut remainder: u64 = 0;
+		let mut i = LIMBS;
+		while i > 0 {
+			i -= 1;
+			// The remainder is less than the divisor, so the quotient of each limb fits within a limb.
+			let dividend = (remainder as u128) << u64::BITS | self.0[i] as u128;
+			limbs[i] = (dividend / divisor as u128) as u64;
+			remainder = (dividend % divisor as u128) as u64;
+		}
+		(Self(limbs), remainder)
+	}
+
+	/// Returns `self / BASE.pow(k)`, dividing by as many places at once as fit within a limb.
+	const fn div_places<const BASE: BaseMaximum>(self, k: FitsMaximumBits) -> Self {
+		let (places, power) = const { chunk(BASE) };
+		let mut value = self;
+		let mut k = k;
+		while k >= places {
+			value = value.div_rem_nonzero(power).0;
+			k -= places;
+		}
+		if k > 0 {
+			value = value.div_rem_nonzero(small_power(BASE, k)).0;
+		}
+		value
+	}
+
+	/// Returns `self * BASE.pow(k)`, wrapping around at the bounds of the type.
+	const fn mul_places<const BASE: BaseMaximum>(self, k: FitsMaximumBits) -> Self {
+		let (places, power) = const { chunk(BASE) };
+		let mut value = self;
+		let mut k = k;
+		while k >= places {
+			value = value.wrapping_mul_small(power);
+			k -= places;
+		}
+		if k > 0 {
+			value = value.wrapping_mul_small(small_power(BASE, k));
+		}
+		value
+	}
+
+	/// Splits the value at the `k`th place into `(n / BASE.pow(k), n % BASE.pow(k))`.
+	const fn split_at_place<const BASE: BaseMaximum>(self, k: FitsMaximumBits) -> (Self, Self) {
+		let high = self.div_places::<BASE>(k);
+		// The high digits shifted back into place are no greater than the value, so this can't wrap.
+		(high, self.wrapping_sub(high.mul_places::<BASE>(k)))
+	}
+
+	/// Returns the digit at the `n`th place.
+	#[allow(clippy::cast_lossless)]
+	const fn digit<const BASE: BaseMaximum>(self, n: FitsMaximumBits) -> u64 {
+		self.div_places::<BASE>(n).div_rem_nonzero(BASE as u64).1
+	}
+
+	/// Replaces the digit at the `n`th place, returning the previous one.
+	#[allow(clippy::cast_lossless)]
+	const fn replace_digit<const BASE: BaseMaximum>(&mut self, n: FitsMaximumBits, digit: BaseMaximum) -> u64 {
+		let previous = self.digit::<BASE>(n);
+		let shifter = Self::ONE.mul_places::<BASE>(n);
+		// Like the primitive containers, this only wraps around if the value holds digits beyond the nit capacity.
+		*self = self.wrapping_sub(shifter.wrapping_mul_small(previous)).wrapping_add(shifter.wrapping_mul_small(digit as u64));
+		previous
+	}
+
+	/// Returns the digits within the nit capacity, starting from the least significant digit.
+	#[allow(clippy::cast_lossless, clippy::cast_possible_truncation)]
+	const fn digits<const BASE: BaseMaximum, const BYTES: usize, const CAP: usize>(self) -> [Nit<BASE, BYTES>; CAP] {
+		let (places, power) = const { chunk(BASE) };
+		// SAFETY: Zero is within the range of `0..BASE`.
+		let mut nits = [unsafe { Nit::new_unchecked(0) }; CAP];
+		let mut remaining = self;
+		let mut i = 0;
+		while i < CAP {
+			// Dividing out as many places as fit within a limb at once, and splitting them apart as a single limb.
+			let (quotient, mut low) = remaining.div_rem_nonzero(power);
+			remaining = quotient;
+			let mut j = 0;
+			while j < places && i < CAP {
+				// SAFETY: The value will be always within the range of `0..BASE` because of the modulo operation.
+				nits[i] = unsafe { Nit::new_unchecked((low % BASE as u64) as BaseMaximum) };
+				low /= BASE as u64;
+				i += 1;
+				j += 1;
+			}
+		}
+		nits
+	}
+
+	/// Creates a value from digits which fit within the nit capacity, starting from the least significant digit.
+	#[allow(clippy::cast_lossless)]
+	const fn from_digits<const BASE: BaseMaximum, const BYTES: usize>(nits: &[Nit<BASE, BYTES>]) -> Self {
+		// Horner's method, from the most significant digit; this can't overflow as the digits fit within the capacity.
+		let mut value = Self::ZERO;
+		let mut i = nits.len();
+		while i > 0 {
+			i -= 1;
+			value = value.wrapping_mul_small(BASE as u64).wrapping_add(Self::from_u64(nits[i].get_value() as u64));
+		}
+		value
+	}
+
+	/// Shifts every digit within the capacity `k` places towards the most significant end, returning the digits which fell off the top.
+	const fn shl_places<const BASE: BaseMaximum>(&mut self, k: FitsMaximumBits, capacity: FitsMaximumBits) -> Self {
+		let k = if k < capacity { k } else { capacity };
+		let (_, value) = self.split_at_place::<BASE>(capacity);
+		let (dropped, kept) = value.split_at_place::<BASE>(capacity - k);
+		// The kept digits fit within the capacity once shifted, so this can't overflow.
+		*self = kept.mul_places::<BASE>(k);
+		dropped
+	}
+
+	/// Shifts every digit within the capacity `k` places towards the least significant end, returning the digits which fell off the bottom.
+	const fn shr_places<const BASE: BaseMaximum>(&mut self, k: FitsMaximumBits, capacity: FitsMaximumBits) -> Self {
+		let k = if k < capacity { k } else { capacity };
+		let (_, value) = self.split_at_place::<BASE>(capacity);
+		let (kept, dropped) = value.split_at_place::<BASE>(k);
+		*self = kept;
+		dropped
+	}
+
+	/// Rotates every digit within the capacity `k` places towards the most significant end.
+	const fn rotate_places_left<const BASE: BaseMaximum>(&mut self, k: FitsMaximumBits, capacity: FitsMaximumBits) {
+		if capacity == 0 {
+			*self = Self::ZERO;
+			return;
+		}
+		let k = k % capacity;
+		let (_, value) = self.split_at_place::<BASE>(capacity);
+		let (high, low) = value.split_at_place::<BASE>(capacity - k);
+		// The low digits are moved into the places vacated by the high digits, so neither this nor the sum can overflow.
+		*self = low.mul_places::<BASE>(k).wrapping_add(high);
+	}
+}
+
+impl<const LIMBS: usize> Default for WideUint<LIMBS> {
+	fn default() -> Self {
+		Self::ZERO
+	}
+}
+
+impl<const LIMBS: usize> From<[u64; LIMBS]> for WideUint<LIMBS> {
+	fn from(limbs: [u64; LIMBS]) -> Self {
+		Self::from_limbs(limbs)
+	}
+}
+
+impl<const LIMBS: usize> From<WideUint<LIMBS>> for [u64; LIMBS] {
+	fn from(value: WideUint<LIMBS>) -> Self {
+		value.into_limbs()
+	}
+}
+
+impl<const LIMBS: usize> Ord for WideUint<LIMBS> {
+	/// Compares the values from the most significant limb down.
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.0.iter().rev().cmp(other.0.iter().rev())
+	}
+}
+
+impl<const LIMBS: usize> PartialOrd for WideUint<LIMBS> {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+/// Returns the most places of base-`base` digits whose power fits within a limb, along with that power.
+///
+/// For a base of two, this is one place short of a whole limb, as `2^64` doesn't fit within it.
+const fn chunk(base: BaseMaximum) -> (FitsMaximumBits, u64) {
+	// Erroneous bases have no nit capacity, so this is never divided by.
+	if base < 2 {
+		return (1, 1);
+	}
+	let mut places = 1;
+	#[allow(clippy::cast_lossless)]
+	let mut power = base as u64;
+	#[allow(clippy::cast_lossless)]
+	while let Some(next) = power.checked_mul(base as u64) {
+		power = next;
+		places += 1;
+	}
+	(places, power)
+}
+
+/// Returns `base.pow(k)` for fewer places than a [`chunk`], which always fits within a limb.
+#[allow(clippy::cast_lossless)]
+const fn small_power(base: BaseMaximum, k: FitsMaximumBits) -> u64 {
+	(base as u64).pow(k as u32)
+}
+
+/// Generates an implementation of the [`NitDataContainer`] trait for a [`WideUint`] of each amount of limbs provided.
+macro_rules! impl_wide_data_container {
+	($($limbs: literal),*) => {
+		$(
+			impl WideUint<$limbs> {
+				/// The nit capacity for the base, or zero if the base is erroneous for the type.
+				const fn capacity<const BASE: BaseMaximum>() -> FitsMaximumBits {
+					match compute_max_nits_in_bits::<BASE, { $limbs * 64 }>() {
+						Ok(max) => max,
+						Err(_) => 0,
+					}
+				}
+			}
+
+			const_impl!(NitDataContainer<{ $limbs * 64 }> | WideUint<$limbs> {
+				fn get_nit_indexed<const BASE: BaseMaximum, const BYTES: usize>(&self, n: PlacesIndex<{ $limbs * 64 }, { BASE }>) -> Nit<BASE, BYTES> {
+					let digit = self.digit::<BASE>(n.get());
+					// SAFETY: The value will be always within the range of `0..BASE` because it is the remainder of a division by `BASE`.
+					unsafe { #[allow(clippy::cast_possible_truncation)] let digit = digit as BaseMaximum; Nit::new_unchecked(digit) }
+				}
+
+				fn set_nit_indexed<const BASE: BaseMaximum, const BYTES: usize>(&mut self, n: PlacesIndex<{ $limbs * 64 }, { BASE }>, value: Nit<BASE, BYTES>) -> Nit<BASE, BYTES> {
+					let digit = self.replace_digit::<BASE>(n.get(), value.get_value());
+					// SAFETY: The value will be always within the range of `0..BASE` because it is the remainder of a division by `BASE`.
+					unsafe { #[allow(clippy::cast_possible_truncation)] let digit = digit as BaseMaximum; Nit::new_unchecked(digit) }
+				}
+
+				fn to_nits<const BASE: BaseMaximum, const BYTES: usize, const CAP: usize>(&self) -> [Nit<BASE, BYTES>; CAP] {
+					const {
+						match compute_max_nits_in_bits::<BASE, { $limbs * 64 }>() {
+							Ok(max) => assert!(max as usize == CAP, "`CAP` must be the nit capacity of the type for the base."),
+							Err(err) => panic!("{}", err.get_str()),
+						}
+					}
+					self.digits::<BASE, BYTES, CAP>()
+				}
+
+				fn from_nits<const BASE: BaseMaximum, const BYTES: usize>(nits: &[Nit<BASE, BYTES>]) -> Result<Self, PlacesIndexCreationError> {
+					match compute_max_nits_in_bits::<BASE, { $limbs * 64 }>() {
+						Err(err) => return Err(PlacesIndexCreationError::BadNitLimitEvaluation(err)),
+						Ok(max) => if nits.len() > max as usize { return Err(PlacesIndexCreationError::OutOfBounds) },
+					}
+					Ok(Self::from_digits(nits))
+				}
+
+				fn shl_nits<const BASE: BaseMaximum>(&mut self, k: FitsMaximumBits) -> Self {
+					self.shl_places::<BASE>(k, Self::capacity::<BASE>())
+				}
+
+				fn shr_nits<const BASE: BaseMaximum>(&mut self, k: FitsMaximumBits) -> Self {
+					self.shr_places::<BASE>(k, Self::capacity::<BASE>())
+				}
+
+				fn rotate_nits_left<const BASE: BaseMaximum>(&mut self, k: FitsMaximumBits) {
+					self.rotate_places_left::<BASE>(k, Self::capacity::<BASE>());
+				}
+
+				fn rotate_nits_right<const BASE: BaseMaximum>(&mut self, k: FitsMaximumBits) {
+					let capacity = Self::capacity::<BASE>();
+					if capacity == 0 {
+						*self = Self::ZERO;
+						return;
+					}
+					// Rotating right by `k` places is rotating left by the rest of the capacity.
+					self.rotate_places_left::<BASE>(capacity - k % capacity, capacity);
+				}
+
+				fn push_nit_low<const BASE: BaseMaximum, const BYTES: usize>(&mut self, nit: Nit<BASE, BYTES>) -> Nit<BASE, BYTES> {
+					let capacity = Self::capacity::<BASE>();
+					if capacity == 0 {
+						return nit;
+					}
+					let dropped = self.shl_places::<BASE>(1, capacity);
+					// The bottom place was vacated by the shift.
+					self.replace_digit::<BASE>(0, nit.get_value());
+					// SAFETY: A single dropped digit is within the range of `0..BASE`.
+					unsafe { #[allow(clippy::cast_possible_truncation)] let dropped = dropped.0[0] as BaseMaximum; Nit::new_unchecked(dropped) }
+				}
+
+				fn push_nit_high<const BASE: BaseMaximum, const BYTES: usize>(&mut self, nit: Nit<BASE, BYTES>) -> Nit<BASE, BYTES> {
+					let capacity = Self::capacity::<BASE>();
+					if capacity == 0 {
+						return nit;
+					}
+					let dropped = self.shr_places::<BASE>(1, capacity);
+					// The top place within the capacity was vacated by the shift.
+					self.replace_digit::<BASE>(capacity - 1, nit.get_value());
+					// SAFETY: A single dropped digit is within the range of `0..BASE`.
+					unsafe { #[allow(clippy::cast_possible_truncation)] let dropped = dropped.0[0] as BaseMaximum; Nit::new_unchecked(dropped) }
+				}
+			});
+
+			impl<const BASE: BaseMaximum, const BYTES: usize> FromIterator<Nit<BASE, BYTES>> for WideUint<$limbs> {
+				/// Creates a value from base-`BASE` digits, starting from the least significant digit (right-hand side).
+				///
+				/// Digits beyond the nit capacity of the type for the base are ignored, and the value is zero if the base is erroneous;
+				/// see [`NitDataContainer::from_nits`] for a checked alternative.
+				fn from_iter<I: IntoIterator<Item = Nit<BASE, BYTES>>>(iter: I) -> Self {
+					let mut value = Self::ZERO;
+					let mut shifter = Self::ONE;
+					for nit in iter.into_iter().take(usize::from(Self::capacity::<BASE>())) {
+						// Each digit is within the capacity, so neither product wraps; the shifter past the last place isn't used.
+						value = value.wrapping_add(shifter.wrapping_mul_small(u64::from(nit.get_value())));
+						shifter = shifter.wrapping_mul_small(u64::from(BASE));
+					}
+					value
+				}
+			}
+		)*
+	};
+}
+impl_wide_data_container!(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16);
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::missing_docs_in_private_items)]
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+	use crate::Trit;
+	use crate::iter::NitIter;
+
+	/// A `u128` as a wide value, for comparing against the primitive implementation.
+	fn wide(value: u128) -> WideUint<2> {
+		#[allow(clippy::cast_possible_truncation)]
+		WideUint::from_limbs([value as u64, (value >> 64) as u64])
+	}
+
+	fn check_against_u128<const BASE: BaseMaximum, const BYTES: usize>(values: &[u128]) {
+		let capacity = compute_max_nits_in_bits::<BASE, 128>().unwrap();
+		for &value in values {
+			let (a, b) = (NitIter::<WideUint<2>, 128, BASE, BYTES>::new(wide(value)), NitIter::<u128, 128, BASE, BYTES>::new(value));
+			assert!(a.clone().eq(b.clone()), "{value} in base {BASE}");
+			assert!(a.clone().rev().eq(b.clone().rev()));
+			assert_eq!(a.collect::<WideUint<2>>(), wide(b.collect()));
+			for k in [0, 1, 2, capacity / 2, capacity - 1, capacity, capacity + 1] {
+				let (mut a, mut b) = (wide(value), value);
+				assert_eq!(a.shl_nits::<BASE>(k), wide(b.shl_nits::<BASE>(k)));
+				assert_eq!(a, wide(b));
+				assert_eq!(a.shr_nits::<BASE>(k), wide(b.shr_nits::<BASE>(k)));
+				assert_eq!(a, wide(b));
+				a.rotate_nits_left::<BASE>(k);
+				b.rotate_nits_left::<BASE>(k);
+				assert_eq!(a, wide(b));
+				a.rotate_nits_right::<BASE>(k + 3);
+				b.rotate_nits_right::<BASE>(k + 3);
+				assert_eq!(a, wide(b));
+			}
+			for n in [0, 1, capacity / 2, capacity - 1] {
+				let digit = Nit::<BASE, BYTES>::new(BASE - 1).unwrap();
+				let (mut a, mut b) = (wide(value), value);
+				assert_eq!(a.set_as(n, digit), b.set_as(n, digit));
+				assert_eq!(a, wide(b));
+				assert_eq!(a.get_as::<Nit<BASE, BYTES>>(n), b.get_as::<Nit<BASE, BYTES>>(n));
+			}
+		}
+	}
+
+	fn check_digits_against_u128<const BASE: BaseMaximum, const BYTES: usize, const CAP: usize>(values: &[u128]) {
+		for &value in values {
+			let digits = value.to_nits::<BASE, BYTES, CAP>();
+			assert_eq!(wide(value).to_nits::<BASE, BYTES, CAP>(), digits, "{value} in base {BASE}");
+			assert_eq!(WideUint::<2>::from_nits(&digits), u128::from_nits(&digits).map(wide));
+			let digit = Nit::<BASE, BYTES>::new(1).unwrap();
+			let (mut a, mut b) = (wide(value), value);
+			assert_eq!(a.push_nit_low(digit), b.push_nit_low(digit));
+			assert_eq!(a, wide(b));
+			assert_eq!(a.push_nit_high(digit), b.push_nit_high(digit));
+			assert_eq!(a, wide(b));
+		}
+	}
+
+	#[test]
+	fn matches_u128() {
+		let values = [0, 1, 12_345, u128::from(u64::MAX), u128::from(u64::MAX) + 1, 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210, u128::MAX];
+		check_against_u128::<2, 1>(&values);
+		check_against_u128::<3, 1>(&values);
+		check_against_u128::<10, 1>(&values);
+		check_against_u128::<16, 1>(&values);
+		check_against_u128::<1000, 2>(&values);
+		check_against_u128::<60000, 2>(&values);
+		check_digits_against_u128::<2, 1, 128>(&values);
+		check_digits_against_u128::<3, 1, 80>(&values);
+		check_digits_against_u128::<10, 1, 38>(&values);
+		check_digits_against_u128::<16, 1, 31>(&values);
+		check_digits_against_u128::<1000, 2, 12>(&values);
+		check_digits_against_u128::<60000, 2, 8>(&values);
+	}
+
+	#[test]
+	fn wide_capacities() {
+		let mut value = U512::ZERO;
+		let trits: [Trit; 323] = value.to_nits();
+		assert!(trits.iter().all(|&trit| trit == Trit::ZERO));
+		for n in 0..323 {
+			value.set_nit(n, Trit::TWO).unwrap();
+		}
+		// Every trit is two, so the value is `3^323 - 1`, which is one less than the next power of three, and beyond the rest of the capacity.
+		assert_eq!(value.checked_add(WideUint::ONE).unwrap().div_places::<3>(323), WideUint::ONE);
+		assert_eq!(value.set_nit(323, Trit::ONE), Err(PlacesIndexCreationError::OutOfBounds));
+		assert_eq!(U512::from_nits(&[Trit::TWO; 323]), Ok(value));
+		assert_eq!(U512::from_nits(&[Trit::TWO; 324]), Err(PlacesIndexCreationError::OutOfBounds));
+		assert_eq!(value.shr_nits::<3>(322), U512::from_nits(&[Trit::TWO; 322]).unwrap());
+		assert_eq!(value, U512::from_u64(2));
+
+		let bits: [Nit<2>; 256] = U256::MAX.to_nits();
+		assert!(bits.iter().all(|&bit| bit == Nit::new(1).unwrap()));
+		assert_eq!(U256::MAX.get_nit::<2, 1>(255), Some(Nit::new(1).unwrap()));
+		assert_eq!(U256::from_nits(&bits), Ok(U256::MAX));
+	}
+
+	#[test]
+	fn limb_arithmetic() {
+		let value = U256::from_limbs([u64::MAX, u64::MAX, 0, 7]);
+		assert_eq!(value.checked_add(U256::ONE), Some(U256::from_limbs([0, 0, 1, 7])));
+		assert_eq!(U256::MAX.checked_add(U256::ONE), None);
+		assert_eq!(U256::ZERO.checked_sub(U256::ONE), None);
+		assert_eq!(U256::ZERO.wrapping_sub(U256::ONE), U256::MAX);
+		assert_eq!(value.carrying_mul_small(1 << 62), (U256::from_limbs([0xC000_0000_0000_0000, u64::MAX, 0x3FFF_FFFF_FFFF_FFFF, 0xC000_0000_0000_0000]), 1));
+		assert_eq!(U256::MAX.checked_mul_small(2), None);
+		let (quotient, remainder) = value.div_rem_small(1000).unwrap();
+		assert_eq!(quotient.wrapping_mul_small(1000).wrapping_add(U256::from_u64(remainder)), value);
+		assert_eq!(value.div_rem_small(0), None);
+		assert!(U256::MAX > value && value > U256::ZERO);
+		assert!(U256::from_limbs([0, 0, 0, 1]) > U256::from_limbs([u64::MAX, u64::MAX, u64::MAX, 0]));
+	}
+}